@@ -25,6 +25,8 @@ CREATE TABLE IF NOT EXISTS products (
     CONSTRAINT chk_stock_range CHECK (stock BETWEEN 0 AND 999999)
 );
 
+ALTER TABLE products ADD COLUMN IF NOT EXISTS version INTEGER NOT NULL DEFAULT 1;
+
 CREATE TABLE IF NOT EXISTS coupons (
     id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
     code VARCHAR(100) NOT NULL UNIQUE,
@@ -53,6 +55,8 @@ CREATE TABLE IF NOT EXISTS coupons (
     )
 );
 
+ALTER TABLE coupons ADD COLUMN IF NOT EXISTS version INTEGER NOT NULL DEFAULT 1;
+
 CREATE TABLE IF NOT EXISTS product_coupon_applications (
     id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
     product_id UUID NOT NULL REFERENCES products(id) ON DELETE CASCADE,
@@ -65,9 +69,311 @@ CREATE TABLE IF NOT EXISTS product_coupon_applications (
     )
 );
 
--- Create index only if it doesn't exist
-CREATE UNIQUE INDEX IF NOT EXISTS idx_unique_active_coupon 
-ON product_coupon_applications (product_id) 
+-- Stackable coupons can share a product, so only the same coupon twice is
+-- rejected here; the one-non-stackable rule is checked in the apply transaction
+DROP INDEX IF EXISTS idx_unique_active_coupon;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_unique_active_coupon_per_product
+ON product_coupon_applications (product_id, coupon_id)
 WHERE removed_at IS NULL;
 
-CREATE UNIQUE INDEX IF NOT EXISTS products_name_unique_idx ON products (name);
+-- Product names only need to be unique among products that are not in the trash
+DO $$
+BEGIN
+    IF EXISTS (
+        SELECT 1 FROM pg_indexes
+        WHERE indexname = 'products_name_unique_idx'
+        AND indexdef NOT LIKE '%WHERE%'
+    ) THEN
+        DROP INDEX products_name_unique_idx;
+    END IF;
+END
+$$;
+
+CREATE UNIQUE INDEX IF NOT EXISTS products_name_unique_idx
+ON products (name)
+WHERE deleted_at IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_products_deleted_at
+ON products (deleted_at)
+WHERE deleted_at IS NOT NULL;
+
+CREATE TABLE IF NOT EXISTS idempotency_keys (
+    scope VARCHAR(255) NOT NULL,
+    key VARCHAR(255) NOT NULL,
+    request JSONB NOT NULL,
+    status_code INTEGER,
+    response_body JSONB,
+    locked_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expires_at TIMESTAMP NOT NULL,
+
+    PRIMARY KEY (scope, key)
+);
+
+CREATE INDEX IF NOT EXISTS idx_idempotency_keys_expires_at
+ON idempotency_keys (expires_at);
+
+-- Ledger of every coupon use; product_id has no FK so history survives purges
+CREATE TABLE IF NOT EXISTS coupon_redemptions (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    coupon_id UUID NOT NULL REFERENCES coupons(id) ON DELETE CASCADE,
+    product_id UUID NOT NULL,
+    application_id UUID REFERENCES product_coupon_applications(id) ON DELETE SET NULL,
+    discount_amount BIGINT NOT NULL CHECK (discount_amount >= 0),
+    customer_ref VARCHAR(255),
+    redeemed_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_coupon_redemptions_coupon
+ON coupon_redemptions (coupon_id, redeemed_at DESC);
+
+ALTER TABLE coupons ADD COLUMN IF NOT EXISTS max_uses_per_customer INTEGER
+    CONSTRAINT chk_max_uses_per_customer CHECK (max_uses_per_customer > 0);
+
+CREATE INDEX IF NOT EXISTS idx_coupon_redemptions_customer
+ON coupon_redemptions (coupon_id, customer_ref)
+WHERE customer_ref IS NOT NULL;
+
+ALTER TABLE coupons ADD COLUMN IF NOT EXISTS allowed_product_ids UUID[] NOT NULL DEFAULT '{}';
+ALTER TABLE coupons ADD COLUMN IF NOT EXISTS denied_product_ids UUID[] NOT NULL DEFAULT '{}';
+ALTER TABLE coupons ADD COLUMN IF NOT EXISTS min_product_price INTEGER
+    CONSTRAINT chk_min_product_price CHECK (min_product_price >= 0);
+ALTER TABLE coupons ADD COLUMN IF NOT EXISTS exclude_discounted BOOLEAN NOT NULL DEFAULT false;
+
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1 FROM pg_type WHERE typname = 'campaign_status'
+    ) THEN
+        CREATE TYPE campaign_status AS ENUM ('active', 'closed');
+    END IF;
+END
+$$;
+
+-- spent is charged in the same transaction that applies a coupon of the campaign
+CREATE TABLE IF NOT EXISTS campaigns (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name VARCHAR(100) NOT NULL,
+    starts_at TIMESTAMP NOT NULL,
+    ends_at TIMESTAMP NOT NULL,
+    budget BIGINT NOT NULL,
+    spent BIGINT NOT NULL DEFAULT 0,
+    status campaign_status NOT NULL DEFAULT 'active',
+    closed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP,
+
+    CONSTRAINT chk_campaign_period CHECK (ends_at > starts_at),
+    CONSTRAINT chk_campaign_budget CHECK (budget > 0),
+    CONSTRAINT chk_campaign_spent CHECK (spent BETWEEN 0 AND budget)
+);
+
+ALTER TABLE coupons ADD COLUMN IF NOT EXISTS campaign_id UUID
+    CONSTRAINT coupons_campaign_id_fkey REFERENCES campaigns(id);
+
+CREATE INDEX IF NOT EXISTS idx_coupons_campaign
+ON coupons (campaign_id)
+WHERE campaign_id IS NOT NULL;
+
+ALTER TABLE coupons ADD COLUMN IF NOT EXISTS stackable BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE coupons ADD COLUMN IF NOT EXISTS priority INTEGER NOT NULL DEFAULT 0;
+
+-- Quantity-based coupon types. New enum labels cannot be used in the same
+-- transaction that adds them, so the check below compares type as text.
+ALTER TYPE coupon_discount_type ADD VALUE IF NOT EXISTS 'bogo';
+ALTER TYPE coupon_discount_type ADD VALUE IF NOT EXISTS 'tiered';
+
+ALTER TABLE coupons ADD COLUMN IF NOT EXISTS params JSONB;
+
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1 FROM pg_constraint
+        WHERE conname = 'chk_value_range'
+        AND pg_get_constraintdef(oid) LIKE '%999999%'
+    ) THEN
+        ALTER TABLE coupons DROP CONSTRAINT IF EXISTS chk_value_range;
+        ALTER TABLE coupons ADD CONSTRAINT chk_value_range CHECK (
+            (type::text = 'percent' AND value BETWEEN 100 AND 8000 AND params IS NULL) OR
+            (type::text = 'fixed' AND value > 0 AND params IS NULL) OR
+            (type::text = 'bogo' AND value BETWEEN 100 AND 10000
+                AND (params->>'buy_quantity')::INTEGER BETWEEN 1 AND 999999
+                AND (params->>'get_quantity')::INTEGER BETWEEN 1 AND 999999) OR
+            (type::text = 'tiered' AND value BETWEEN 100 AND 8000
+                AND jsonb_typeof(params->'tiers') = 'array'
+                AND jsonb_array_length(params->'tiers') > 0)
+        );
+    END IF;
+END
+$$;
+
+ALTER TABLE coupons ADD COLUMN IF NOT EXISTS max_discount_amount INTEGER
+    CONSTRAINT chk_max_discount_amount CHECK (max_discount_amount > 0);
+
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1 FROM pg_type WHERE typname = 'application_removal_reason'
+    ) THEN
+        CREATE TYPE application_removal_reason AS ENUM ('removed', 'expired', 'coupon_deleted');
+    END IF;
+END
+$$;
+
+-- Set when an application is closed: by the API, or by the expiry job once
+-- its coupon expires or is deleted
+ALTER TABLE product_coupon_applications
+    ADD COLUMN IF NOT EXISTS removal_reason application_removal_reason;
+
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1 FROM pg_type WHERE typname = 'sale_event_status'
+    ) THEN
+        CREATE TYPE sale_event_status AS ENUM ('active', 'cancelled');
+    END IF;
+END
+$$;
+
+-- Storewide sales are priced on read from their target; they never create
+-- product_coupon_applications rows
+CREATE TABLE IF NOT EXISTS sale_events (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name VARCHAR(100) NOT NULL,
+    starts_at TIMESTAMP NOT NULL,
+    ends_at TIMESTAMP NOT NULL,
+    type coupon_discount_type NOT NULL,
+    value BIGINT NOT NULL,
+    target JSONB NOT NULL,
+    status sale_event_status NOT NULL DEFAULT 'active',
+    cancelled_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP,
+
+    CONSTRAINT chk_sale_event_period CHECK (ends_at > starts_at),
+    CONSTRAINT chk_sale_event_value CHECK (
+        (type::text = 'percent' AND value BETWEEN 100 AND 8000) OR
+        (type::text = 'fixed' AND value > 0)
+    )
+);
+
+CREATE INDEX IF NOT EXISTS idx_sale_events_running
+ON sale_events (starts_at, ends_at)
+WHERE status = 'active';
+
+-- Rates are basis points; whether products.price already includes them is a
+-- store setting, not a column
+CREATE TABLE IF NOT EXISTS tax_classes (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name VARCHAR(100) NOT NULL,
+    rate INTEGER NOT NULL,
+    region VARCHAR(50) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP,
+
+    CONSTRAINT chk_tax_class_rate CHECK (rate BETWEEN 0 AND 10000),
+    CONSTRAINT tax_classes_name_region_key UNIQUE (name, region)
+);
+
+ALTER TABLE products
+    ADD COLUMN IF NOT EXISTS tax_class_id UUID REFERENCES tax_classes(id);
+
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1 FROM pg_type WHERE typname = 'order_status'
+    ) THEN
+        CREATE TYPE order_status AS ENUM ('pending');
+    END IF;
+END
+$$;
+
+-- Totals follow the store's tax convention at the time the order was placed
+CREATE TABLE IF NOT EXISTS orders (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    customer_ref VARCHAR(255),
+    status order_status NOT NULL DEFAULT 'pending',
+    coupon_code VARCHAR(100),
+    subtotal BIGINT NOT NULL,
+    discount_total BIGINT NOT NULL,
+    total BIGINT NOT NULL,
+    prices_include_tax BOOLEAN NOT NULL,
+    tax_amount BIGINT NOT NULL,
+    net_total BIGINT NOT NULL,
+    gross_total BIGINT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_orders_customer
+ON orders (customer_ref, created_at DESC)
+WHERE customer_ref IS NOT NULL;
+
+-- Lines snapshot the product as it was priced; product_id has no FK so
+-- orders survive purges
+CREATE TABLE IF NOT EXISTS order_lines (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    order_id UUID NOT NULL REFERENCES orders(id) ON DELETE CASCADE,
+    product_id UUID NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    quantity INTEGER NOT NULL,
+    price BIGINT NOT NULL,
+    unit_price BIGINT NOT NULL,
+    subtotal BIGINT NOT NULL,
+    discount_total BIGINT NOT NULL,
+    total BIGINT NOT NULL,
+    tax_rate INTEGER NOT NULL,
+    tax_amount BIGINT NOT NULL,
+    position INTEGER NOT NULL,
+
+    CONSTRAINT chk_order_line_quantity CHECK (quantity > 0)
+);
+
+CREATE INDEX IF NOT EXISTS idx_order_lines_order
+ON order_lines (order_id, position);
+
+ALTER TABLE coupon_redemptions
+    ADD COLUMN IF NOT EXISTS order_id UUID REFERENCES orders(id) ON DELETE SET NULL;
+
+-- New enum labels cannot be used in the same transaction that adds them;
+-- they are only written by the API
+ALTER TYPE order_status ADD VALUE IF NOT EXISTS 'paid';
+ALTER TYPE order_status ADD VALUE IF NOT EXISTS 'fulfilled';
+ALTER TYPE order_status ADD VALUE IF NOT EXISTS 'cancelled';
+ALTER TYPE order_status ADD VALUE IF NOT EXISTS 'partially_refunded';
+ALTER TYPE order_status ADD VALUE IF NOT EXISTS 'refunded';
+
+ALTER TABLE orders ADD COLUMN IF NOT EXISTS refunded_total BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE order_lines ADD COLUMN IF NOT EXISTS refunded_quantity INTEGER NOT NULL DEFAULT 0
+    CONSTRAINT chk_order_line_refunded_quantity CHECK (refunded_quantity >= 0);
+ALTER TABLE order_lines ADD COLUMN IF NOT EXISTS refunded_amount BIGINT NOT NULL DEFAULT 0;
+
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1 FROM pg_type WHERE typname = 'order_action'
+    ) THEN
+        CREATE TYPE order_action AS ENUM ('pay', 'fulfill', 'cancel', 'refund');
+    END IF;
+END
+$$;
+
+-- Every transition of an order, with the units it put back in stock
+CREATE TABLE IF NOT EXISTS order_events (
+    id UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    order_id UUID NOT NULL REFERENCES orders(id) ON DELETE CASCADE,
+    action order_action NOT NULL,
+    from_status order_status NOT NULL,
+    to_status order_status NOT NULL,
+    lines JSONB NOT NULL DEFAULT '[]',
+    refund_amount BIGINT NOT NULL DEFAULT 0,
+    coupon_restored BOOLEAN NOT NULL DEFAULT false,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS idx_order_events_order
+ON order_events (order_id, created_at);
+
+-- Redemptions of a cancelled or refunded order stay in the ledger, reversed
+ALTER TABLE coupon_redemptions ADD COLUMN IF NOT EXISTS reversed_at TIMESTAMP;
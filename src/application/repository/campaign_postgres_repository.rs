@@ -0,0 +1,154 @@
+use crate::domain::entity::{
+    campaign_entity::{Campaign, CampaignStatus, CreateCampaign},
+    discount_entity::{PaginatedResponse, PaginationMeta},
+};
+use crate::domain::repository::campaign_repository::CampaignRepository;
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{QueryBuilder, Row, postgres::PgPool};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct PostgresCampaignRepository {
+    pool: Arc<PgPool>,
+}
+
+impl PostgresCampaignRepository {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CampaignRepository for PostgresCampaignRepository {
+    async fn create(&self, campaign: CreateCampaign) -> Result<Campaign, String> {
+        let now = Utc::now().naive_utc();
+
+        let row = sqlx::query(
+            r#"
+            INSERT INTO campaigns (id, name, starts_at, ends_at, budget, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $6)
+            RETURNING id, name, starts_at, ends_at, budget, spent, status,
+                      closed_at, created_at, updated_at
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(&campaign.name)
+        .bind(campaign.starts_at.naive_utc())
+        .bind(campaign.ends_at.naive_utc())
+        .bind(campaign.budget as i64)
+        .bind(now)
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|e| match &e {
+            sqlx::Error::Database(db_err) if db_err.is_unique_violation() => {
+                "Campaign already exists".to_string()
+            }
+            _ => e.to_string(),
+        })?;
+
+        Ok(Self::map_row_to_campaign(row))
+    }
+
+    async fn find(&self, id: &str) -> Result<Campaign, String> {
+        let campaign_id = Uuid::parse_str(id).map_err(|_| "Campaign not found".to_string())?;
+
+        let row = sqlx::query(
+            r#"
+            SELECT id, name, starts_at, ends_at, budget, spent, status,
+                   closed_at, created_at, updated_at
+            FROM campaigns
+            WHERE id = $1
+            "#,
+        )
+        .bind(campaign_id)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        match row {
+            Some(row) => Ok(Self::map_row_to_campaign(row)),
+            None => Err("Campaign not found".to_string()),
+        }
+    }
+
+    async fn find_all(
+        &self,
+        page: Option<u32>,
+        limit: Option<u32>,
+        status: Option<CampaignStatus>,
+    ) -> Result<PaginatedResponse<Campaign>, String> {
+        let page = page.unwrap_or(1);
+        let limit = limit.unwrap_or(10);
+        let offset = (page - 1) * limit;
+
+        let mut count_builder = QueryBuilder::new("SELECT COUNT(*) FROM campaigns WHERE TRUE");
+        if let Some(status) = status {
+            count_builder.push(" AND status = ").push_bind(status);
+        }
+
+        let total_items: i64 = count_builder
+            .build_query_scalar()
+            .fetch_one(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut query_builder = QueryBuilder::new(
+            r#"
+            SELECT id, name, starts_at, ends_at, budget, spent, status,
+                   closed_at, created_at, updated_at
+            FROM campaigns
+            WHERE TRUE
+            "#,
+        );
+        if let Some(status) = status {
+            query_builder.push(" AND status = ").push_bind(status);
+        }
+        query_builder
+            .push(" ORDER BY created_at DESC LIMIT ")
+            .push_bind(limit as i64)
+            .push(" OFFSET ")
+            .push_bind(offset as i64);
+
+        let rows = query_builder
+            .build()
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let campaigns = rows.into_iter().map(Self::map_row_to_campaign).collect();
+
+        let total_pages = (total_items as f64 / limit as f64).ceil() as u32;
+
+        Ok(PaginatedResponse {
+            data: campaigns,
+            meta: PaginationMeta {
+                page,
+                limit,
+                total_items: total_items as u64,
+                total_pages,
+            },
+        })
+    }
+}
+
+impl PostgresCampaignRepository {
+    pub(crate) fn map_row_to_campaign(row: sqlx::postgres::PgRow) -> Campaign {
+        Campaign {
+            id: row.get("id"),
+            name: row.get("name"),
+            starts_at: row.get::<chrono::NaiveDateTime, _>("starts_at").and_utc(),
+            ends_at: row.get::<chrono::NaiveDateTime, _>("ends_at").and_utc(),
+            budget: row.get::<i64, _>("budget") as u64,
+            spent: row.get::<i64, _>("spent") as u64,
+            status: row.get("status"),
+            closed_at: row
+                .get::<Option<chrono::NaiveDateTime>, _>("closed_at")
+                .map(|dt| dt.and_utc()),
+            created_at: row.get::<chrono::NaiveDateTime, _>("created_at").and_utc(),
+            updated_at: row
+                .get::<Option<chrono::NaiveDateTime>, _>("updated_at")
+                .map(|dt| dt.and_utc()),
+        }
+    }
+}
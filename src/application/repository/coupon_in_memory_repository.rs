@@ -1,13 +1,14 @@
 use crate::domain::entity::discount_entity::PaginatedResponse;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use std::collections::HashSet;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
 use crate::domain::{
     entity::{
-        coupon_entity::{Coupon, CreateCoupon, UpdateCoupon},
+        coupon_entity::{Coupon, CouponRedemption, CouponType, CreateCoupon, UpdateCoupon},
         discount_entity::PaginationMeta,
     },
     repository::coupon_repository::CouponRepository,
@@ -15,13 +16,17 @@ use crate::domain::{
 
 #[derive(Debug, Default)]
 pub struct InMemoryCouponRepository {
-    coupons: Arc<RwLock<Vec<Coupon>>>,
+    pub(crate) coupons: Arc<RwLock<Vec<Coupon>>>,
+    /// The redemption ledger, written by the in-memory discount and order
+    /// repositories built from this one.
+    pub(crate) redemptions: Arc<RwLock<Vec<CouponRedemption>>>,
 }
 
 impl InMemoryCouponRepository {
     pub fn new() -> Self {
         Self {
             coupons: Arc::new(RwLock::new(Vec::new())),
+            redemptions: Arc::new(RwLock::new(Vec::new())),
         }
     }
 }
@@ -46,11 +51,18 @@ impl CouponRepository for InMemoryCouponRepository {
             code: coupon.code.clone(),
             coupon_type: coupon.coupon_type,
             value: coupon.value,
+            params: coupon.params,
+            max_discount_amount: coupon.max_discount_amount,
             one_shot: coupon.one_shot,
             valid_from: coupon.valid_from,
             valid_until: coupon.valid_until,
             uses_count: 0,
             max_uses: coupon.max_uses,
+            max_uses_per_customer: coupon.max_uses_per_customer,
+            rules: coupon.rules,
+            campaign_id: coupon.campaign_id,
+            stacking: coupon.stacking,
+            version: 1,
             created_at: now,
             updated_at: Some(now),
             deleted_at: None,
@@ -61,6 +73,53 @@ impl CouponRepository for InMemoryCouponRepository {
         Ok(new_coupon)
     }
 
+    async fn create_many(&self, coupons: Vec<CreateCoupon>) -> Result<Vec<Coupon>, String> {
+        let mut stored = self.coupons.write().await;
+
+        let mut codes: Vec<String> = stored
+            .iter()
+            .filter(|c| c.deleted_at.is_none())
+            .map(|c| c.code.to_lowercase())
+            .collect();
+        for coupon in &coupons {
+            let code = coupon.code.to_lowercase();
+            if codes.contains(&code) {
+                return Err("Coupon code already exists".to_string());
+            }
+            codes.push(code);
+        }
+
+        let now = Utc::now();
+        let created: Vec<Coupon> = coupons
+            .into_iter()
+            .map(|coupon| Coupon {
+                id: Uuid::new_v4(),
+                code: coupon.code,
+                coupon_type: coupon.coupon_type,
+                value: coupon.value,
+                params: coupon.params,
+                max_discount_amount: coupon.max_discount_amount,
+                one_shot: coupon.one_shot,
+                valid_from: coupon.valid_from,
+                valid_until: coupon.valid_until,
+                uses_count: 0,
+                max_uses: coupon.max_uses,
+                max_uses_per_customer: coupon.max_uses_per_customer,
+                rules: coupon.rules,
+                campaign_id: coupon.campaign_id,
+                stacking: coupon.stacking,
+                version: 1,
+                created_at: now,
+                updated_at: Some(now),
+                deleted_at: None,
+            })
+            .collect();
+
+        stored.extend(created.iter().cloned());
+
+        Ok(created)
+    }
+
     async fn find(&self, code: &str) -> Result<Coupon, String> {
         let coupons = self.coupons.read().await;
         coupons
@@ -152,18 +211,35 @@ impl CouponRepository for InMemoryCouponRepository {
         })
     }
 
-    async fn update(&self, code: String, data: UpdateCoupon) -> Result<Coupon, String> {
+    async fn update(
+        &self,
+        code: String,
+        data: UpdateCoupon,
+        expected_versions: Option<Vec<u32>>,
+    ) -> Result<Coupon, String> {
         let mut coupons = self.coupons.write().await;
         if let Some(coupon) = coupons
             .iter_mut()
             .find(|c| c.code == code && c.deleted_at.is_none())
         {
+            if expected_versions.is_some_and(|v| !v.contains(&coupon.version)) {
+                return Err("Version mismatch".to_string());
+            }
             if let Some(coupon_type) = data.coupon_type {
                 coupon.coupon_type = coupon_type;
             }
             if let Some(value) = data.value {
                 coupon.value = value;
             }
+            if let Some(params) = data.params {
+                coupon.params = params;
+            }
+            if let Some(max_discount_amount) = data.max_discount_amount {
+                coupon.max_discount_amount = Some(max_discount_amount);
+            }
+            if matches!(coupon.coupon_type, CouponType::Percent | CouponType::Fixed) {
+                coupon.params = None;
+            }
             if let Some(one_shot) = data.one_shot {
                 coupon.one_shot = one_shot;
             }
@@ -176,52 +252,154 @@ impl CouponRepository for InMemoryCouponRepository {
             if let Some(max_uses) = data.max_uses {
                 coupon.max_uses = Some(max_uses);
             }
+            if let Some(max_uses_per_customer) = data.max_uses_per_customer {
+                coupon.max_uses_per_customer = Some(max_uses_per_customer);
+            }
+            if let Some(allowed_product_ids) = data.rules.allowed_product_ids {
+                coupon.rules.allowed_product_ids = allowed_product_ids;
+            }
+            if let Some(denied_product_ids) = data.rules.denied_product_ids {
+                coupon.rules.denied_product_ids = denied_product_ids;
+            }
+            if let Some(min_product_price) = data.rules.min_product_price {
+                coupon.rules.min_product_price = Some(min_product_price);
+            }
+            if let Some(exclude_discounted) = data.rules.exclude_discounted {
+                coupon.rules.exclude_discounted = exclude_discounted;
+            }
+            if let Some(campaign_id) = data.campaign_id {
+                coupon.campaign_id = Some(campaign_id);
+            }
+            if let Some(stackable) = data.stacking.stackable {
+                coupon.stacking.stackable = stackable;
+            }
+            if let Some(priority) = data.stacking.priority {
+                coupon.stacking.priority = priority;
+            }
             coupon.updated_at = Some(Utc::now());
+            coupon.version += 1;
             Ok(coupon.clone())
         } else {
             Err("Coupon not found".to_string())
         }
     }
 
-    async fn delete(&self, code: String) -> Result<(), String> {
+    async fn delete(
+        &self,
+        code: String,
+        expected_versions: Option<Vec<u32>>,
+    ) -> Result<(), String> {
         let mut coupons = self.coupons.write().await;
         if let Some(coupon) = coupons
             .iter_mut()
             .find(|c| c.code == code && c.deleted_at.is_none())
         {
+            if expected_versions.is_some_and(|v| !v.contains(&coupon.version)) {
+                return Err("Version mismatch".to_string());
+            }
             coupon.deleted_at = Some(Utc::now());
+            coupon.version += 1;
             Ok(())
         } else {
             Err("Coupon not found".to_string())
         }
     }
 
-    async fn find_valid_coupon_by_code(&self, code: &str) -> Result<Coupon, String> {
+    async fn find_with_deleted(&self, code: &str) -> Result<Coupon, String> {
         let coupons = self.coupons.read().await;
-        let now = Utc::now();
-
         coupons
             .iter()
-            .find(|c| {
-                c.code == code
-                    && c.valid_from <= now
-                    && c.valid_until >= now
-                    && c.deleted_at.is_none()
-                    && c.max_uses.map_or(true, |max| c.uses_count < max)
+            .filter(|c| c.code == code)
+            .max_by_key(|c| c.created_at)
+            .cloned()
+            .ok_or_else(|| "Coupon not found".to_string())
+    }
+
+    async fn count_customer_redemptions(
+        &self,
+        coupon_id: Uuid,
+        customer_ref: &str,
+    ) -> Result<u64, String> {
+        let redemptions = self.redemptions.read().await;
+        Ok(redemptions
+            .iter()
+            .filter(|r| {
+                r.coupon_id == coupon_id
+                    && r.customer_ref.as_deref() == Some(customer_ref)
+                    && r.reversed_at.is_none()
             })
+            .map(CouponRedemption::use_id)
+            .collect::<HashSet<_>>()
+            .len() as u64)
+    }
+
+    async fn find_redemptions(
+        &self,
+        code: &str,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedResponse<CouponRedemption>, String> {
+        let page = page.unwrap_or(1);
+        let limit = limit.unwrap_or(10);
+
+        // Redemptions stay visible after the coupon itself is deleted
+        let coupon_ids: Vec<Uuid> = self
+            .coupons
+            .read()
+            .await
+            .iter()
+            .filter(|c| c.code == code)
+            .map(|c| c.id)
+            .collect();
+        if coupon_ids.is_empty() {
+            return Err("Coupon not found".to_string());
+        }
+
+        let redemptions = self.redemptions.read().await;
+        let mut filtered: Vec<CouponRedemption> = redemptions
+            .iter()
+            .filter(|r| coupon_ids.contains(&r.coupon_id))
             .cloned()
-            .ok_or_else(|| "Valid coupon not found".to_string())
+            .collect();
+        filtered.sort_by_key(|r| std::cmp::Reverse(r.redeemed_at));
+
+        let total_items = filtered.len() as u64;
+        let total_pages = (total_items as f64 / limit as f64).ceil() as u32;
+        let start = ((page - 1) * limit) as usize;
+        let data = filtered
+            .into_iter()
+            .skip(start)
+            .take(limit as usize)
+            .collect();
+
+        Ok(PaginatedResponse {
+            data,
+            meta: PaginationMeta {
+                page,
+                limit,
+                total_items,
+                total_pages,
+            },
+        })
     }
 
-    async fn increment_uses(&self, coupon_id: String) {
-        if let Ok(uuid) = Uuid::parse_str(&coupon_id) {
-            let mut coupons = self.coupons.write().await;
-            if let Some(coupon) = coupons
-                .iter_mut()
-                .find(|c| c.id == uuid && c.deleted_at.is_none())
-            {
-                coupon.uses_count += 1;
+    async fn reconcile_uses_count(&self) -> Result<u64, String> {
+        let mut coupons = self.coupons.write().await;
+        let redemptions = self.redemptions.read().await;
+
+        let mut corrected = 0;
+        for coupon in coupons.iter_mut() {
+            let count = redemptions
+                .iter()
+                .filter(|r| r.coupon_id == coupon.id && r.reversed_at.is_none())
+                .map(CouponRedemption::use_id)
+                .collect::<HashSet<_>>()
+                .len() as u32;
+            if coupon.uses_count != count {
+                coupon.uses_count = count;
+                corrected += 1;
             }
         }
+        Ok(corrected)
     }
 }
@@ -1,14 +1,38 @@
 use crate::domain::entity::{
-    coupon_entity::{Coupon, CreateCoupon, UpdateCoupon},
+    coupon_entity::{
+        Coupon, CouponParams, CouponRedemption, CouponRules, CouponStacking, CreateCoupon,
+        UpdateCoupon,
+    },
     discount_entity::{PaginatedResponse, PaginationMeta},
 };
 use crate::domain::repository::coupon_repository::CouponRepository;
+use crate::domain::utils::validation::{constraint_error, validation_error};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use sqlx::{QueryBuilder, Row, postgres::PgPool};
+use sqlx::{QueryBuilder, Row, postgres::PgPool, types::Json};
 use std::sync::Arc;
 use uuid::Uuid;
 
+/// Uses of coupon `$1` in the redemption ledger: one per application, and
+/// one per order however many of its lines the coupon discounted. Reversed
+/// redemptions are not uses.
+const USES_QUERY: &str = "SELECT COUNT(DISTINCT COALESCE(order_id, id)) FROM coupon_redemptions WHERE coupon_id = $1 AND reversed_at IS NULL";
+
+/// Like `USES_QUERY`, for customer `$2` only.
+pub(crate) const CUSTOMER_USES_QUERY: &str = "SELECT COUNT(DISTINCT COALESCE(order_id, id)) FROM coupon_redemptions WHERE coupon_id = $1 AND customer_ref = $2 AND reversed_at IS NULL";
+
+/// The validation error for a violated coupon `CHECK` constraint, like a
+/// partial update leaving `value` out of range for the type.
+fn check_violation(e: &sqlx::Error) -> Option<String> {
+    match e {
+        sqlx::Error::Database(db_err) => db_err
+            .constraint()
+            .and_then(constraint_error)
+            .map(|error| validation_error(&[error])),
+        _ => None,
+    }
+}
+
 pub struct PostgresCouponRepository {
     pool: Arc<PgPool>,
 }
@@ -29,11 +53,18 @@ impl CouponRepository for PostgresCouponRepository {
             r#"
             INSERT INTO coupons (
                 id, code, type, value, one_shot,
-                valid_from, valid_until, max_uses, uses_count, created_at, updated_at
+                valid_from, valid_until, max_uses, uses_count, created_at, updated_at,
+                max_uses_per_customer, allowed_product_ids, denied_product_ids,
+                min_product_price, exclude_discounted, campaign_id, stackable, priority, params,
+                max_discount_amount
             )
-            VALUES ($1, $2, $3::coupon_discount_type, $4, $5, $6, $7, $8, $9, $10, $11)
+            VALUES ($1, $2, $3::coupon_discount_type, $4, $5, $6, $7, $8, $9, $10, $11, $12,
+                    $13, $14, $15, $16, $17, $18, $19, $20, $21)
             RETURNING id, code, type, value, one_shot, valid_from,
-                      valid_until, uses_count, max_uses, created_at, updated_at, deleted_at
+                      valid_until, uses_count, max_uses, max_uses_per_customer,
+                      allowed_product_ids, denied_product_ids, min_product_price, exclude_discounted,
+                      campaign_id, stackable, priority, params, max_discount_amount,
+                      created_at, updated_at, deleted_at, version
             "#,
         )
         .bind(id)
@@ -47,6 +78,16 @@ impl CouponRepository for PostgresCouponRepository {
         .bind(0i32)
         .bind(now)
         .bind(now)
+        .bind(coupon.max_uses_per_customer.map(|m| m as i32))
+        .bind(&coupon.rules.allowed_product_ids)
+        .bind(&coupon.rules.denied_product_ids)
+        .bind(coupon.rules.min_product_price.map(|p| p as i32))
+        .bind(coupon.rules.exclude_discounted)
+        .bind(coupon.campaign_id)
+        .bind(coupon.stacking.stackable)
+        .bind(coupon.stacking.priority)
+        .bind(coupon.params.as_ref().map(Json))
+        .bind(coupon.max_discount_amount.map(|m| m as i32))
         .fetch_one(&*self.pool)
         .await
         .map_err(|e| {
@@ -54,19 +95,103 @@ impl CouponRepository for PostgresCouponRepository {
                 if db_err.constraint() == Some("coupons_code_key") {
                     return "Coupon code already exists".to_string();
                 }
+                if db_err.constraint() == Some("coupons_campaign_id_fkey") {
+                    return "Campaign not found".to_string();
+                }
             }
-            e.to_string()
+            check_violation(&e).unwrap_or_else(|| e.to_string())
         })?;
 
         Ok(Self::map_row_to_coupon(row))
     }
 
+    async fn create_many(&self, coupons: Vec<CreateCoupon>) -> Result<Vec<Coupon>, String> {
+        let now = Utc::now().naive_utc();
+        let mut tx = self.pool.begin().await.map_err(|e| e.to_string())?;
+        let mut created = Vec::with_capacity(coupons.len());
+
+        // 21 binds per row keeps each chunk well under Postgres' 65535 parameter limit.
+        for chunk in coupons.chunks(1000) {
+            let mut query_builder = QueryBuilder::new(
+                r#"
+                INSERT INTO coupons (
+                    id, code, type, value, one_shot,
+                    valid_from, valid_until, max_uses, uses_count, created_at, updated_at,
+                    max_uses_per_customer, allowed_product_ids, denied_product_ids,
+                    min_product_price, exclude_discounted, campaign_id, stackable, priority,
+                    params, max_discount_amount
+                )
+                "#,
+            );
+
+            query_builder.push_values(chunk, |mut row, coupon| {
+                row.push_bind(Uuid::new_v4())
+                    .push_bind(&coupon.code)
+                    .push_bind(&coupon.coupon_type)
+                    .push_bind(coupon.value as i32)
+                    .push_bind(coupon.one_shot)
+                    .push_bind(coupon.valid_from)
+                    .push_bind(coupon.valid_until)
+                    .push_bind(coupon.max_uses.map(|m| m as i32))
+                    .push_bind(0i32)
+                    .push_bind(now)
+                    .push_bind(now)
+                    .push_bind(coupon.max_uses_per_customer.map(|m| m as i32))
+                    .push_bind(&coupon.rules.allowed_product_ids)
+                    .push_bind(&coupon.rules.denied_product_ids)
+                    .push_bind(coupon.rules.min_product_price.map(|p| p as i32))
+                    .push_bind(coupon.rules.exclude_discounted)
+                    .push_bind(coupon.campaign_id)
+                    .push_bind(coupon.stacking.stackable)
+                    .push_bind(coupon.stacking.priority)
+                    .push_bind(coupon.params.as_ref().map(Json))
+                    .push_bind(coupon.max_discount_amount.map(|m| m as i32));
+            });
+
+            query_builder.push(
+                r#"
+                RETURNING id, code, type, value, one_shot, valid_from,
+                          valid_until, uses_count, max_uses, max_uses_per_customer,
+                          allowed_product_ids, denied_product_ids, min_product_price, exclude_discounted,
+                          campaign_id, stackable, priority, params, max_discount_amount,
+                          created_at, updated_at, deleted_at, version
+                "#,
+            );
+
+            let rows = query_builder
+                .build()
+                .fetch_all(&mut *tx)
+                .await
+                .map_err(|e| match &e {
+                    sqlx::Error::Database(db_err)
+                        if db_err.constraint() == Some("coupons_code_key") =>
+                    {
+                        "Coupon code already exists".to_string()
+                    }
+                    sqlx::Error::Database(db_err)
+                        if db_err.constraint() == Some("coupons_campaign_id_fkey") =>
+                    {
+                        "Campaign not found".to_string()
+                    }
+                    _ => check_violation(&e).unwrap_or_else(|| e.to_string()),
+                })?;
+
+            created.extend(rows.into_iter().map(Self::map_row_to_coupon));
+        }
+
+        tx.commit().await.map_err(|e| e.to_string())?;
+
+        Ok(created)
+    }
+
     async fn find(&self, code: &str) -> Result<Coupon, String> {
         let row = sqlx::query(
             r#"
             SELECT id, code, type, value, one_shot, valid_from,
-                   valid_until, uses_count, max_uses, created_at,
-                   updated_at, deleted_at
+                   valid_until, uses_count, max_uses, max_uses_per_customer, created_at,
+                   allowed_product_ids, denied_product_ids, min_product_price, exclude_discounted,
+                   campaign_id, stackable, priority, params, max_discount_amount,
+                   updated_at, deleted_at, version
             FROM coupons
             WHERE code = $1 AND deleted_at IS NULL
             "#,
@@ -142,7 +267,10 @@ impl CouponRepository for PostgresCouponRepository {
         let mut query_builder = QueryBuilder::new(
             r#"
         SELECT id, code, type, value, one_shot, valid_from, valid_until,
-               uses_count, max_uses, created_at, updated_at, deleted_at
+               uses_count, max_uses, max_uses_per_customer,
+               allowed_product_ids, denied_product_ids, min_product_price, exclude_discounted,
+               campaign_id, stackable, priority, params, max_discount_amount,
+               created_at, updated_at, deleted_at, version
         FROM coupons c
         WHERE c.deleted_at IS NULL
         "#,
@@ -207,7 +335,12 @@ impl CouponRepository for PostgresCouponRepository {
         })
     }
 
-    async fn update(&self, code: String, update: UpdateCoupon) -> Result<Coupon, String> {
+    async fn update(
+        &self,
+        code: String,
+        update: UpdateCoupon,
+        expected_versions: Option<Vec<u32>>,
+    ) -> Result<Coupon, String> {
         let now = Utc::now().naive_utc();
 
         let row = sqlx::query(
@@ -219,11 +352,29 @@ impl CouponRepository for PostgresCouponRepository {
                 valid_from = COALESCE($4, valid_from),
                 valid_until = COALESCE($5, valid_until),
                 max_uses = COALESCE($6, max_uses),
-                updated_at = $7
+                max_uses_per_customer = COALESCE($10, max_uses_per_customer),
+                allowed_product_ids = COALESCE($11, allowed_product_ids),
+                denied_product_ids = COALESCE($12, denied_product_ids),
+                min_product_price = COALESCE($13, min_product_price),
+                exclude_discounted = COALESCE($14, exclude_discounted),
+                campaign_id = COALESCE($15, campaign_id),
+                stackable = COALESCE($16, stackable),
+                priority = COALESCE($17, priority),
+                params = CASE
+                    WHEN COALESCE($1::coupon_discount_type, type) IN ('percent', 'fixed') OR $20
+                        THEN NULL
+                    ELSE COALESCE($18, params)
+                END,
+                max_discount_amount = COALESCE($19, max_discount_amount),
+                updated_at = $7,
+                version = version + 1
             WHERE code = $8 AND deleted_at IS NULL
+            AND ($9::INTEGER[] IS NULL OR version = ANY($9))
             RETURNING id, code, type, value, one_shot, valid_from,
-                      valid_until, uses_count, max_uses, created_at,
-                      updated_at, deleted_at
+                      valid_until, uses_count, max_uses, max_uses_per_customer, created_at,
+                      allowed_product_ids, denied_product_ids, min_product_price, exclude_discounted,
+                      campaign_id, stackable, priority, params, max_discount_amount,
+                      updated_at, deleted_at, version
             "#,
         )
         .bind(update.coupon_type.as_ref().map(|t| t.to_string()))
@@ -233,82 +384,237 @@ impl CouponRepository for PostgresCouponRepository {
         .bind(update.valid_until)
         .bind(update.max_uses.map(|m| m as i32))
         .bind(now)
-        .bind(code)
-        .fetch_one(&*self.pool)
+        .bind(&code)
+        .bind(expected_versions.map(|v| v.into_iter().map(|v| v as i32).collect::<Vec<i32>>()))
+        .bind(update.max_uses_per_customer.map(|m| m as i32))
+        .bind(update.rules.allowed_product_ids)
+        .bind(update.rules.denied_product_ids)
+        .bind(update.rules.min_product_price.map(|p| p as i32))
+        .bind(update.rules.exclude_discounted)
+        .bind(update.campaign_id)
+        .bind(update.stacking.stackable)
+        .bind(update.stacking.priority)
+        .bind(update.params.clone().flatten().map(Json))
+        .bind(update.max_discount_amount.map(|m| m as i32))
+        .bind(update.params.is_some_and(|params| params.is_none()))
+        .fetch_optional(&*self.pool)
         .await
-        .map_err(|e| {
-            if let sqlx::Error::RowNotFound = e {
-                "Coupon not found".to_string()
-            } else {
-                e.to_string()
+        .map_err(|e| match &e {
+            sqlx::Error::Database(db_err)
+                if db_err.constraint() == Some("coupons_campaign_id_fkey") =>
+            {
+                "Campaign not found".to_string()
             }
+            _ => check_violation(&e).unwrap_or_else(|| e.to_string()),
         })?;
 
-        Ok(Self::map_row_to_coupon(row))
+        match row {
+            Some(row) => Ok(Self::map_row_to_coupon(row)),
+            None => Err(self.missing_or_stale(&code).await),
+        }
     }
 
-    async fn delete(&self, code: String) -> Result<(), String> {
+    async fn delete(
+        &self,
+        code: String,
+        expected_versions: Option<Vec<u32>>,
+    ) -> Result<(), String> {
         let now = Utc::now().naive_utc();
 
         let result = sqlx::query(
-            "UPDATE coupons SET deleted_at = $1 WHERE code = $2 AND deleted_at IS NULL",
+            r#"
+            UPDATE coupons SET deleted_at = $1, version = version + 1
+            WHERE code = $2 AND deleted_at IS NULL
+            AND ($3::INTEGER[] IS NULL OR version = ANY($3))
+            "#,
         )
         .bind(now)
-        .bind(code)
+        .bind(&code)
+        .bind(expected_versions.map(|v| v.into_iter().map(|v| v as i32).collect::<Vec<i32>>()))
         .execute(&*self.pool)
         .await
         .map_err(|e| e.to_string())?;
 
         if result.rows_affected() == 0 {
-            return Err("Coupon not found".to_string());
+            return Err(self.missing_or_stale(&code).await);
         }
 
         Ok(())
     }
 
-    async fn find_valid_coupon_by_code(&self, code: &str) -> Result<Coupon, String> {
-        let now = Utc::now();
-
+    async fn find_with_deleted(&self, code: &str) -> Result<Coupon, String> {
         let row = sqlx::query(
             r#"
             SELECT id, code, type, value, one_shot, valid_from,
-                   valid_until, uses_count, max_uses, created_at,
-                   updated_at, deleted_at
+                   valid_until, uses_count, max_uses, max_uses_per_customer, created_at,
+                   allowed_product_ids, denied_product_ids, min_product_price, exclude_discounted,
+                   campaign_id, stackable, priority, params, max_discount_amount,
+                   updated_at, deleted_at, version
             FROM coupons
             WHERE code = $1
-              AND deleted_at IS NULL
-              AND valid_from <= $2
-              AND valid_until >= $2
-              AND (max_uses IS NULL OR uses_count < max_uses)
             "#,
         )
         .bind(code)
-        .bind(now)
-        .fetch_one(&*self.pool)
+        .fetch_optional(&*self.pool)
         .await
-        .map_err(|e| {
-            if let sqlx::Error::RowNotFound = e {
-                "Coupon not found".to_string()
-            } else {
-                e.to_string()
-            }
-        })?;
+        .map_err(|e| e.to_string())?;
 
-        Ok(Self::map_row_to_coupon(row))
+        match row {
+            Some(row) => Ok(Self::map_row_to_coupon(row)),
+            None => Err("Coupon not found".to_string()),
+        }
+    }
+
+    async fn count_customer_redemptions(
+        &self,
+        coupon_id: Uuid,
+        customer_ref: &str,
+    ) -> Result<u64, String> {
+        let count: i64 = sqlx::query_scalar(CUSTOMER_USES_QUERY)
+            .bind(coupon_id)
+            .bind(customer_ref)
+            .fetch_one(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(count as u64)
     }
 
-    async fn increment_uses(&self, coupon_id: String) {
-        if let Ok(uuid) = Uuid::parse_str(&coupon_id) {
-            let _ = sqlx::query("UPDATE coupons SET uses_count = uses_count + 1 WHERE id = $1")
-                .bind(uuid)
-                .execute(&*self.pool)
-                .await;
+    async fn find_redemptions(
+        &self,
+        code: &str,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedResponse<CouponRedemption>, String> {
+        let page = page.unwrap_or(1) as i64;
+        let limit = limit.unwrap_or(10) as i64;
+        let offset = (page - 1) * limit;
+
+        // Redemptions stay visible after the coupon itself is deleted
+        let coupon_id: Uuid = sqlx::query_scalar("SELECT id FROM coupons WHERE code = $1")
+            .bind(code)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| "Coupon not found".to_string())?;
+
+        let rows = sqlx::query(
+            r#"
+            SELECT id, coupon_id, product_id, application_id, order_id, discount_amount,
+                   customer_ref, redeemed_at, reversed_at
+            FROM coupon_redemptions
+            WHERE coupon_id = $1
+            ORDER BY redeemed_at DESC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(coupon_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        let redemptions = rows
+            .into_iter()
+            .map(|row| CouponRedemption {
+                id: row.get("id"),
+                coupon_id: row.get("coupon_id"),
+                product_id: row.get("product_id"),
+                application_id: row.get("application_id"),
+                order_id: row.get("order_id"),
+                discount_amount: row.get::<i64, _>("discount_amount") as u64,
+                customer_ref: row.get("customer_ref"),
+                redeemed_at: row.get::<chrono::NaiveDateTime, _>("redeemed_at").and_utc(),
+                reversed_at: row
+                    .get::<Option<chrono::NaiveDateTime>, _>("reversed_at")
+                    .map(|dt| dt.and_utc()),
+            })
+            .collect();
+
+        let total_items: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM coupon_redemptions WHERE coupon_id = $1")
+                .bind(coupon_id)
+                .fetch_one(&*self.pool)
+                .await
+                .map_err(|e| e.to_string())?;
+
+        let total_items = total_items as u64;
+        let total_pages = ((total_items as f64) / (limit as f64)).ceil() as u32;
+
+        Ok(PaginatedResponse {
+            data: redemptions,
+            meta: PaginationMeta {
+                page: page as u32,
+                limit: limit as u32,
+                total_items,
+                total_pages,
+            },
+        })
+    }
+
+    async fn reconcile_uses_count(&self) -> Result<u64, String> {
+        let drifted: Vec<Uuid> = sqlx::query_scalar(
+            r#"
+            SELECT c.id
+            FROM coupons c
+            LEFT JOIN coupon_redemptions r ON r.coupon_id = c.id AND r.reversed_at IS NULL
+            GROUP BY c.id
+            HAVING c.uses_count <> COUNT(DISTINCT COALESCE(r.order_id, r.id))
+            "#,
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        // Redemptions are written under the coupon row lock, so the count
+        // can't move while the row is held.
+        let mut corrected = 0;
+        for coupon_id in drifted {
+            let mut transaction = self.pool.begin().await.map_err(|e| e.to_string())?;
+            sqlx::query("SELECT id FROM coupons WHERE id = $1 FOR UPDATE")
+                .bind(coupon_id)
+                .execute(&mut *transaction)
+                .await
+                .map_err(|e| e.to_string())?;
+            let result = sqlx::query(&format!(
+                r#"
+                UPDATE coupons
+                SET uses_count = ({uses})
+                WHERE id = $1
+                AND uses_count <> ({uses})
+                "#,
+                uses = USES_QUERY
+            ))
+            .bind(coupon_id)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| e.to_string())?;
+            transaction.commit().await.map_err(|e| e.to_string())?;
+            corrected += result.rows_affected();
         }
+
+        Ok(corrected)
     }
 }
 
 impl PostgresCouponRepository {
-    fn map_row_to_coupon(row: sqlx::postgres::PgRow) -> Coupon {
+    async fn missing_or_stale(&self, code: &str) -> String {
+        let exists = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM coupons WHERE code = $1 AND deleted_at IS NULL)",
+        )
+        .bind(code)
+        .fetch_one(&*self.pool)
+        .await;
+
+        match exists {
+            Ok(true) => "Version mismatch".to_string(),
+            Ok(false) => "Coupon not found".to_string(),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    pub(crate) fn map_row_to_coupon(row: sqlx::postgres::PgRow) -> Coupon {
         Coupon {
             id: row.get("id"),
             code: row.get("code"),
@@ -319,6 +625,29 @@ impl PostgresCouponRepository {
             valid_until: row.get::<chrono::NaiveDateTime, _>("valid_until").and_utc(),
             uses_count: row.get::<i32, _>("uses_count") as u32,
             max_uses: row.get::<Option<i32>, _>("max_uses").map(|v| v as u32),
+            max_uses_per_customer: row
+                .get::<Option<i32>, _>("max_uses_per_customer")
+                .map(|v| v as u32),
+            rules: CouponRules {
+                allowed_product_ids: row.get("allowed_product_ids"),
+                denied_product_ids: row.get("denied_product_ids"),
+                min_product_price: row
+                    .get::<Option<i32>, _>("min_product_price")
+                    .map(|v| v as u64),
+                exclude_discounted: row.get("exclude_discounted"),
+            },
+            campaign_id: row.get("campaign_id"),
+            params: row
+                .get::<Option<Json<CouponParams>>, _>("params")
+                .map(|params| params.0),
+            max_discount_amount: row
+                .get::<Option<i32>, _>("max_discount_amount")
+                .map(|m| m as u64),
+            stacking: CouponStacking {
+                stackable: row.get("stackable"),
+                priority: row.get("priority"),
+            },
+            version: row.get::<i32, _>("version") as u32,
             created_at: row.get::<chrono::NaiveDateTime, _>("created_at").and_utc(),
             updated_at: row
                 .get::<Option<chrono::NaiveDateTime>, _>("updated_at")
@@ -1,92 +1,396 @@
-use crate::domain::entity::{
-    discount_entity::{DiscountDiscount, PaginatedResponse, PaginationMeta},
-    product_entity::{CreateDiscount, Discount, UpdateDiscount},
-};
-use crate::domain::repository::product_repository::DiscountRepository;
 use async_trait::async_trait;
-use chrono::Utc;
-use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
+use crate::{
+    application::repository::{
+        coupon_in_memory_repository::InMemoryCouponRepository,
+        product_in_memory_repository::InMemoryProductRepository,
+    },
+    domain::{
+        entity::{
+            coupon_entity::{Coupon, CouponRedemption},
+            discount_entity::{
+                DiscountApplication, DiscountPeriod, PaginatedResponse, PaginationMeta,
+                ProductDiscount, ProductDiscountInfo, RemovalReason,
+            },
+            product_entity::Product,
+        },
+        pricing::pricing_engine::PricingEngine,
+        repository::discount_repository::DiscountRepository,
+    },
+};
+
+/// Applications kept in memory, reading products and coupons from the
+/// in-memory repositories it was built from and writing to the coupons'
+/// redemption ledger. Campaign budgets are not tracked.
 #[derive(Debug, Clone)]
 pub struct InMemoryDiscountRepository {
-    products: Arc<RwLock<HashMap<String, Discount>>>,
-    discounts: Arc<RwLock<HashMap<String, DiscountDiscount>>>,
+    applications: Arc<RwLock<Vec<ProductDiscount>>>,
+    products: Arc<RwLock<HashMap<String, Product>>>,
+    product_discounts: Arc<RwLock<HashMap<String, ProductDiscount>>>,
+    coupons: Arc<RwLock<Vec<Coupon>>>,
+    redemptions: Arc<RwLock<Vec<CouponRedemption>>>,
 }
 
 impl InMemoryDiscountRepository {
-    pub fn new() -> Self {
+    pub fn new(products: &InMemoryProductRepository, coupons: &InMemoryCouponRepository) -> Self {
         Self {
-            products: Arc::new(RwLock::new(HashMap::new())),
-            discounts: Arc::new(RwLock::new(HashMap::new())),
+            applications: Arc::new(RwLock::new(Vec::new())),
+            products: products.products.clone(),
+            product_discounts: products.discounts.clone(),
+            coupons: coupons.coupons.clone(),
+            redemptions: coupons.redemptions.clone(),
         }
     }
-}
 
-unsafe impl Send for InMemoryDiscountRepository {}
-unsafe impl Sync for InMemoryDiscountRepository {}
+    /// Open applications of `product_ids`, with their coupons, oldest first.
+    async fn open_applications(&self, product_ids: &[Uuid]) -> Vec<(ProductDiscount, Coupon)> {
+        let applications = self.applications.read().await;
+        let coupons = self.coupons.read().await;
+
+        let mut open: Vec<(ProductDiscount, Coupon)> = applications
+            .iter()
+            .filter(|a| a.removed_at.is_none() && product_ids.contains(&a.product_id))
+            .filter_map(|a| {
+                coupons
+                    .iter()
+                    .find(|c| c.id == a.coupon_id)
+                    .map(|c| (a.clone(), c.clone()))
+            })
+            .collect();
+        open.sort_by_key(|(a, _)| a.applied_at);
+        open
+    }
+
+    /// One page of the applications `matches` accepts, newest first.
+    async fn find_history(
+        &self,
+        matches: impl Fn(&ProductDiscount) -> bool,
+        period: DiscountPeriod,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> PaginatedResponse<DiscountApplication> {
+        let page = page.unwrap_or(1);
+        let limit = limit.unwrap_or(10);
+
+        let applications = self.applications.read().await;
+        let coupons = self.coupons.read().await;
+        let mut filtered: Vec<DiscountApplication> = applications
+            .iter()
+            .filter(|a| matches(a) && period.overlaps(a))
+            .map(|a| DiscountApplication {
+                discount: a.clone(),
+                coupon_code: coupons
+                    .iter()
+                    .find(|c| c.id == a.coupon_id)
+                    .map(|c| c.code.clone())
+                    .unwrap_or_default(),
+            })
+            .collect();
+        filtered.sort_by_key(|a| std::cmp::Reverse(a.discount.applied_at));
+
+        let total_items = filtered.len() as u64;
+        let total_pages = (total_items as f64 / limit as f64).ceil() as u32;
+        let data = filtered
+            .into_iter()
+            .skip(((page - 1) * limit) as usize)
+            .take(limit as usize)
+            .collect();
+
+        PaginatedResponse {
+            data,
+            meta: PaginationMeta {
+                page,
+                limit,
+                total_items,
+                total_pages,
+            },
+        }
+    }
+}
 
 #[async_trait]
 impl DiscountRepository for InMemoryDiscountRepository {
-    async fn apply_coupon(&self, product_id: Uuid, coupon_id: Uuid) -> Result<(), String> {
-        let mut applications = self.coupon_applications.write().await;
+    async fn apply_coupon(
+        &self,
+        product_id: String,
+        coupon_code: String,
+        customer_ref: Option<String>,
+        pricing: PricingEngine,
+    ) -> Result<ProductDiscount, String> {
+        let product_uuid =
+            Uuid::parse_str(&product_id).map_err(|e| format!("Invalid product ID: {}", e))?;
+        let now = Utc::now();
 
-        applications.retain(|app| {
-            app.product_id != product_id
-                || (app.product_id == product_id && app.removed_at.is_some())
-        });
+        let coupon = {
+            let coupons = self.coupons.read().await;
+            let coupon = coupons
+                .iter()
+                .find(|c| c.code == coupon_code && c.deleted_at.is_none())
+                .cloned()
+                .ok_or_else(|| "Coupon not found".to_string())?;
+            if coupon.valid_from > now || coupon.valid_until < now {
+                return Err("Coupon is not valid".to_string());
+            }
+            coupon
+        };
+
+        let global_limit = match coupon.one_shot {
+            true => Some(1),
+            false => coupon.max_uses,
+        };
+        if global_limit.is_some_and(|limit| coupon.uses_count >= limit) {
+            return Err("Coupon usage limit reached".to_string());
+        }
+        if let Some(per_customer) = coupon.max_uses_per_customer {
+            let Some(customer_ref) = &customer_ref else {
+                return Err("Customer reference is required for this coupon".to_string());
+            };
+            let customer_uses = self
+                .redemptions
+                .read()
+                .await
+                .iter()
+                .filter(|r| {
+                    r.coupon_id == coupon.id && r.customer_ref.as_ref() == Some(customer_ref)
+                })
+                .map(CouponRedemption::use_id)
+                .collect::<HashSet<_>>()
+                .len() as u32;
+            if customer_uses >= per_customer {
+                return Err("Customer usage limit reached".to_string());
+            }
+        }
+
+        let price = self
+            .products
+            .read()
+            .await
+            .get(&product_id)
+            .filter(|p| p.deleted_at.is_none())
+            .map(|p| p.price)
+            .ok_or_else(|| "Product not found".to_string())?;
+
+        let existing = self.open_applications(&[product_uuid]).await;
+        if existing.iter().any(|(_, c)| c.id == coupon.id) {
+            return Err("Coupon already applied to product".to_string());
+        }
+        let stacks = existing.is_empty()
+            || (coupon.stacking.stackable && existing.iter().all(|(_, c)| c.stacking.stackable));
+        if !stacks {
+            return Err("Product already has an active coupon".to_string());
+        }
+
+        let reasons = coupon
+            .rules
+            .ineligibility_reasons(product_uuid, price, !existing.is_empty());
+        if let Some(reason) = reasons.first() {
+            return Err(format!("Coupon not eligible: {}", reason));
+        }
+
+        // What this coupon takes off once stacked with the ones still in effect.
+        let mut stack: Vec<ProductDiscountInfo> = existing
+            .iter()
+            .filter(|(_, c)| c.is_active_at(now))
+            .map(|(d, c)| ProductDiscountInfo::new(c, d.applied_at))
+            .collect();
+        stack.push(ProductDiscountInfo::new(&coupon, now));
+        let discount_amount = pricing
+            .price(price, stack)
+            .discounts
+            .into_iter()
+            .find(|d| d.code == coupon.code)
+            .map_or(0, |d| d.amount);
 
-        let new_application = DiscountCouponApplication {
+        let application = ProductDiscount {
             id: Uuid::new_v4(),
-            product_id,
-            coupon_id,
-            applied_at: Utc::now(),
+            product_id: product_uuid,
+            coupon_id: coupon.id,
+            applied_at: now,
             removed_at: None,
+            removal_reason: None,
         };
+        self.applications.write().await.push(application.clone());
+        self.product_discounts
+            .write()
+            .await
+            .insert(product_id, application.clone());
+
+        if let Some(c) = self
+            .coupons
+            .write()
+            .await
+            .iter_mut()
+            .find(|c| c.id == coupon.id)
+        {
+            c.uses_count += 1;
+        }
+        self.redemptions.write().await.push(CouponRedemption {
+            id: Uuid::new_v4(),
+            coupon_id: coupon.id,
+            product_id: product_uuid,
+            application_id: Some(application.id),
+            order_id: None,
+            discount_amount,
+            customer_ref,
+            redeemed_at: now,
+            reversed_at: None,
+        });
 
-        applications.push(new_application);
-        Ok(())
+        Ok(application)
     }
 
-    async fn remove_coupon(&self, product_id: Uuid) -> Result<(), String> {
-        let mut applications = self.coupon_applications.write().await;
-        let now = Utc::now();
+    async fn remove_coupon(
+        &self,
+        product_id: String,
+        coupon_code: String,
+    ) -> Result<ProductDiscount, String> {
+        let coupon_ids: Vec<Uuid> = self
+            .coupons
+            .read()
+            .await
+            .iter()
+            .filter(|c| c.code == coupon_code)
+            .map(|c| c.id)
+            .collect();
 
-        if let Some(app) = applications
+        let mut applications = self.applications.write().await;
+        let application = applications
             .iter_mut()
-            .find(|app| app.product_id == product_id && app.removed_at.is_none())
+            .find(|a| {
+                a.product_id.to_string() == product_id
+                    && coupon_ids.contains(&a.coupon_id)
+                    && a.removed_at.is_none()
+            })
+            .ok_or_else(|| "No active coupon found for product".to_string())?;
+        application.removed_at = Some(Utc::now());
+        application.removal_reason = Some(RemovalReason::Removed);
+        let removed = application.clone();
+
+        if !applications
+            .iter()
+            .any(|a| a.product_id == removed.product_id && a.removed_at.is_none())
         {
-            app.removed_at = Some(now);
-            Ok(())
-        } else {
-            Err("No active coupon found for this product".to_string())
+            self.product_discounts.write().await.remove(&product_id);
         }
+
+        Ok(removed)
     }
 
-    async fn get_active_coupon(&self, product_id: Uuid) -> Option<Uuid> {
-        let applications = self.coupon_applications.read().await;
-        applications
-            .iter()
-            .find(|app| app.product_id == product_id && app.removed_at.is_none())
-            .map(|app| app.coupon_id)
+    async fn find_active_discounts(
+        &self,
+        product_id: String,
+    ) -> Result<Vec<(ProductDiscount, Coupon)>, String> {
+        let product_uuid =
+            Uuid::parse_str(&product_id).map_err(|e| format!("Invalid product ID: {}", e))?;
+        let now = Utc::now();
+
+        Ok(self
+            .open_applications(&[product_uuid])
+            .await
+            .into_iter()
+            .filter(|(_, coupon)| coupon.is_active_at(now))
+            .collect())
     }
 
-    async fn has_discount(&self, product_id: String) -> bool {
-        if let Ok(uuid) = Uuid::parse_str(&product_id) {
-            self.get_active_coupon(uuid).await.is_some()
-        } else {
-            false
+    async fn find_active_discounts_for(
+        &self,
+        product_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<(ProductDiscount, Coupon)>>, String> {
+        let now = Utc::now();
+
+        let mut by_product: HashMap<Uuid, Vec<(ProductDiscount, Coupon)>> = HashMap::new();
+        for (discount, coupon) in self.open_applications(product_ids).await {
+            if coupon.is_active_at(now) {
+                by_product
+                    .entry(discount.product_id)
+                    .or_default()
+                    .push((discount, coupon));
+            }
+        }
+        Ok(by_product)
+    }
+
+    async fn close_dead_applications(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<ProductDiscount>, String> {
+        let coupons = self.coupons.read().await;
+        let mut applications = self.applications.write().await;
+
+        let mut closed = Vec::new();
+        for application in applications.iter_mut().filter(|a| a.removed_at.is_none()) {
+            let Some(coupon) = coupons.iter().find(|c| c.id == application.coupon_id) else {
+                continue;
+            };
+            let reason = match (coupon.deleted_at, coupon.valid_until < now) {
+                (Some(_), _) => RemovalReason::CouponDeleted,
+                (None, true) => RemovalReason::Expired,
+                (None, false) => continue,
+            };
+            application.removed_at = Some(now);
+            application.removal_reason = Some(reason);
+            closed.push(application.clone());
+        }
+
+        let mut product_discounts = self.product_discounts.write().await;
+        for application in &closed {
+            if !applications
+                .iter()
+                .any(|a| a.product_id == application.product_id && a.removed_at.is_none())
+            {
+                product_discounts.remove(&application.product_id.to_string());
+            }
         }
+
+        Ok(closed)
     }
 
-    async fn has_discount(&self, product_id: String) -> bool {
-        let discounts = self.discounts.read().await;
-        if let Some(discount) = discounts.get(&product_id) {
-            discount.applied_at > Utc::now() - chrono::Duration::days(30)
-        } else {
-            false
+    async fn find_product_history(
+        &self,
+        product_id: String,
+        period: DiscountPeriod,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedResponse<DiscountApplication>, String> {
+        let product = self
+            .products
+            .read()
+            .await
+            .get(&product_id)
+            .map(|p| p.id)
+            .ok_or_else(|| "Product not found".to_string())?;
+
+        Ok(self
+            .find_history(|a| a.product_id == product, period, page, limit)
+            .await)
+    }
+
+    async fn find_coupon_applications(
+        &self,
+        code: &str,
+        period: DiscountPeriod,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedResponse<DiscountApplication>, String> {
+        let coupon_ids: Vec<Uuid> = self
+            .coupons
+            .read()
+            .await
+            .iter()
+            .filter(|c| c.code == code)
+            .map(|c| c.id)
+            .collect();
+        if coupon_ids.is_empty() {
+            return Err("Coupon not found".to_string());
         }
+
+        Ok(self
+            .find_history(|a| coupon_ids.contains(&a.coupon_id), period, page, limit)
+            .await)
     }
 }
@@ -1,15 +1,27 @@
 use async_trait::async_trait;
-use chrono::Utc;
-use sqlx::{Row, postgres::PgPool};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use sqlx::{PgConnection, Row, postgres::PgPool};
+use std::collections::HashMap;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use crate::domain::{
-    entity::{
-        coupon_entity::{Coupon, CouponType},
-        discount_entity::ProductDiscount,
+use crate::{
+    application::repository::{
+        campaign_postgres_repository::PostgresCampaignRepository,
+        coupon_postgres_repository::{CUSTOMER_USES_QUERY, PostgresCouponRepository},
+    },
+    domain::{
+        entity::{
+            coupon_entity::Coupon,
+            discount_entity::{
+                DiscountApplication, DiscountPeriod, PaginatedResponse, PaginationMeta,
+                ProductDiscount, ProductDiscountInfo,
+            },
+        },
+        pricing::pricing_engine::PricingEngine,
+        repository::discount_repository::DiscountRepository,
+        utils::validation::{FieldError, validation_error},
     },
-    repository::discount_repository::DiscountRepository,
 };
 
 pub struct PostgresDiscountRepository {
@@ -28,44 +40,81 @@ impl DiscountRepository for PostgresDiscountRepository {
         &self,
         product_id: String,
         coupon_code: String,
+        customer_ref: Option<String>,
+        pricing: PricingEngine,
     ) -> Result<ProductDiscount, String> {
+        let product_uuid =
+            Uuid::parse_str(&product_id).map_err(|e| format!("Invalid product ID: {}", e))?;
         let now = Utc::now().naive_utc();
         let mut transaction = self.pool.begin().await.map_err(|e| e.to_string())?;
 
-        let coupon_id: Uuid = match sqlx::query(
-            r#"
-            SELECT id FROM coupons 
-            WHERE code = $1 AND deleted_at IS NULL
-            "#,
+        let coupon = Self::lock_redeemable_coupon(
+            &mut transaction,
+            &coupon_code,
+            customer_ref.as_deref(),
+            now,
+        )
+        .await?;
+        let coupon_id = coupon.id;
+
+        // Locking the product serializes every apply on it, so two stackable
+        // and non-stackable coupons cannot slip past each other's check.
+        let price = match sqlx::query_scalar::<_, i32>(
+            "SELECT price FROM products WHERE id = $1 AND deleted_at IS NULL FOR UPDATE",
         )
-        .bind(&coupon_code)
+        .bind(product_uuid)
         .fetch_optional(&mut *transaction)
         .await
         .map_err(|e| e.to_string())?
         {
-            Some(row) => row.get("id"),
-            None => return Err("Coupon not found".to_string()),
+            Some(price) => price as u64,
+            None => return Err("Product not found".to_string()),
         };
 
-        let valid_coupon = sqlx::query(
-            r#"
-            SELECT id FROM coupons
-            WHERE id = $1
-            AND deleted_at IS NULL
-            AND valid_from <= $2
-            AND valid_until >= $2
-            AND (max_uses IS NULL OR uses_count < max_uses)
-            FOR UPDATE
-            "#,
-        )
-        .bind(coupon_id)
-        .bind(now)
-        .fetch_optional(&mut *transaction)
-        .await
-        .map_err(|e| e.to_string())?;
+        let existing: Vec<(ProductDiscount, Coupon)> = sqlx::query(ACTIVE_APPLICATIONS_QUERY)
+            .bind(&[product_uuid][..])
+            .fetch_all(&mut *transaction)
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(Self::map_row_to_application)
+            .collect();
 
-        if valid_coupon.is_none() {
-            return Err("Coupon is not valid".to_string());
+        if existing.iter().any(|(_, c)| c.id == coupon.id) {
+            return Err("Coupon already applied to product".to_string());
+        }
+        let stacks = existing.is_empty()
+            || (coupon.stacking.stackable && existing.iter().all(|(_, c)| c.stacking.stackable));
+        if !stacks {
+            return Err("Product already has an active coupon".to_string());
+        }
+
+        let reasons = coupon
+            .rules
+            .ineligibility_reasons(product_uuid, price, !existing.is_empty());
+        if let Some(reason) = reasons.first() {
+            return Err(format!("Coupon not eligible: {}", reason));
+        }
+
+        // What this coupon takes off once stacked with the ones still in effect.
+        let applied_at = now.and_utc();
+        let mut stack: Vec<ProductDiscountInfo> = existing
+            .iter()
+            .filter(|(_, c)| c.is_active_at(applied_at))
+            .map(|(d, c)| ProductDiscountInfo::new(c, d.applied_at))
+            .collect();
+        stack.push(ProductDiscountInfo::new(&coupon, applied_at));
+        let discount_amount = pricing
+            .price(price, stack)
+            .discounts
+            .into_iter()
+            .find(|d| d.code == coupon.code)
+            .map_or(0, |d| d.amount);
+
+        // The campaign row is locked after the coupon row, so every apply
+        // takes the two locks in the same order.
+        if let Some(campaign_id) = coupon.campaign_id {
+            Self::charge_campaign(&mut transaction, campaign_id, discount_amount, now).await?;
         }
 
         let application_id = Uuid::new_v4();
@@ -75,24 +124,42 @@ impl DiscountRepository for PostgresDiscountRepository {
                 id, product_id, coupon_id, applied_at
             )
             VALUES ($1, $2, $3, $4)
-            RETURNING id, product_id, coupon_id, applied_at, removed_at
+            RETURNING id, product_id, coupon_id, applied_at, removed_at, removal_reason
             "#,
         )
         .bind(application_id)
-        .bind(product_id)
+        .bind(product_uuid)
         .bind(coupon_id)
         .bind(now)
         .fetch_one(&mut *transaction)
         .await
-        .map_err(|e| {
-            if let sqlx::Error::Database(db_err) = &e {
-                if db_err.constraint() == Some("idx_unique_active_coupon") {
-                    return "Product already has an active coupon".to_string();
-                }
+        .map_err(|e| match &e {
+            sqlx::Error::Database(db_err)
+                if db_err.constraint() == Some("idx_unique_active_coupon_per_product") =>
+            {
+                "Coupon already applied to product".to_string()
             }
-            e.to_string()
+            _ => e.to_string(),
         })?;
 
+        sqlx::query(
+            r#"
+            INSERT INTO coupon_redemptions (
+                coupon_id, product_id, application_id, discount_amount, customer_ref, redeemed_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+        )
+        .bind(coupon_id)
+        .bind(product_uuid)
+        .bind(application_id)
+        .bind(discount_amount as i64)
+        .bind(customer_ref)
+        .bind(now)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| e.to_string())?;
+
         sqlx::query(
             r#"
             UPDATE coupons
@@ -107,17 +174,7 @@ impl DiscountRepository for PostgresDiscountRepository {
 
         transaction.commit().await.map_err(|e| e.to_string())?;
 
-        Ok(ProductDiscount {
-            id: result.get("id"),
-            product_id: result.get("product_id"),
-            coupon_id: result.get("coupon_id"),
-            applied_at: result
-                .get::<chrono::NaiveDateTime, _>("applied_at")
-                .and_utc(),
-            removed_at: result
-                .get::<Option<chrono::NaiveDateTime>, _>("removed_at")
-                .map(|dt| dt.and_utc()),
-        })
+        Ok(Self::map_row_to_discount(&result))
     }
 
     async fn remove_coupon(
@@ -125,23 +182,25 @@ impl DiscountRepository for PostgresDiscountRepository {
         product_id: String,
         coupon_code: String,
     ) -> Result<ProductDiscount, String> {
+        let product_uuid =
+            Uuid::parse_str(&product_id).map_err(|e| format!("Invalid product ID: {}", e))?;
         let now = Utc::now().naive_utc();
 
         let result = sqlx::query(
             r#"
             UPDATE product_coupon_applications
-            SET removed_at = $1
+            SET removed_at = $1, removal_reason = 'removed'
             WHERE product_id = $2
             AND coupon_id IN (
                 SELECT id FROM coupons
                 WHERE code = $3
             )
             AND removed_at IS NULL
-            RETURNING id, product_id, coupon_id, applied_at, removed_at
+            RETURNING id, product_id, coupon_id, applied_at, removed_at, removal_reason
             "#,
         )
         .bind(now)
-        .bind(product_id)
+        .bind(product_uuid)
         .bind(coupon_code)
         .fetch_one(&*self.pool)
         .await
@@ -153,88 +212,401 @@ impl DiscountRepository for PostgresDiscountRepository {
             }
         })?;
 
-        Ok(ProductDiscount {
-            id: result.get("id"),
-            product_id: result.get("product_id"),
-            coupon_id: result.get("coupon_id"),
-            applied_at: result
-                .get::<chrono::NaiveDateTime, _>("applied_at")
-                .and_utc(),
-            removed_at: result
-                .get::<Option<chrono::NaiveDateTime>, _>("removed_at")
-                .map(|dt| dt.and_utc()),
-        })
+        Ok(Self::map_row_to_discount(&result))
     }
 
-    async fn find_active_discount(
+    async fn find_active_discounts(
         &self,
         product_id: String,
-    ) -> Result<Option<(ProductDiscount, Coupon)>, String> {
+    ) -> Result<Vec<(ProductDiscount, Coupon)>, String> {
         let product_uuid =
             Uuid::parse_str(&product_id).map_err(|e| format!("Invalid product ID: {}", e))?;
+        let now = Utc::now();
 
-        let row = sqlx::query(
+        let rows = sqlx::query(ACTIVE_APPLICATIONS_QUERY)
+            .bind(&[product_uuid][..])
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| {
+                log::error!("{}", e);
+                e.to_string()
+            })?;
+
+        Ok(rows
+            .into_iter()
+            .map(Self::map_row_to_application)
+            .filter(|(_, coupon)| coupon.is_active_at(now))
+            .collect())
+    }
+
+    async fn find_active_discounts_for(
+        &self,
+        product_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<(ProductDiscount, Coupon)>>, String> {
+        let mut connection = self.pool.acquire().await.map_err(|e| e.to_string())?;
+        Self::active_applications(&mut connection, product_ids, Utc::now()).await
+    }
+
+    async fn close_dead_applications(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<ProductDiscount>, String> {
+        // SKIP LOCKED leaves rows held by an apply, or by the same job running
+        // on another instance, to whoever holds them; the next run catches up.
+        let rows = sqlx::query(
             r#"
-        SELECT 
-            pca.id, pca.product_id, pca.coupon_id, pca.applied_at, pca.removed_at,
-            c.code, c.type AS coupon_type, c.value, c.one_shot, 
-            c.valid_from, c.valid_until, c.uses_count, c.max_uses,
-            c.created_at AS coupon_created, c.updated_at AS coupon_updated, c.deleted_at
-        FROM product_coupon_applications pca
-        JOIN coupons c ON c.id = pca.coupon_id
-        WHERE pca.product_id = $1 
-        AND pca.removed_at IS NULL
-        AND c.deleted_at IS NULL
-        AND c.valid_from <= NOW() AT TIME ZONE 'UTC'
-        AND c.valid_until >= NOW() AT TIME ZONE 'UTC'
-        "#,
+            WITH dead AS (
+                SELECT pca.id,
+                       CASE WHEN c.deleted_at IS NOT NULL THEN 'coupon_deleted'
+                            ELSE 'expired'
+                       END::application_removal_reason AS reason
+                FROM product_coupon_applications pca
+                JOIN coupons c ON c.id = pca.coupon_id
+                WHERE pca.removed_at IS NULL
+                AND (c.deleted_at IS NOT NULL OR c.valid_until < $1)
+                FOR UPDATE OF pca SKIP LOCKED
+            )
+            UPDATE product_coupon_applications pca
+            SET removed_at = $1, removal_reason = dead.reason
+            FROM dead
+            WHERE pca.id = dead.id
+            RETURNING pca.id, pca.product_id, pca.coupon_id, pca.applied_at,
+                      pca.removed_at, pca.removal_reason
+            "#,
         )
-        .bind(product_uuid)
-        .fetch_optional(&*self.pool)
+        .bind(now.naive_utc())
+        .fetch_all(&*self.pool)
         .await
-        .map_err(|e| {
-            log::error!("{}", e);
-            e.to_string()
-        })?;
+        .map_err(|e| e.to_string())?;
+
+        Ok(rows.iter().map(Self::map_row_to_discount).collect())
+    }
+
+    async fn find_product_history(
+        &self,
+        product_id: String,
+        period: DiscountPeriod,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedResponse<DiscountApplication>, String> {
+        let product_uuid =
+            Uuid::parse_str(&product_id).map_err(|_| "Product not found".to_string())?;
+
+        let exists: bool =
+            sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM products WHERE id = $1)")
+                .bind(product_uuid)
+                .fetch_one(&*self.pool)
+                .await
+                .map_err(|e| e.to_string())?;
+        if !exists {
+            return Err("Product not found".to_string());
+        }
+
+        self.find_history("product_id", &[product_uuid], period, page, limit)
+            .await
+    }
+
+    async fn find_coupon_applications(
+        &self,
+        code: &str,
+        period: DiscountPeriod,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedResponse<DiscountApplication>, String> {
+        let coupon_ids: Vec<Uuid> = sqlx::query_scalar("SELECT id FROM coupons WHERE code = $1")
+            .bind(code)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        if coupon_ids.is_empty() {
+            return Err("Coupon not found".to_string());
+        }
+
+        self.find_history("coupon_id", &coupon_ids, period, page, limit)
+            .await
+    }
+}
+
+/// Every application still attached to the products in `$1`, with its
+/// coupon, oldest first. Callers decide whether expired or deleted coupons count.
+const ACTIVE_APPLICATIONS_QUERY: &str = r#"
+    SELECT
+        pca.id AS application_id, pca.product_id, pca.applied_at, pca.removed_at, pca.removal_reason,
+        c.id, c.code, c.type, c.value, c.one_shot, c.valid_from, c.valid_until,
+        c.uses_count, c.max_uses, c.max_uses_per_customer,
+        c.allowed_product_ids, c.denied_product_ids, c.min_product_price, c.exclude_discounted,
+        c.campaign_id, c.stackable, c.priority, c.params, c.max_discount_amount,
+        c.created_at, c.updated_at, c.deleted_at, c.version
+    FROM product_coupon_applications pca
+    JOIN coupons c ON c.id = pca.coupon_id
+    WHERE pca.product_id = ANY($1)
+    AND pca.removed_at IS NULL
+    ORDER BY pca.applied_at
+"#;
+
+impl PostgresDiscountRepository {
+    /// Locks coupon `code` for a redemption at `now`, once it is known to be
+    /// valid and within its usage limits. Both limits are checked while
+    /// holding the coupon row lock, so concurrent redemptions of the same
+    /// coupon are serialized here.
+    pub(crate) async fn lock_redeemable_coupon(
+        connection: &mut PgConnection,
+        code: &str,
+        customer_ref: Option<&str>,
+        now: NaiveDateTime,
+    ) -> Result<Coupon, String> {
+        let coupon_id: Uuid = match sqlx::query(
+            r#"
+            SELECT id FROM coupons
+            WHERE code = $1 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(code)
+        .fetch_optional(&mut *connection)
+        .await
+        .map_err(|e| e.to_string())?
+        {
+            Some(row) => row.get("id"),
+            None => return Err("Coupon not found".to_string()),
+        };
+
+        let valid_coupon = sqlx::query(
+            r#"
+            SELECT id, code, type, value, one_shot, valid_from,
+                   valid_until, uses_count, max_uses, max_uses_per_customer,
+                   allowed_product_ids, denied_product_ids, min_product_price, exclude_discounted,
+                   campaign_id, stackable, priority, params, max_discount_amount,
+                   created_at, updated_at, deleted_at, version
+            FROM coupons
+            WHERE id = $1
+            AND deleted_at IS NULL
+            AND valid_from <= $2
+            AND valid_until >= $2
+            FOR UPDATE
+            "#,
+        )
+        .bind(coupon_id)
+        .bind(now)
+        .fetch_optional(&mut *connection)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        let Some(valid_coupon) = valid_coupon else {
+            return Err("Coupon is not valid".to_string());
+        };
+        let coupon = PostgresCouponRepository::map_row_to_coupon(valid_coupon);
 
-        match row {
-            Some(row) => {
-                let discount = ProductDiscount {
-                    id: row.get("id"),
-                    product_id: row.get("product_id"),
-                    coupon_id: row.get("coupon_id"),
-                    applied_at: row.get::<chrono::NaiveDateTime, _>("applied_at").and_utc(),
-                    removed_at: row
-                        .get::<Option<chrono::NaiveDateTime>, _>("removed_at")
-                        .map(|dt| dt.and_utc()),
-                };
-
-                let coupon_type: CouponType = row.get("coupon_type");
-
-                let coupon = Coupon {
-                    id: discount.coupon_id,
-                    code: row.get("code"),
-                    coupon_type,
-                    value: row.get::<i32, _>("value") as u64,
-                    one_shot: row.get("one_shot"),
-                    valid_from: row.get::<chrono::NaiveDateTime, _>("valid_from").and_utc(),
-                    valid_until: row.get::<chrono::NaiveDateTime, _>("valid_until").and_utc(),
-                    uses_count: row.get::<i32, _>("uses_count") as u32,
-                    max_uses: row.get::<Option<i32>, _>("max_uses").map(|v| v as u32),
-                    created_at: row
-                        .get::<chrono::NaiveDateTime, _>("coupon_created")
-                        .and_utc(),
-                    updated_at: row
-                        .get::<Option<chrono::NaiveDateTime>, _>("coupon_updated")
-                        .map(|dt| dt.and_utc()),
-                    deleted_at: row
-                        .get::<Option<chrono::NaiveDateTime>, _>("deleted_at")
-                        .map(|dt| dt.and_utc()),
-                };
-
-                Ok(Some((discount, coupon)))
+        let global_limit = match coupon.one_shot {
+            true => Some(1),
+            false => coupon.max_uses,
+        };
+        if global_limit.is_some_and(|limit| coupon.uses_count >= limit) {
+            return Err("Coupon usage limit reached".to_string());
+        }
+
+        if let Some(per_customer) = coupon.max_uses_per_customer {
+            let Some(customer_ref) = customer_ref else {
+                return Err("Customer reference is required for this coupon".to_string());
+            };
+
+            let customer_uses: i64 = sqlx::query_scalar(CUSTOMER_USES_QUERY)
+                .bind(coupon_id)
+                .bind(customer_ref)
+                .fetch_one(&mut *connection)
+                .await
+                .map_err(|e| e.to_string())?;
+
+            if customer_uses >= per_customer as i64 {
+                return Err("Customer usage limit reached".to_string());
             }
-            None => Ok(None),
         }
+
+        Ok(coupon)
+    }
+
+    /// The applications in effect at `now` on each of `product_ids`.
+    pub(crate) async fn active_applications(
+        connection: &mut PgConnection,
+        product_ids: &[Uuid],
+        now: DateTime<Utc>,
+    ) -> Result<HashMap<Uuid, Vec<(ProductDiscount, Coupon)>>, String> {
+        let rows = sqlx::query(ACTIVE_APPLICATIONS_QUERY)
+            .bind(product_ids)
+            .fetch_all(&mut *connection)
+            .await
+            .map_err(|e| {
+                log::error!("{}", e);
+                e.to_string()
+            })?;
+
+        let mut by_product: HashMap<Uuid, Vec<(ProductDiscount, Coupon)>> = HashMap::new();
+        for (discount, coupon) in rows.into_iter().map(Self::map_row_to_application) {
+            if coupon.is_active_at(now) {
+                by_product
+                    .entry(discount.product_id)
+                    .or_default()
+                    .push((discount, coupon));
+            }
+        }
+        Ok(by_product)
+    }
+
+    /// Locks campaign `campaign_id` and charges `amount` to its budget,
+    /// closing it once the budget is spent.
+    pub(crate) async fn charge_campaign(
+        connection: &mut PgConnection,
+        campaign_id: Uuid,
+        amount: u64,
+        now: NaiveDateTime,
+    ) -> Result<(), String> {
+        let campaign = sqlx::query(
+            r#"
+            SELECT id, name, starts_at, ends_at, budget, spent, status,
+                   closed_at, created_at, updated_at
+            FROM campaigns
+            WHERE id = $1
+            FOR UPDATE
+            "#,
+        )
+        .bind(campaign_id)
+        .fetch_one(&mut *connection)
+        .await
+        .map(PostgresCampaignRepository::map_row_to_campaign)
+        .map_err(|e| e.to_string())?;
+
+        if !campaign.is_open(now.and_utc()) {
+            return Err("Campaign is not active".to_string());
+        }
+        if amount > campaign.remaining_budget() {
+            return Err("Campaign budget exhausted".to_string());
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE campaigns SET
+                spent = spent + $2,
+                status = CASE WHEN spent + $2 >= budget
+                    THEN 'closed'::campaign_status ELSE status END,
+                closed_at = CASE WHEN spent + $2 >= budget THEN $3 ELSE closed_at END,
+                updated_at = $3
+            WHERE id = $1
+            "#,
+        )
+        .bind(campaign_id)
+        .bind(amount as i64)
+        .bind(now)
+        .execute(&mut *connection)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    /// One page of the applications whose `column` is in `ids`, newest first.
+    async fn find_history(
+        &self,
+        column: &str,
+        ids: &[Uuid],
+        period: DiscountPeriod,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedResponse<DiscountApplication>, String> {
+        let page = page.unwrap_or(1);
+        let limit = limit.unwrap_or(10);
+        let offset = (page.max(1) as i64 - 1)
+            .checked_mul(limit as i64)
+            .ok_or_else(|| {
+                validation_error(&[FieldError::new("page", "overflow", "Page is too large")])
+            })?;
+
+        // An application matches when it was in effect inside the period.
+        let filter = format!(
+            r#"
+            WHERE pca.{column} = ANY($1)
+            AND ($2::timestamp IS NULL OR pca.removed_at IS NULL OR pca.removed_at >= $2)
+            AND ($3::timestamp IS NULL OR pca.applied_at <= $3)
+            "#
+        );
+        let from = period.from.map(|dt| dt.naive_utc());
+        let to = period.to.map(|dt| dt.naive_utc());
+
+        let total_items: i64 = sqlx::query_scalar(&format!(
+            "SELECT COUNT(*) FROM product_coupon_applications pca {filter}"
+        ))
+        .bind(ids)
+        .bind(from)
+        .bind(to)
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT pca.id, pca.product_id, pca.coupon_id, pca.applied_at,
+                   pca.removed_at, pca.removal_reason, c.code
+            FROM product_coupon_applications pca
+            JOIN coupons c ON c.id = pca.coupon_id
+            {filter}
+            ORDER BY pca.applied_at DESC
+            LIMIT $4 OFFSET $5
+            "#
+        ))
+        .bind(ids)
+        .bind(from)
+        .bind(to)
+        .bind(limit as i64)
+        .bind(offset)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        let data = rows
+            .iter()
+            .map(|row| DiscountApplication {
+                discount: Self::map_row_to_discount(row),
+                coupon_code: row.get("code"),
+            })
+            .collect();
+
+        let total_items = total_items as u64;
+        let total_pages = (total_items as f64 / limit as f64).ceil() as u32;
+
+        Ok(PaginatedResponse {
+            data,
+            meta: PaginationMeta {
+                page,
+                limit,
+                total_items,
+                total_pages,
+            },
+        })
+    }
+
+    fn map_row_to_discount(row: &sqlx::postgres::PgRow) -> ProductDiscount {
+        ProductDiscount {
+            id: row.get("id"),
+            product_id: row.get("product_id"),
+            coupon_id: row.get("coupon_id"),
+            applied_at: row.get::<chrono::NaiveDateTime, _>("applied_at").and_utc(),
+            removed_at: row
+                .get::<Option<chrono::NaiveDateTime>, _>("removed_at")
+                .map(|dt| dt.and_utc()),
+            removal_reason: row.get("removal_reason"),
+        }
+    }
+
+    fn map_row_to_application(row: sqlx::postgres::PgRow) -> (ProductDiscount, Coupon) {
+        let discount = ProductDiscount {
+            id: row.get("application_id"),
+            product_id: row.get("product_id"),
+            coupon_id: row.get("id"),
+            applied_at: row.get::<chrono::NaiveDateTime, _>("applied_at").and_utc(),
+            removed_at: row
+                .get::<Option<chrono::NaiveDateTime>, _>("removed_at")
+                .map(|dt| dt.and_utc()),
+            removal_reason: row.get("removal_reason"),
+        };
+
+        (discount, PostgresCouponRepository::map_row_to_coupon(row))
     }
 }
@@ -0,0 +1,139 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::Row;
+use sqlx::postgres::PgPool;
+use std::sync::Arc;
+
+use crate::domain::{
+    entity::idempotency_entity::IdempotencyLookup,
+    repository::idempotency_repository::IdempotencyRepository,
+};
+
+pub struct PostgresIdempotencyRepository {
+    pool: Arc<PgPool>,
+}
+
+impl PostgresIdempotencyRepository {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl IdempotencyRepository for PostgresIdempotencyRepository {
+    async fn acquire(
+        &self,
+        scope: String,
+        key: String,
+        request: Value,
+        expires_at: DateTime<Utc>,
+        stale_before: DateTime<Utc>,
+    ) -> Result<IdempotencyLookup, String> {
+        let now = Utc::now().naive_utc();
+
+        // Concurrent inserts of the same key block on the primary key until
+        // the first one commits, so only one caller can win the lock.
+        let acquired = sqlx::query(
+            r#"INSERT INTO idempotency_keys (scope, key, request, locked_at, created_at, expires_at)
+               VALUES ($1, $2, $3, $4, $4, $5)
+               ON CONFLICT (scope, key) DO UPDATE SET
+                  request = EXCLUDED.request,
+                  status_code = NULL,
+                  response_body = NULL,
+                  locked_at = EXCLUDED.locked_at,
+                  created_at = EXCLUDED.created_at,
+                  expires_at = EXCLUDED.expires_at
+               WHERE idempotency_keys.expires_at <= EXCLUDED.created_at
+                  OR (idempotency_keys.status_code IS NULL
+                      AND idempotency_keys.locked_at < $6
+                      AND idempotency_keys.request = EXCLUDED.request)
+               RETURNING key"#,
+        )
+        .bind(&scope)
+        .bind(&key)
+        .bind(&request)
+        .bind(now)
+        .bind(expires_at.naive_utc())
+        .bind(stale_before.naive_utc())
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        if acquired.is_some() {
+            return Ok(IdempotencyLookup::Acquired);
+        }
+
+        let row = sqlx::query(
+            r#"SELECT request = $3 AS same_request, status_code, response_body
+               FROM idempotency_keys
+               WHERE scope = $1 AND key = $2"#,
+        )
+        .bind(&scope)
+        .bind(&key)
+        .bind(&request)
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        if !row.get::<bool, _>("same_request") {
+            return Ok(IdempotencyLookup::Mismatch);
+        }
+
+        match row.get::<Option<i32>, _>("status_code") {
+            Some(status) => Ok(IdempotencyLookup::Replay {
+                status: status as u16,
+                body: row
+                    .get::<Option<Value>, _>("response_body")
+                    .unwrap_or(Value::Null),
+            }),
+            None => Ok(IdempotencyLookup::InProgress),
+        }
+    }
+
+    async fn complete(
+        &self,
+        scope: String,
+        key: String,
+        status: u16,
+        body: Value,
+    ) -> Result<(), String> {
+        sqlx::query(
+            r#"UPDATE idempotency_keys
+               SET status_code = $3, response_body = $4, locked_at = NULL
+               WHERE scope = $1 AND key = $2"#,
+        )
+        .bind(scope)
+        .bind(key)
+        .bind(status as i32)
+        .bind(body)
+        .execute(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    async fn release(&self, scope: String, key: String) -> Result<(), String> {
+        sqlx::query(
+            "DELETE FROM idempotency_keys WHERE scope = $1 AND key = $2 AND status_code IS NULL",
+        )
+        .bind(scope)
+        .bind(key)
+        .execute(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    async fn purge_expired(&self, now: DateTime<Utc>) -> Result<u64, String> {
+        let result = sqlx::query("DELETE FROM idempotency_keys WHERE expires_at <= $1")
+            .bind(now.naive_utc())
+            .execute(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -1,5 +1,12 @@
+pub mod campaign_postgres_repository;
 pub mod coupon_in_memory_repository;
 pub mod coupon_postgres_repository;
+pub mod discount_in_memory_repository;
 pub mod discount_postgres_repository;
+pub mod idempotency_postgres_repository;
+pub mod order_postgres_repository;
 pub mod product_in_memory_repository;
 pub mod product_postgres_repository;
+pub mod rate_limit_in_memory_repository;
+pub mod sale_event_postgres_repository;
+pub mod tax_class_postgres_repository;
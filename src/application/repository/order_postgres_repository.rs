@@ -0,0 +1,645 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{
+    PgConnection, QueryBuilder, Row,
+    postgres::{PgPool, PgRow},
+    types::Json,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    application::repository::{
+        discount_postgres_repository::PostgresDiscountRepository,
+        product_postgres_repository::PostgresProductRepository,
+        sale_event_postgres_repository::PostgresSaleEventRepository,
+        tax_class_postgres_repository::PostgresTaxClassRepository,
+    },
+    domain::{
+        entity::{
+            discount_entity::{PaginatedResponse, PaginationMeta},
+            order_entity::{
+                Order, OrderAction, OrderEvent, OrderLine, OrderLineRefund, OrderStatus,
+                TransitionOrder,
+            },
+            product_entity::Product,
+            quote_entity::{QuoteCouponError, QuoteDraft, QuoteItem},
+        },
+        pricing::pricing_engine::PricingEngine,
+        repository::order_repository::OrderRepository,
+        usecase::product::price_products_usecase::PriceProductsUseCase,
+    },
+};
+
+const ORDER_COLUMNS: &str = r#"
+    id, customer_ref, status, coupon_code, subtotal, discount_total, total,
+    prices_include_tax, tax_amount, net_total, gross_total, refunded_total,
+    created_at, updated_at
+"#;
+
+const ORDER_LINE_COLUMNS: &str = r#"
+    id, order_id, product_id, name, quantity, price, unit_price, subtotal,
+    discount_total, total, tax_rate, tax_amount, refunded_quantity, refunded_amount
+"#;
+
+pub struct PostgresOrderRepository {
+    pool: Arc<PgPool>,
+}
+
+impl PostgresOrderRepository {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl OrderRepository for PostgresOrderRepository {
+    async fn create(
+        &self,
+        items: Vec<QuoteItem>,
+        coupon_code: Option<String>,
+        customer_ref: Option<String>,
+        pricing: PricingEngine,
+    ) -> Result<Order, String> {
+        let now = Utc::now();
+        let order_id = Uuid::new_v4();
+        let mut transaction = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        // The coupon row is locked before the products, the same order
+        // `apply_coupon` takes them in.
+        let coupon = match &coupon_code {
+            Some(code) => Some(
+                PostgresDiscountRepository::lock_redeemable_coupon(
+                    &mut transaction,
+                    code,
+                    customer_ref.as_deref(),
+                    now.naive_utc(),
+                )
+                .await?,
+            ),
+            None => None,
+        };
+
+        // Products are locked in id order so two orders sharing products
+        // cannot deadlock.
+        let mut product_ids: Vec<Uuid> = items.iter().map(|i| i.product_id).collect();
+        product_ids.sort();
+        let products: Vec<Product> = sqlx::query(
+            r#"
+            SELECT id, name, description, stock, price, created_at, updated_at,
+                   deleted_at, version, tax_class_id
+            FROM products
+            WHERE id = ANY($1) AND deleted_at IS NULL
+            ORDER BY id
+            FOR UPDATE
+            "#,
+        )
+        .bind(&product_ids)
+        .fetch_all(&mut *transaction)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .map(PostgresProductRepository::map_row_to_product)
+        .collect();
+
+        // The lines are priced from what is in effect under these locks, so
+        // the order keeps no figure read before the transaction.
+        let discounts =
+            PostgresDiscountRepository::active_applications(&mut transaction, &product_ids, now)
+                .await?;
+        let events = PostgresSaleEventRepository::running(&mut transaction, now).await?;
+        let tax_class_ids: Vec<Uuid> = products.iter().filter_map(|p| p.tax_class_id).collect();
+        let tax_classes =
+            PostgresTaxClassRepository::by_ids(&mut transaction, &tax_class_ids).await?;
+        let priced =
+            PriceProductsUseCase::price(&pricing, products, discounts, &events, &tax_classes)
+                .into_iter()
+                .map(|p| (p.id, p))
+                .collect();
+
+        let mut draft = QuoteDraft::new(&items, priced)?;
+        if let Some(warning) = draft.warnings.first() {
+            return Err(warning.order_error());
+        }
+        if let Some(coupon) = &coupon {
+            match draft.coupon_discounts(coupon, &pricing)? {
+                Ok(discounts) => draft.add_coupon(coupon.stacking.stackable, discounts),
+                Err(reasons) => {
+                    return Err(QuoteCouponError {
+                        code: coupon.code.clone(),
+                        reasons,
+                    }
+                    .order_error());
+                }
+            }
+        }
+        let quote = draft.finish(pricing.tax)?;
+        let now = now.naive_utc();
+
+        for line in &quote.lines {
+            sqlx::query(
+                r#"
+                UPDATE products
+                SET stock = stock - $2, updated_at = $3, version = version + 1
+                WHERE id = $1
+                "#,
+            )
+            .bind(line.product_id)
+            .bind(line.quantity as i32)
+            .bind(now)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| e.to_string())?;
+        }
+
+        // The campaign row is locked after the coupon and products, and its
+        // remaining budget checked against the amount priced here.
+        if let Some(campaign_id) = coupon.as_ref().and_then(|c| c.campaign_id) {
+            PostgresDiscountRepository::charge_campaign(
+                &mut transaction,
+                campaign_id,
+                quote.discount_total,
+                now,
+            )
+            .await?;
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO orders (
+                id, customer_ref, status, coupon_code, subtotal, discount_total, total,
+                prices_include_tax, tax_amount, net_total, gross_total, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $12)
+            "#,
+        )
+        .bind(order_id)
+        .bind(&customer_ref)
+        .bind(OrderStatus::Pending)
+        .bind(&coupon_code)
+        .bind(quote.subtotal as i64)
+        .bind(quote.discount_total as i64)
+        .bind(quote.total as i64)
+        .bind(quote.prices_include_tax)
+        .bind(quote.tax_amount as i64)
+        .bind(quote.net_total as i64)
+        .bind(quote.gross_total as i64)
+        .bind(now)
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        let mut query_builder = QueryBuilder::new(
+            r#"
+            INSERT INTO order_lines (
+                id, order_id, product_id, name, quantity, price, unit_price, subtotal,
+                discount_total, total, tax_rate, tax_amount, position
+            )
+            "#,
+        );
+        query_builder.push_values(
+            quote.lines.iter().enumerate(),
+            |mut row, (position, line)| {
+                row.push_bind(Uuid::new_v4())
+                    .push_bind(order_id)
+                    .push_bind(line.product_id)
+                    .push_bind(&line.name)
+                    .push_bind(line.quantity as i32)
+                    .push_bind(line.price as i64)
+                    .push_bind(line.unit_price as i64)
+                    .push_bind(line.subtotal as i64)
+                    .push_bind(line.discount_total as i64)
+                    .push_bind(line.total as i64)
+                    .push_bind(line.tax_rate as i32)
+                    .push_bind(line.tax_amount as i64)
+                    .push_bind(position as i32);
+            },
+        );
+        query_builder
+            .build()
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if let Some(coupon) = coupon {
+            for line in &quote.lines {
+                for discount in line.coupons.iter().filter(|c| c.code == coupon.code) {
+                    sqlx::query(
+                        r#"
+                        INSERT INTO coupon_redemptions (
+                            coupon_id, product_id, order_id, discount_amount, customer_ref, redeemed_at
+                        )
+                        VALUES ($1, $2, $3, $4, $5, $6)
+                        "#,
+                    )
+                    .bind(coupon.id)
+                    .bind(line.product_id)
+                    .bind(order_id)
+                    .bind(discount.amount as i64)
+                    .bind(&customer_ref)
+                    .bind(now)
+                    .execute(&mut *transaction)
+                    .await
+                    .map_err(|e| e.to_string())?;
+                }
+            }
+
+            sqlx::query("UPDATE coupons SET uses_count = uses_count + 1 WHERE id = $1")
+                .bind(coupon.id)
+                .execute(&mut *transaction)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        transaction.commit().await.map_err(|e| e.to_string())?;
+
+        self.find(&order_id.to_string()).await
+    }
+
+    async fn find(&self, id: &str) -> Result<Order, String> {
+        let order_id = Uuid::parse_str(id).map_err(|_| "Order not found".to_string())?;
+        let mut connection = self.pool.acquire().await.map_err(|e| e.to_string())?;
+
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM orders WHERE id = $1",
+            ORDER_COLUMNS
+        ))
+        .bind(order_id)
+        .fetch_optional(&mut *connection)
+        .await
+        .map_err(|e| e.to_string())?;
+        let Some(row) = row else {
+            return Err("Order not found".to_string());
+        };
+
+        let mut orders = Self::load_orders(&mut connection, vec![row]).await?;
+        Ok(orders.remove(0))
+    }
+
+    async fn find_all(
+        &self,
+        page: Option<u32>,
+        limit: Option<u32>,
+        customer_ref: Option<String>,
+    ) -> Result<PaginatedResponse<Order>, String> {
+        let page = page.unwrap_or(1);
+        let limit = limit.unwrap_or(10);
+        let offset = (page - 1) * limit;
+        let mut connection = self.pool.acquire().await.map_err(|e| e.to_string())?;
+
+        let mut count_builder = QueryBuilder::new("SELECT COUNT(*) FROM orders WHERE TRUE");
+        if let Some(customer_ref) = &customer_ref {
+            count_builder
+                .push(" AND customer_ref = ")
+                .push_bind(customer_ref);
+        }
+
+        let total_items: i64 = count_builder
+            .build_query_scalar()
+            .fetch_one(&mut *connection)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut query_builder =
+            QueryBuilder::new(format!("SELECT {} FROM orders WHERE TRUE", ORDER_COLUMNS));
+        if let Some(customer_ref) = &customer_ref {
+            query_builder
+                .push(" AND customer_ref = ")
+                .push_bind(customer_ref);
+        }
+        query_builder
+            .push(" ORDER BY created_at DESC, id LIMIT ")
+            .push_bind(limit as i64)
+            .push(" OFFSET ")
+            .push_bind(offset as i64);
+
+        let rows = query_builder
+            .build()
+            .fetch_all(&mut *connection)
+            .await
+            .map_err(|e| e.to_string())?;
+        let orders = Self::load_orders(&mut connection, rows).await?;
+
+        let total_pages = (total_items as f64 / limit as f64).ceil() as u32;
+
+        Ok(PaginatedResponse {
+            data: orders,
+            meta: PaginationMeta {
+                page,
+                limit,
+                total_items: total_items as u64,
+                total_pages,
+            },
+        })
+    }
+
+    async fn transition(
+        &self,
+        id: &str,
+        action: OrderAction,
+        transition: TransitionOrder,
+    ) -> Result<Order, String> {
+        let order_id = Uuid::parse_str(id).map_err(|_| "Order not found".to_string())?;
+        let now = Utc::now();
+        let mut transaction = self.pool.begin().await.map_err(|e| e.to_string())?;
+
+        // The order row lock serializes transitions of the same order.
+        let row = sqlx::query(&format!(
+            "SELECT {} FROM orders WHERE id = $1 FOR UPDATE",
+            ORDER_COLUMNS
+        ))
+        .bind(order_id)
+        .fetch_optional(&mut *transaction)
+        .await
+        .map_err(|e| e.to_string())?;
+        let Some(row) = row else {
+            return Err("Order not found".to_string());
+        };
+        let mut order = Self::load_orders(&mut transaction, vec![row])
+            .await?
+            .remove(0);
+
+        let change = order.transition(action, transition, now)?;
+        let event = &change.event;
+
+        // The coupon is written before the products, the same order placing
+        // an order locks them in. Its redemptions stay in the ledger, marked
+        // reversed, so they stop counting towards the coupon's limits.
+        let mut campaign_credit = None;
+        if event.coupon_restored {
+            let reversed = sqlx::query(
+                r#"
+                SELECT r.coupon_id, c.campaign_id, SUM(r.discount_amount)::BIGINT AS amount
+                FROM coupon_redemptions r
+                JOIN coupons c ON c.id = r.coupon_id
+                WHERE r.order_id = $1 AND r.reversed_at IS NULL
+                GROUP BY r.coupon_id, c.campaign_id
+                "#,
+            )
+            .bind(order_id)
+            .fetch_optional(&mut *transaction)
+            .await
+            .map_err(|e| e.to_string())?;
+
+            if let Some(reversed) = reversed {
+                sqlx::query(
+                    r#"
+                    UPDATE coupons
+                    SET uses_count = GREATEST(uses_count - 1, 0)
+                    WHERE id = $1
+                    "#,
+                )
+                .bind(reversed.get::<Uuid, _>("coupon_id"))
+                .execute(&mut *transaction)
+                .await
+                .map_err(|e| e.to_string())?;
+
+                sqlx::query(
+                    r#"
+                    UPDATE coupon_redemptions
+                    SET reversed_at = $2
+                    WHERE order_id = $1 AND reversed_at IS NULL
+                    "#,
+                )
+                .bind(order_id)
+                .bind(now.naive_utc())
+                .execute(&mut *transaction)
+                .await
+                .map_err(|e| e.to_string())?;
+
+                campaign_credit = reversed
+                    .get::<Option<Uuid>, _>("campaign_id")
+                    .map(|campaign_id| (campaign_id, reversed.get::<i64, _>("amount")));
+            }
+        }
+
+        // A product restocked since the order was placed may have no room
+        // left under chk_stock_range; the whole transition is then refused.
+        let mut restock = change.restock.clone();
+        restock.sort();
+        for (product_id, quantity) in restock {
+            let result = sqlx::query(
+                r#"
+                UPDATE products
+                SET stock = stock + $2, updated_at = $3, version = version + 1
+                WHERE id = $1 AND stock + $2 <= 999999
+                "#,
+            )
+            .bind(product_id)
+            .bind(quantity as i32)
+            .bind(now.naive_utc())
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| e.to_string())?;
+
+            if result.rows_affected() == 0 {
+                let exists = sqlx::query_scalar::<_, bool>(
+                    "SELECT EXISTS(SELECT 1 FROM products WHERE id = $1)",
+                )
+                .bind(product_id)
+                .fetch_one(&mut *transaction)
+                .await
+                .map_err(|e| e.to_string())?;
+                if exists {
+                    return Err("Restock exceeds the stock limit".to_string());
+                }
+            }
+        }
+
+        // The order's coupon discount goes back to the campaign budget after
+        // the products are written, the same order placing an order locks
+        // the campaign in. A campaign closed by its budget opens again.
+        if let Some((campaign_id, amount)) = campaign_credit {
+            sqlx::query(
+                r#"
+                UPDATE campaigns SET
+                    spent = GREATEST(spent - $2, 0),
+                    status = CASE WHEN GREATEST(spent - $2, 0) < budget
+                        THEN 'active'::campaign_status ELSE status END,
+                    closed_at = CASE WHEN GREATEST(spent - $2, 0) < budget
+                        THEN NULL ELSE closed_at END,
+                    updated_at = $3
+                WHERE id = $1
+                "#,
+            )
+            .bind(campaign_id)
+            .bind(amount)
+            .bind(now.naive_utc())
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| e.to_string())?;
+        }
+
+        for line in &order.lines {
+            sqlx::query(
+                r#"
+                UPDATE order_lines
+                SET refunded_quantity = $2, refunded_amount = $3
+                WHERE id = $1
+                "#,
+            )
+            .bind(line.id)
+            .bind(line.refunded_quantity as i32)
+            .bind(line.refunded_amount as i64)
+            .execute(&mut *transaction)
+            .await
+            .map_err(|e| e.to_string())?;
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE orders
+            SET status = $2, refunded_total = $3, updated_at = $4
+            WHERE id = $1
+            "#,
+        )
+        .bind(order_id)
+        .bind(order.status)
+        .bind(order.refunded_total as i64)
+        .bind(now.naive_utc())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO order_events (
+                id, order_id, action, from_status, to_status, lines,
+                refund_amount, coupon_restored, created_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+        .bind(event.id)
+        .bind(order_id)
+        .bind(event.action)
+        .bind(event.from_status)
+        .bind(event.to_status)
+        .bind(Json(&event.lines))
+        .bind(event.refund_amount as i64)
+        .bind(event.coupon_restored)
+        .bind(now.naive_utc())
+        .execute(&mut *transaction)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        transaction.commit().await.map_err(|e| e.to_string())?;
+
+        self.find(id).await
+    }
+}
+
+impl PostgresOrderRepository {
+    /// Maps `rows` to orders, with the lines and history of all of them
+    /// looked up at once.
+    async fn load_orders(
+        connection: &mut PgConnection,
+        rows: Vec<PgRow>,
+    ) -> Result<Vec<Order>, String> {
+        let order_ids: Vec<Uuid> = rows.iter().map(|row| row.get("id")).collect();
+
+        let mut lines: HashMap<Uuid, Vec<OrderLine>> = HashMap::new();
+        for row in sqlx::query(&format!(
+            "SELECT {} FROM order_lines WHERE order_id = ANY($1) ORDER BY order_id, position",
+            ORDER_LINE_COLUMNS
+        ))
+        .bind(&order_ids)
+        .fetch_all(&mut *connection)
+        .await
+        .map_err(|e| e.to_string())?
+        {
+            lines
+                .entry(row.get("order_id"))
+                .or_default()
+                .push(Self::map_row_to_order_line(&row));
+        }
+
+        let mut history: HashMap<Uuid, Vec<OrderEvent>> = HashMap::new();
+        for row in sqlx::query(
+            r#"
+            SELECT id, order_id, action, from_status, to_status, lines,
+                   refund_amount, coupon_restored, created_at
+            FROM order_events
+            WHERE order_id = ANY($1)
+            ORDER BY order_id, created_at
+            "#,
+        )
+        .bind(&order_ids)
+        .fetch_all(&mut *connection)
+        .await
+        .map_err(|e| e.to_string())?
+        {
+            history
+                .entry(row.get("order_id"))
+                .or_default()
+                .push(Self::map_row_to_order_event(&row));
+        }
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let id: Uuid = row.get("id");
+                Self::map_row_to_order(
+                    row,
+                    lines.remove(&id).unwrap_or_default(),
+                    history.remove(&id).unwrap_or_default(),
+                )
+            })
+            .collect())
+    }
+
+    fn map_row_to_order(row: PgRow, lines: Vec<OrderLine>, history: Vec<OrderEvent>) -> Order {
+        Order {
+            id: row.get("id"),
+            customer_ref: row.get("customer_ref"),
+            status: row.get("status"),
+            coupon_code: row.get("coupon_code"),
+            lines,
+            subtotal: row.get::<i64, _>("subtotal") as u64,
+            discount_total: row.get::<i64, _>("discount_total") as u64,
+            total: row.get::<i64, _>("total") as u64,
+            prices_include_tax: row.get("prices_include_tax"),
+            tax_amount: row.get::<i64, _>("tax_amount") as u64,
+            net_total: row.get::<i64, _>("net_total") as u64,
+            gross_total: row.get::<i64, _>("gross_total") as u64,
+            refunded_total: row.get::<i64, _>("refunded_total") as u64,
+            history,
+            created_at: row.get::<chrono::NaiveDateTime, _>("created_at").and_utc(),
+            updated_at: row
+                .get::<Option<chrono::NaiveDateTime>, _>("updated_at")
+                .map(|dt| dt.and_utc()),
+        }
+    }
+
+    fn map_row_to_order_line(row: &PgRow) -> OrderLine {
+        OrderLine {
+            id: row.get("id"),
+            product_id: row.get("product_id"),
+            name: row.get("name"),
+            quantity: row.get::<i32, _>("quantity") as u32,
+            price: row.get::<i64, _>("price") as u64,
+            unit_price: row.get::<i64, _>("unit_price") as u64,
+            subtotal: row.get::<i64, _>("subtotal") as u64,
+            discount_total: row.get::<i64, _>("discount_total") as u64,
+            total: row.get::<i64, _>("total") as u64,
+            tax_rate: row.get::<i32, _>("tax_rate") as u64,
+            tax_amount: row.get::<i64, _>("tax_amount") as u64,
+            refunded_quantity: row.get::<i32, _>("refunded_quantity") as u32,
+            refunded_amount: row.get::<i64, _>("refunded_amount") as u64,
+        }
+    }
+
+    fn map_row_to_order_event(row: &PgRow) -> OrderEvent {
+        OrderEvent {
+            id: row.get("id"),
+            action: row.get("action"),
+            from_status: row.get("from_status"),
+            to_status: row.get("to_status"),
+            lines: row.get::<Json<Vec<OrderLineRefund>>, _>("lines").0,
+            refund_amount: row.get::<i64, _>("refund_amount") as u64,
+            coupon_restored: row.get("coupon_restored"),
+            created_at: row.get::<chrono::NaiveDateTime, _>("created_at").and_utc(),
+        }
+    }
+}
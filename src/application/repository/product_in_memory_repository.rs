@@ -1,10 +1,11 @@
 use crate::domain::entity::{
     discount_entity::{PaginatedResponse, PaginationMeta, ProductDiscount},
     product_entity::{CreateProduct, Product, UpdateProduct},
+    sale_event_entity::SaleTarget,
 };
 use crate::domain::repository::product_repository::ProductRepository;
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -12,8 +13,8 @@ use uuid::Uuid;
 
 #[derive(Debug, Clone)]
 pub struct InMemoryProductRepository {
-    products: Arc<RwLock<HashMap<String, Product>>>,
-    discounts: Arc<RwLock<HashMap<String, ProductDiscount>>>,
+    pub(crate) products: Arc<RwLock<HashMap<String, Product>>>,
+    pub(crate) discounts: Arc<RwLock<HashMap<String, ProductDiscount>>>,
 }
 
 impl InMemoryProductRepository {
@@ -39,6 +40,15 @@ impl ProductRepository for InMemoryProductRepository {
             .ok_or_else(|| "Product not found".to_string())
     }
 
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Product>, String> {
+        let products = self.products.read().await;
+        Ok(products
+            .values()
+            .filter(|p| p.deleted_at.is_none() && ids.contains(&p.id))
+            .cloned()
+            .collect())
+    }
+
     async fn find_all(
         &self,
         page: Option<u32>,
@@ -141,6 +151,8 @@ impl ProductRepository for InMemoryProductRepository {
             stock: product.stock,
             description: product.description,
             price: product.price,
+            version: 1,
+            tax_class_id: None,
             created_at: now,
             updated_at: Some(now),
             deleted_at: None,
@@ -152,9 +164,26 @@ impl ProductRepository for InMemoryProductRepository {
         Ok(new_product)
     }
 
-    async fn update(&self, id: String, new_product: UpdateProduct) -> Result<Product, String> {
+    async fn update(
+        &self,
+        id: String,
+        new_product: UpdateProduct,
+        expected_versions: Option<Vec<u32>>,
+    ) -> Result<Product, String> {
         let mut products = self.products.write().await;
-        if let Some(product) = products.get_mut(&id) {
+        if let Some(name) = &new_product.name
+            && products.iter().any(|(other, p)| {
+                *other != id
+                    && p.deleted_at.is_none()
+                    && p.name.to_lowercase() == name.to_lowercase()
+            })
+        {
+            return Err("Product already exists".to_string());
+        }
+        if let Some(product) = products.get_mut(&id).filter(|p| p.deleted_at.is_none()) {
+            if expected_versions.is_some_and(|v| !v.contains(&product.version)) {
+                return Err("Version mismatch".to_string());
+            }
             if let Some(name) = new_product.name {
                 product.name = name;
             }
@@ -167,20 +196,22 @@ impl ProductRepository for InMemoryProductRepository {
             if let Some(stock) = new_product.stock {
                 product.stock = stock;
             }
-            if let Some(_) = new_product.deleted_at {
-                product.deleted_at = None;
-            }
             product.updated_at = Some(Utc::now());
+            product.version += 1;
             Ok(product.clone())
         } else {
             Err("Product not found".to_string())
         }
     }
 
-    async fn delete(&self, id: String) -> Result<(), String> {
+    async fn delete(&self, id: String, expected_versions: Option<Vec<u32>>) -> Result<(), String> {
         let mut products = self.products.write().await;
-        if let Some(product) = products.get_mut(&id) {
+        if let Some(product) = products.get_mut(&id).filter(|p| p.deleted_at.is_none()) {
+            if expected_versions.is_some_and(|v| !v.contains(&product.version)) {
+                return Err("Version mismatch".to_string());
+            }
             product.deleted_at = Some(Utc::now());
+            product.version += 1;
             Ok(())
         } else {
             Err("Product not found".to_string())
@@ -195,4 +226,150 @@ impl ProductRepository for InMemoryProductRepository {
             false
         }
     }
+
+    async fn find_deleted(
+        &self,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedResponse<Product>, String> {
+        let products = self.products.read().await;
+
+        let mut deleted_products: Vec<Product> = products
+            .values()
+            .filter(|p| p.deleted_at.is_some())
+            .cloned()
+            .collect();
+        deleted_products.sort_by_key(|p| std::cmp::Reverse(p.deleted_at));
+
+        let page = page.unwrap_or(1);
+        let limit = limit.unwrap_or(10);
+
+        let total_items = deleted_products.len() as u64;
+        let total_pages = (total_items as f64 / limit as f64).ceil() as u32;
+
+        let start_index = ((page - 1) * limit) as usize;
+        let end_index = std::cmp::min(start_index + limit as usize, deleted_products.len());
+
+        let paginated_data = if start_index < deleted_products.len() {
+            deleted_products[start_index..end_index].to_vec()
+        } else {
+            Vec::new()
+        };
+
+        Ok(PaginatedResponse {
+            data: paginated_data,
+            meta: PaginationMeta {
+                page,
+                limit,
+                total_items,
+                total_pages,
+            },
+        })
+    }
+
+    async fn restore(&self, id: String) -> Result<Product, String> {
+        let mut products = self.products.write().await;
+
+        let name = match products.get(&id).filter(|p| p.deleted_at.is_some()) {
+            Some(product) => product.name.to_lowercase(),
+            None => return Err("Product not found in trash".to_string()),
+        };
+
+        if products
+            .values()
+            .any(|p| p.deleted_at.is_none() && p.name.to_lowercase() == name)
+        {
+            return Err("Product already exists".to_string());
+        }
+
+        let product = products
+            .get_mut(&id)
+            .ok_or_else(|| "Product not found in trash".to_string())?;
+        product.deleted_at = None;
+        product.updated_at = Some(Utc::now());
+        product.version += 1;
+        Ok(product.clone())
+    }
+
+    async fn purge(&self, id: String) -> Result<(), String> {
+        let mut products = self.products.write().await;
+        match products.get(&id) {
+            Some(product) if product.deleted_at.is_some() => {
+                products.remove(&id);
+                self.discounts.write().await.remove(&id);
+                Ok(())
+            }
+            _ => Err("Product not found in trash".to_string()),
+        }
+    }
+
+    async fn purge_deleted_before(&self, cutoff: DateTime<Utc>) -> Result<u64, String> {
+        let mut products = self.products.write().await;
+        let mut discounts = self.discounts.write().await;
+
+        let expired: Vec<String> = products
+            .iter()
+            .filter(|(_, p)| p.deleted_at.is_some_and(|deleted_at| deleted_at < cutoff))
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        for id in &expired {
+            products.remove(id);
+            discounts.remove(id);
+        }
+
+        Ok(expired.len() as u64)
+    }
+
+    async fn set_tax_class(
+        &self,
+        id: String,
+        tax_class_id: Option<Uuid>,
+    ) -> Result<Product, String> {
+        let mut products = self.products.write().await;
+        let product = products
+            .get_mut(&id)
+            .filter(|p| p.deleted_at.is_none())
+            .ok_or_else(|| "Product not found".to_string())?;
+        product.tax_class_id = tax_class_id;
+        product.updated_at = Some(Utc::now());
+        product.version += 1;
+        Ok(product.clone())
+    }
+
+    async fn find_by_sale_target(
+        &self,
+        target: &SaleTarget,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedResponse<Product>, String> {
+        let page = page.unwrap_or(1);
+        let limit = limit.unwrap_or(10);
+
+        let products = self.products.read().await;
+        let mut targeted: Vec<Product> = products
+            .values()
+            .filter(|p| p.deleted_at.is_none() && target.matches(p))
+            .cloned()
+            .collect();
+        targeted.sort_by_key(|p| std::cmp::Reverse(p.created_at));
+
+        let total_items = targeted.len() as u64;
+        let total_pages = (total_items as f64 / limit as f64).ceil() as u32;
+        let data = targeted
+            .into_iter()
+            .skip(((page - 1) * limit) as usize)
+            .take(limit as usize)
+            .collect();
+
+        Ok(PaginatedResponse {
+            data,
+            meta: PaginationMeta {
+                page,
+                limit,
+                total_items,
+                total_pages,
+            },
+        })
+    }
 }
@@ -1,10 +1,11 @@
 use crate::domain::entity::discount_entity::{PaginatedResponse, PaginationMeta};
 use crate::domain::entity::product_entity::{CreateProduct, Product, UpdateProduct};
+use crate::domain::entity::sale_event_entity::SaleTarget;
 use crate::domain::repository::product_repository::ProductRepository;
 use async_trait::async_trait;
-use chrono::Utc;
-use sqlx::Row;
-use sqlx::postgres::PgPool;
+use chrono::{DateTime, Utc};
+use sqlx::postgres::{PgPool, Postgres};
+use sqlx::{QueryBuilder, Row};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -24,7 +25,8 @@ impl ProductRepository for PostgresProductRepository {
         let uuid = Uuid::parse_str(&id).map_err(|_| "Invalid UUID".to_string())?;
         let row = sqlx::query(
             r#"SELECT p.id, p.name, p.description, p.stock, p.price,
-                      p.created_at, p.updated_at, p.deleted_at
+                      p.created_at, p.updated_at, p.deleted_at, p.version,
+                      p.tax_class_id
                FROM products p
                WHERE p.id = $1 AND p.deleted_at IS NULL"#,
         )
@@ -39,20 +41,27 @@ impl ProductRepository for PostgresProductRepository {
             }
         })?;
 
-        Ok(Product {
-            id: row.get::<Uuid, _>("id"),
-            name: row.get::<String, _>("name"),
-            description: row.get::<Option<String>, _>("description"),
-            stock: row.get::<i32, _>("stock") as u32,
-            price: row.get::<i32, _>("price") as u64,
-            created_at: row.get::<chrono::NaiveDateTime, _>("created_at").and_utc(),
-            updated_at: row
-                .get::<Option<chrono::NaiveDateTime>, _>("updated_at")
-                .map(|dt| dt.and_utc()),
-            deleted_at: row
-                .get::<Option<chrono::NaiveDateTime>, _>("deleted_at")
-                .map(|dt| dt.and_utc()),
-        })
+        Ok(Self::map_row_to_product(row))
+    }
+
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Product>, String> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let rows = sqlx::query(
+            r#"SELECT p.id, p.name, p.description, p.stock, p.price,
+                      p.created_at, p.updated_at, p.deleted_at, p.version,
+                      p.tax_class_id
+               FROM products p
+               WHERE p.id = ANY($1) AND p.deleted_at IS NULL"#,
+        )
+        .bind(ids)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(rows.into_iter().map(Self::map_row_to_product).collect())
     }
 
     async fn find_all(
@@ -88,7 +97,8 @@ impl ProductRepository for PostgresProductRepository {
 
         let query = format!(
             r#"SELECT p.id, p.name, p.description, p.stock, p.price,
-                       p.created_at, p.updated_at, p.deleted_at
+                       p.created_at, p.updated_at, p.deleted_at, p.version,
+                       p.tax_class_id
                FROM products p
                WHERE {}
                ORDER BY p.created_at DESC
@@ -108,20 +118,7 @@ impl ProductRepository for PostgresProductRepository {
 
         let mut products = Vec::with_capacity(rows.len());
         for row in rows {
-            products.push(Product {
-                id: row.get::<Uuid, _>("id"),
-                name: row.get::<String, _>("name"),
-                description: row.get::<Option<String>, _>("description"),
-                stock: row.get::<i32, _>("stock") as u32,
-                price: row.get::<i32, _>("price") as u64,
-                created_at: row.get::<chrono::NaiveDateTime, _>("created_at").and_utc(),
-                updated_at: row
-                    .get::<Option<chrono::NaiveDateTime>, _>("updated_at")
-                    .map(|dt| dt.and_utc()),
-                deleted_at: row
-                    .get::<Option<chrono::NaiveDateTime>, _>("deleted_at")
-                    .map(|dt| dt.and_utc()),
-            });
+            products.push(Self::map_row_to_product(row));
         }
 
         let count_query = format!("SELECT COUNT(*) FROM products p WHERE {}", condition_str);
@@ -153,7 +150,8 @@ impl ProductRepository for PostgresProductRepository {
         let row = sqlx::query(
             r#"INSERT INTO products (name, description, stock, price, created_at, updated_at)
                VALUES ($1, $2, $3, $4, $5, $6)
-               RETURNING id, name, description, stock, price, created_at, updated_at, deleted_at"#,
+               RETURNING id, name, description, stock, price, created_at, updated_at, deleted_at, version,
+                         tax_class_id"#,
         )
         .bind(&create.name)
         .bind(&create.description)
@@ -165,30 +163,22 @@ impl ProductRepository for PostgresProductRepository {
         .await
         .map_err(|e| {
             if let sqlx::Error::Database(db_err) = &e {
-                if db_err.constraint() == Some("products_name_key") {
+                if db_err.constraint() == Some("products_name_unique_idx") {
                     return "Product already exists".to_string();
                 }
             }
             e.to_string()
         })?;
 
-        Ok(Product {
-            id: row.get("id"),
-            name: row.get("name"),
-            description: row.get("description"),
-            stock: row.get::<i32, _>("stock") as u32,
-            price: row.get::<i32, _>("price") as u64,
-            created_at: row.get::<chrono::NaiveDateTime, _>("created_at").and_utc(),
-            updated_at: row
-                .get::<Option<chrono::NaiveDateTime>, _>("updated_at")
-                .map(|dt| dt.and_utc()),
-            deleted_at: row
-                .get::<Option<chrono::NaiveDateTime>, _>("deleted_at")
-                .map(|dt| dt.and_utc()),
-        })
+        Ok(Self::map_row_to_product(row))
     }
 
-    async fn update(&self, id: String, update: UpdateProduct) -> Result<Product, String> {
+    async fn update(
+        &self,
+        id: String,
+        update: UpdateProduct,
+        expected_versions: Option<Vec<u32>>,
+    ) -> Result<Product, String> {
         let uuid = Uuid::parse_str(&id).map_err(|_| "Invalid UUID".to_string())?;
         let now = Utc::now();
         let row = sqlx::query(
@@ -198,57 +188,54 @@ impl ProductRepository for PostgresProductRepository {
                   stock = COALESCE($3, stock),
                   price = COALESCE($4, price),
                   updated_at = $5,
-                  deleted_at = $6
-               WHERE id = $7
-               RETURNING id, name, description, stock, price, created_at, updated_at, deleted_at"#,
+                  version = version + 1
+               WHERE id = $6 AND deleted_at IS NULL
+               AND ($7::INTEGER[] IS NULL OR version = ANY($7))
+               RETURNING id, name, description, stock, price, created_at, updated_at, deleted_at, version,
+                         tax_class_id"#,
         )
         .bind(update.name)
         .bind(update.description)
         .bind(update.stock.map(|s| s as i32))
         .bind(update.price.map(|p| p as i32))
         .bind(now.naive_utc())
-        .bind(update.deleted_at.map(|dt| dt.naive_utc()))
         .bind(uuid)
-        .fetch_one(&*self.pool)
+        .bind(expected_versions.map(|v| v.into_iter().map(|v| v as i32).collect::<Vec<i32>>()))
+        .fetch_optional(&*self.pool)
         .await
-        .map_err(|e| {
-            if let sqlx::Error::RowNotFound = e {
-                "Product not found".to_string()
-            } else {
-                e.to_string()
+        .map_err(|e| match &e {
+            sqlx::Error::Database(db_err)
+                if db_err.constraint() == Some("products_name_unique_idx") =>
+            {
+                "Product already exists".to_string()
             }
+            _ => e.to_string(),
         })?;
 
-        Ok(Product {
-            id: row.get("id"),
-            name: row.get("name"),
-            description: row.get("description"),
-            stock: row.get::<i32, _>("stock") as u32,
-            price: row.get::<i32, _>("price") as u64,
-            created_at: row.get::<chrono::NaiveDateTime, _>("created_at").and_utc(),
-            updated_at: row
-                .get::<Option<chrono::NaiveDateTime>, _>("updated_at")
-                .map(|dt| dt.and_utc()),
-            deleted_at: row
-                .get::<Option<chrono::NaiveDateTime>, _>("deleted_at")
-                .map(|dt| dt.and_utc()),
-        })
+        match row {
+            Some(row) => Ok(Self::map_row_to_product(row)),
+            None => Err(self.missing_or_stale(uuid).await),
+        }
     }
 
-    async fn delete(&self, id: String) -> Result<(), String> {
+    async fn delete(&self, id: String, expected_versions: Option<Vec<u32>>) -> Result<(), String> {
         let uuid = Uuid::parse_str(&id).map_err(|_| "Invalid UUID".to_string())?;
         let now = Utc::now().naive_utc();
 
-        let result =
-            sqlx::query("UPDATE products SET deleted_at = $1 WHERE id = $2 AND deleted_at IS NULL")
-                .bind(now)
-                .bind(uuid)
-                .execute(&*self.pool)
-                .await
-                .map_err(|e| e.to_string())?;
+        let result = sqlx::query(
+            r#"UPDATE products SET deleted_at = $1, version = version + 1
+               WHERE id = $2 AND deleted_at IS NULL
+               AND ($3::INTEGER[] IS NULL OR version = ANY($3))"#,
+        )
+        .bind(now)
+        .bind(uuid)
+        .bind(expected_versions.map(|v| v.into_iter().map(|v| v as i32).collect::<Vec<i32>>()))
+        .execute(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
 
         if result.rows_affected() == 0 {
-            return Err("Product not found".to_string());
+            return Err(self.missing_or_stale(uuid).await);
         }
 
         Ok(())
@@ -277,4 +264,273 @@ impl ProductRepository for PostgresProductRepository {
             Err(_) => false,
         }
     }
+
+    async fn find_deleted(
+        &self,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedResponse<Product>, String> {
+        let page = page.unwrap_or(1) as i64;
+        let limit = limit.unwrap_or(10) as i64;
+        let offset = (page - 1) * limit;
+
+        let rows = sqlx::query(
+            r#"SELECT p.id, p.name, p.description, p.stock, p.price,
+                      p.created_at, p.updated_at, p.deleted_at, p.version,
+                      p.tax_class_id
+               FROM products p
+               WHERE p.deleted_at IS NOT NULL
+               ORDER BY p.deleted_at DESC
+               LIMIT $1 OFFSET $2"#,
+        )
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        let products = rows.into_iter().map(Self::map_row_to_product).collect();
+
+        let total_items: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM products WHERE deleted_at IS NOT NULL")
+                .fetch_one(&*self.pool)
+                .await
+                .map_err(|e| e.to_string())?;
+
+        let total_items = total_items as u64;
+        let total_pages = ((total_items as f64) / (limit as f64)).ceil() as u32;
+
+        Ok(PaginatedResponse {
+            data: products,
+            meta: PaginationMeta {
+                page: page as u32,
+                limit: limit as u32,
+                total_items,
+                total_pages,
+            },
+        })
+    }
+
+    async fn restore(&self, id: String) -> Result<Product, String> {
+        let uuid = Uuid::parse_str(&id).map_err(|_| "Invalid UUID".to_string())?;
+        let now = Utc::now().naive_utc();
+
+        let row = sqlx::query(
+            r#"UPDATE products SET
+                  deleted_at = NULL,
+                  updated_at = $1,
+                  version = version + 1
+               WHERE id = $2 AND deleted_at IS NOT NULL
+               RETURNING id, name, description, stock, price, created_at, updated_at, deleted_at, version,
+                         tax_class_id"#,
+        )
+        .bind(now)
+        .bind(uuid)
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|e| match &e {
+            sqlx::Error::RowNotFound => "Product not found in trash".to_string(),
+            sqlx::Error::Database(db_err)
+                if db_err.constraint() == Some("products_name_unique_idx") =>
+            {
+                "Product already exists".to_string()
+            }
+            _ => e.to_string(),
+        })?;
+
+        Ok(Self::map_row_to_product(row))
+    }
+
+    async fn purge(&self, id: String) -> Result<(), String> {
+        let uuid = Uuid::parse_str(&id).map_err(|_| "Invalid UUID".to_string())?;
+
+        let result = sqlx::query("DELETE FROM products WHERE id = $1 AND deleted_at IS NOT NULL")
+            .bind(uuid)
+            .execute(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        if result.rows_affected() == 0 {
+            return Err("Product not found in trash".to_string());
+        }
+
+        Ok(())
+    }
+
+    async fn purge_deleted_before(&self, cutoff: DateTime<Utc>) -> Result<u64, String> {
+        let result =
+            sqlx::query("DELETE FROM products WHERE deleted_at IS NOT NULL AND deleted_at < $1")
+                .bind(cutoff.naive_utc())
+                .execute(&*self.pool)
+                .await
+                .map_err(|e| e.to_string())?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn set_tax_class(
+        &self,
+        id: String,
+        tax_class_id: Option<Uuid>,
+    ) -> Result<Product, String> {
+        let uuid = Uuid::parse_str(&id).map_err(|_| "Invalid UUID".to_string())?;
+        let now = Utc::now().naive_utc();
+
+        let row = sqlx::query(
+            r#"UPDATE products SET
+                  tax_class_id = $1,
+                  updated_at = $2,
+                  version = version + 1
+               WHERE id = $3 AND deleted_at IS NULL
+               RETURNING id, name, description, stock, price, created_at, updated_at, deleted_at, version,
+                         tax_class_id"#,
+        )
+        .bind(tax_class_id)
+        .bind(now)
+        .bind(uuid)
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|e| match &e {
+            sqlx::Error::RowNotFound => "Product not found".to_string(),
+            sqlx::Error::Database(db_err)
+                if db_err.constraint() == Some("products_tax_class_id_fkey") =>
+            {
+                "Tax class not found".to_string()
+            }
+            _ => e.to_string(),
+        })?;
+
+        Ok(Self::map_row_to_product(row))
+    }
+
+    async fn find_by_sale_target(
+        &self,
+        target: &SaleTarget,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedResponse<Product>, String> {
+        let page = page.unwrap_or(1);
+        let limit = limit.unwrap_or(10);
+        let offset = (page - 1) * limit;
+
+        let mut count_builder =
+            QueryBuilder::new("SELECT COUNT(*) FROM products p WHERE p.deleted_at IS NULL");
+        Self::push_sale_target(&mut count_builder, target);
+
+        let total_items: i64 = count_builder
+            .build_query_scalar()
+            .fetch_one(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut query_builder = QueryBuilder::new(
+            r#"SELECT p.id, p.name, p.description, p.stock, p.price,
+                      p.created_at, p.updated_at, p.deleted_at, p.version,
+                      p.tax_class_id
+               FROM products p
+               WHERE p.deleted_at IS NULL"#,
+        );
+        Self::push_sale_target(&mut query_builder, target);
+        query_builder
+            .push(" ORDER BY p.created_at DESC LIMIT ")
+            .push_bind(limit as i64)
+            .push(" OFFSET ")
+            .push_bind(offset as i64);
+
+        let rows = query_builder
+            .build()
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let products = rows.into_iter().map(Self::map_row_to_product).collect();
+
+        let total_items = total_items as u64;
+        let total_pages = ((total_items as f64) / (limit as f64)).ceil() as u32;
+
+        Ok(PaginatedResponse {
+            data: products,
+            meta: PaginationMeta {
+                page,
+                limit,
+                total_items,
+                total_pages,
+            },
+        })
+    }
+}
+
+impl PostgresProductRepository {
+    /// SQL form of `SaleTarget::matches`.
+    fn push_sale_target(builder: &mut QueryBuilder<'_, Postgres>, target: &SaleTarget) {
+        match target {
+            SaleTarget::All => {}
+            SaleTarget::Products { product_ids } => {
+                builder
+                    .push(" AND p.id = ANY(")
+                    .push_bind(product_ids.clone())
+                    .push(")");
+            }
+            SaleTarget::PriceRange {
+                min_price,
+                max_price,
+            } => {
+                // Prices past i64 match no row anyway.
+                builder
+                    .push(" AND p.price BETWEEN ")
+                    .push_bind(i64::try_from(*min_price).unwrap_or(i64::MAX))
+                    .push(" AND ")
+                    .push_bind(i64::try_from(*max_price).unwrap_or(i64::MAX));
+            }
+            SaleTarget::Search { term } => {
+                // The term is matched literally, as `SaleTarget::matches` does.
+                let term = term
+                    .to_lowercase()
+                    .replace('\\', "\\\\")
+                    .replace('%', "\\%")
+                    .replace('_', "\\_");
+                let pattern = format!("%{}%", term);
+                builder
+                    .push(" AND (LOWER(p.name) LIKE ")
+                    .push_bind(pattern.clone())
+                    .push(" ESCAPE '\\' OR LOWER(COALESCE(p.description, '')) LIKE ")
+                    .push_bind(pattern)
+                    .push(" ESCAPE '\\')");
+            }
+        }
+    }
+
+    async fn missing_or_stale(&self, uuid: Uuid) -> String {
+        let exists = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM products WHERE id = $1 AND deleted_at IS NULL)",
+        )
+        .bind(uuid)
+        .fetch_one(&*self.pool)
+        .await;
+
+        match exists {
+            Ok(true) => "Version mismatch".to_string(),
+            Ok(false) => "Product not found".to_string(),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    pub(crate) fn map_row_to_product(row: sqlx::postgres::PgRow) -> Product {
+        Product {
+            id: row.get("id"),
+            name: row.get("name"),
+            description: row.get("description"),
+            stock: row.get::<i32, _>("stock") as u32,
+            price: row.get::<i32, _>("price") as u64,
+            version: row.get::<i32, _>("version") as u32,
+            tax_class_id: row.get("tax_class_id"),
+            created_at: row.get::<chrono::NaiveDateTime, _>("created_at").and_utc(),
+            updated_at: row
+                .get::<Option<chrono::NaiveDateTime>, _>("updated_at")
+                .map(|dt| dt.and_utc()),
+            deleted_at: row
+                .get::<Option<chrono::NaiveDateTime>, _>("deleted_at")
+                .map(|dt| dt.and_utc()),
+        }
+    }
 }
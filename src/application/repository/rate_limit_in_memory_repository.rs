@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::rate_limit_entity::{RateLimit, RateLimitDecision, TokenBucket},
+    repository::rate_limit_repository::RateLimitRepository,
+};
+
+/// Buckets kept at most. Past this, the one used least recently is dropped.
+const MAX_BUCKETS: usize = 10_000;
+
+/// How often refilled buckets are dropped, as a full bucket is what a new
+/// client starts with anyway.
+const SWEEP_INTERVAL_SECS: i64 = 60;
+
+#[derive(Debug)]
+struct Entry {
+    limit: RateLimit,
+    bucket: TokenBucket,
+    /// Position in `Buckets::recency`.
+    used: u64,
+}
+
+#[derive(Debug, Default)]
+struct Buckets {
+    entries: HashMap<String, Entry>,
+    /// Keys by last use, oldest first.
+    recency: BTreeMap<u64, String>,
+    next_use: u64,
+    swept_at: Option<DateTime<Utc>>,
+}
+
+impl Buckets {
+    fn sweep(&mut self, now: DateTime<Utc>) {
+        if self
+            .swept_at
+            .is_some_and(|at| now - at < Duration::seconds(SWEEP_INTERVAL_SECS))
+        {
+            return;
+        }
+        self.swept_at = Some(now);
+        let recency = &mut self.recency;
+        self.entries.retain(|_, entry| {
+            let refilled = entry.bucket.tokens_at(&entry.limit, now) >= entry.limit.requests as f64;
+            if refilled {
+                recency.remove(&entry.used);
+            }
+            !refilled
+        });
+    }
+
+    fn evict_least_recent(&mut self) {
+        if let Some((_, key)) = self.recency.pop_first() {
+            self.entries.remove(&key);
+        }
+    }
+}
+
+/// Buckets kept in this process, so each server instance limits on its own.
+#[derive(Debug, Default)]
+pub struct InMemoryRateLimitRepository {
+    buckets: Arc<RwLock<Buckets>>,
+}
+
+impl InMemoryRateLimitRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl RateLimitRepository for InMemoryRateLimitRepository {
+    async fn take(
+        &self,
+        key: String,
+        limit: RateLimit,
+        now: DateTime<Utc>,
+    ) -> Result<RateLimitDecision, String> {
+        let mut buckets = self.buckets.write().await;
+        buckets.sweep(now);
+        if buckets.entries.len() >= MAX_BUCKETS && !buckets.entries.contains_key(&key) {
+            buckets.evict_least_recent();
+        }
+
+        let used = buckets.next_use;
+        buckets.next_use += 1;
+        buckets.recency.insert(used, key.clone());
+        let Buckets {
+            entries, recency, ..
+        } = &mut *buckets;
+        let entry = entries.entry(key).or_insert_with(|| Entry {
+            limit,
+            bucket: TokenBucket::full(&limit, now),
+            used,
+        });
+        if entry.used != used {
+            recency.remove(&entry.used);
+            entry.used = used;
+        }
+        // A changed limit applies from now on, keeping the tokens left.
+        if entry.limit != limit {
+            entry.limit = limit;
+            entry.bucket.tokens = entry.bucket.tokens.min(limit.requests as f64);
+        }
+        Ok(entry.bucket.take(&limit, now))
+    }
+}
@@ -0,0 +1,151 @@
+use crate::domain::entity::sale_event_entity::{
+    CreateSaleEvent, SaleEvent, SaleEventStatus, SaleTarget,
+};
+use crate::domain::repository::sale_event_repository::SaleEventRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, Row, postgres::PgPool, types::Json};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct PostgresSaleEventRepository {
+    pool: Arc<PgPool>,
+}
+
+impl PostgresSaleEventRepository {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SaleEventRepository for PostgresSaleEventRepository {
+    async fn create(&self, event: CreateSaleEvent) -> Result<SaleEvent, String> {
+        let now = Utc::now().naive_utc();
+
+        let row = sqlx::query(
+            r#"
+            INSERT INTO sale_events (id, name, starts_at, ends_at, type, value, target,
+                                     created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5::coupon_discount_type, $6, $7, $8, $8)
+            RETURNING id, name, starts_at, ends_at, type, value, target, status,
+                      cancelled_at, created_at, updated_at
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(&event.name)
+        .bind(event.starts_at.naive_utc())
+        .bind(event.ends_at.naive_utc())
+        .bind(&event.discount_type)
+        .bind(event.value as i64)
+        .bind(Json(&event.target))
+        .bind(now)
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(Self::map_row_to_sale_event(row))
+    }
+
+    async fn find(&self, id: &str) -> Result<SaleEvent, String> {
+        let event_id = Uuid::parse_str(id).map_err(|_| "Sale event not found".to_string())?;
+
+        let row = sqlx::query(
+            r#"
+            SELECT id, name, starts_at, ends_at, type, value, target, status,
+                   cancelled_at, created_at, updated_at
+            FROM sale_events
+            WHERE id = $1
+            "#,
+        )
+        .bind(event_id)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        match row {
+            Some(row) => Ok(Self::map_row_to_sale_event(row)),
+            None => Err("Sale event not found".to_string()),
+        }
+    }
+
+    async fn find_running(&self, now: DateTime<Utc>) -> Result<Vec<SaleEvent>, String> {
+        let mut connection = self.pool.acquire().await.map_err(|e| e.to_string())?;
+        Self::running(&mut connection, now).await
+    }
+
+    async fn cancel(&self, id: &str, now: DateTime<Utc>) -> Result<SaleEvent, String> {
+        let event = self.find(id).await?;
+        if event.status == SaleEventStatus::Cancelled {
+            return Err("Sale event already cancelled".to_string());
+        }
+        if event.ends_at < now {
+            return Err("Sale event already ended".to_string());
+        }
+
+        let row = sqlx::query(
+            r#"
+            UPDATE sale_events
+            SET status = 'cancelled', cancelled_at = $1, updated_at = $1
+            WHERE id = $2 AND status = 'active'
+            RETURNING id, name, starts_at, ends_at, type, value, target, status,
+                      cancelled_at, created_at, updated_at
+            "#,
+        )
+        .bind(now.naive_utc())
+        .bind(event.id)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        match row {
+            Some(row) => Ok(Self::map_row_to_sale_event(row)),
+            None => Err("Sale event already cancelled".to_string()),
+        }
+    }
+}
+
+impl PostgresSaleEventRepository {
+    /// The events running at `now`.
+    pub(crate) async fn running(
+        connection: &mut PgConnection,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<SaleEvent>, String> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, name, starts_at, ends_at, type, value, target, status,
+                   cancelled_at, created_at, updated_at
+            FROM sale_events
+            WHERE status = 'active'
+            AND starts_at <= $1
+            AND ends_at >= $1
+            "#,
+        )
+        .bind(now.naive_utc())
+        .fetch_all(&mut *connection)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(rows.into_iter().map(Self::map_row_to_sale_event).collect())
+    }
+
+    fn map_row_to_sale_event(row: sqlx::postgres::PgRow) -> SaleEvent {
+        SaleEvent {
+            id: row.get("id"),
+            name: row.get("name"),
+            starts_at: row.get::<chrono::NaiveDateTime, _>("starts_at").and_utc(),
+            ends_at: row.get::<chrono::NaiveDateTime, _>("ends_at").and_utc(),
+            discount_type: row.get("type"),
+            value: row.get::<i64, _>("value") as u64,
+            target: row.get::<Json<SaleTarget>, _>("target").0,
+            status: row.get("status"),
+            cancelled_at: row
+                .get::<Option<chrono::NaiveDateTime>, _>("cancelled_at")
+                .map(|dt| dt.and_utc()),
+            created_at: row.get::<chrono::NaiveDateTime, _>("created_at").and_utc(),
+            updated_at: row
+                .get::<Option<chrono::NaiveDateTime>, _>("updated_at")
+                .map(|dt| dt.and_utc()),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::domain::entity::tax_entity::{CreateTaxClass, TaxClass};
+use crate::domain::repository::tax_class_repository::TaxClassRepository;
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{PgConnection, Row, postgres::PgPool};
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct PostgresTaxClassRepository {
+    pool: Arc<PgPool>,
+}
+
+impl PostgresTaxClassRepository {
+    pub fn new(pool: Arc<PgPool>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TaxClassRepository for PostgresTaxClassRepository {
+    async fn create(&self, tax_class: CreateTaxClass) -> Result<TaxClass, String> {
+        let now = Utc::now().naive_utc();
+
+        let row = sqlx::query(
+            r#"
+            INSERT INTO tax_classes (id, name, rate, region, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $5)
+            RETURNING id, name, rate, region, created_at, updated_at
+            "#,
+        )
+        .bind(Uuid::new_v4())
+        .bind(&tax_class.name)
+        .bind(tax_class.rate as i32)
+        .bind(&tax_class.region)
+        .bind(now)
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|e| match &e {
+            sqlx::Error::Database(db_err)
+                if db_err.constraint() == Some("tax_classes_name_region_key") =>
+            {
+                "Tax class already exists".to_string()
+            }
+            _ => e.to_string(),
+        })?;
+
+        Ok(Self::map_row_to_tax_class(row))
+    }
+
+    async fn find(&self, id: &str) -> Result<TaxClass, String> {
+        let tax_class_id = Uuid::parse_str(id).map_err(|_| "Tax class not found".to_string())?;
+
+        let row = sqlx::query(
+            r#"
+            SELECT id, name, rate, region, created_at, updated_at
+            FROM tax_classes
+            WHERE id = $1
+            "#,
+        )
+        .bind(tax_class_id)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        match row {
+            Some(row) => Ok(Self::map_row_to_tax_class(row)),
+            None => Err("Tax class not found".to_string()),
+        }
+    }
+
+    async fn find_all(&self) -> Result<Vec<TaxClass>, String> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, name, rate, region, created_at, updated_at
+            FROM tax_classes
+            ORDER BY region, name
+            "#,
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(rows.into_iter().map(Self::map_row_to_tax_class).collect())
+    }
+
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<HashMap<Uuid, TaxClass>, String> {
+        let mut connection = self.pool.acquire().await.map_err(|e| e.to_string())?;
+        Self::by_ids(&mut connection, ids).await
+    }
+}
+
+impl PostgresTaxClassRepository {
+    /// The tax classes of `ids` that exist, by id.
+    pub(crate) async fn by_ids(
+        connection: &mut PgConnection,
+        ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, TaxClass>, String> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let rows = sqlx::query(
+            r#"
+            SELECT id, name, rate, region, created_at, updated_at
+            FROM tax_classes
+            WHERE id = ANY($1)
+            "#,
+        )
+        .bind(ids)
+        .fetch_all(&mut *connection)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(rows
+            .into_iter()
+            .map(Self::map_row_to_tax_class)
+            .map(|t| (t.id, t))
+            .collect())
+    }
+
+    fn map_row_to_tax_class(row: sqlx::postgres::PgRow) -> TaxClass {
+        TaxClass {
+            id: row.get("id"),
+            name: row.get("name"),
+            rate: row.get::<i32, _>("rate") as u64,
+            region: row.get("region"),
+            created_at: row.get::<chrono::NaiveDateTime, _>("created_at").and_utc(),
+            updated_at: row
+                .get::<Option<chrono::NaiveDateTime>, _>("updated_at")
+                .map(|dt| dt.and_utc()),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Type)]
+#[sqlx(type_name = "campaign_status")]
+#[sqlx(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum CampaignStatus {
+    Active,
+    Closed,
+}
+
+/// A group of coupons sharing a discount budget. Amounts are in cents.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Campaign {
+    pub id: Uuid,
+    pub name: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub budget: u64,
+    pub spent: u64,
+    pub status: CampaignStatus,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl Campaign {
+    pub fn remaining_budget(&self) -> u64 {
+        self.budget.saturating_sub(self.spent)
+    }
+
+    /// Whether a coupon of this campaign may be redeemed at `now`.
+    pub fn is_open(&self, now: DateTime<Utc>) -> bool {
+        self.status == CampaignStatus::Active && self.starts_at <= now && now <= self.ends_at
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CreateCampaign {
+    pub name: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub budget: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CampaignResponse {
+    #[serde(flatten)]
+    pub campaign: Campaign,
+    pub remaining_budget: u64,
+}
+
+impl From<Campaign> for CampaignResponse {
+    fn from(campaign: Campaign) -> Self {
+        let remaining_budget = campaign.remaining_budget();
+        Self {
+            campaign,
+            remaining_budget,
+        }
+    }
+}
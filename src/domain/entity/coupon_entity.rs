@@ -1,12 +1,20 @@
-use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Months, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
 use sqlx::Type;
 use std::fmt;
 use std::str::FromStr;
 use uuid::Uuid;
 use validator::Validate;
 
-use crate::domain::utils::{coupon_value_validate::validate_coupon_value, statics::COUPON_REGEX};
+use crate::domain::{
+    entity::discount_entity::ProductDiscountInfo,
+    pricing::pricing_engine::PricingEngine,
+    utils::{
+        coupon_value_validate::validate_coupon_value,
+        statics::COUPON_REGEX,
+        validation::{FieldError, constraint_error, field_errors, validation_error},
+    },
+};
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Type)]
 #[sqlx(type_name = "coupon_discount_type")]
@@ -15,15 +23,122 @@ use crate::domain::utils::{coupon_value_validate::validate_coupon_value, statics
 pub enum CouponType {
     Fixed,
     Percent,
+    Bogo,
+    Tiered,
 }
 
-impl CouponType {}
+impl CouponType {
+    /// Checks `value` and `params` against this type; mirrors the
+    /// `chk_value_range` constraint.
+    pub fn validate_params(&self, value: u64, params: Option<&CouponParams>) -> Result<(), String> {
+        match (self, params) {
+            (CouponType::Percent, None) if (100..=8000).contains(&value) => Ok(()),
+            (CouponType::Percent, None) => {
+                Err("percent value must be between 100 and 8000".to_string())
+            }
+            (CouponType::Fixed, None) if value > 0 => Ok(()),
+            (CouponType::Fixed, None) => Err("fixed value must be positive".to_string()),
+            (CouponType::Percent | CouponType::Fixed, Some(_)) => {
+                Err(format!("{} coupons take no params", self))
+            }
+            (CouponType::Bogo, Some(CouponParams::Bogo(bogo))) => {
+                let quantities = [bogo.buy_quantity, bogo.get_quantity];
+                if quantities.iter().any(|q| !(1..=999_999).contains(q)) {
+                    return Err(
+                        "buy_quantity and get_quantity must be between 1 and 999999".to_string()
+                    );
+                }
+                if !(100..=10_000).contains(&value) {
+                    return Err("bogo value must be between 100 and 10000".to_string());
+                }
+                Ok(())
+            }
+            (CouponType::Tiered, Some(CouponParams::Tiered(tiered))) => {
+                let Some(top) = tiered.tiers.last() else {
+                    return Err("tiers must not be empty".to_string());
+                };
+                if tiered.tiers.first().is_some_and(|t| t.min_quantity < 2) {
+                    return Err("tiers start at a min_quantity of 2".to_string());
+                }
+                let increasing = tiered
+                    .tiers
+                    .windows(2)
+                    .all(|w| w[0].min_quantity < w[1].min_quantity && w[0].percent < w[1].percent);
+                if !increasing {
+                    return Err("tiers must increase in min_quantity and percent".to_string());
+                }
+                if tiered
+                    .tiers
+                    .iter()
+                    .any(|t| !(100..=8000).contains(&t.percent))
+                {
+                    return Err("tier percent must be between 100 and 8000".to_string());
+                }
+                if value != top.percent {
+                    return Err("tiered value must equal the percent of the top tier".to_string());
+                }
+                Ok(())
+            }
+            (CouponType::Bogo, _) => {
+                Err("bogo coupons need buy_quantity and get_quantity".to_string())
+            }
+            (CouponType::Tiered, _) => Err("tiered coupons need tiers".to_string()),
+        }
+    }
+}
+
+/// Parameters of the quantity-based coupon types, stored as JSON in
+/// `coupons.params`.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(untagged)]
+pub enum CouponParams {
+    Bogo(BogoParams),
+    Tiered(TieredParams),
+}
+
+/// Every `buy_quantity` units bought, the next `get_quantity` units get the
+/// coupon `value` (basis points, 10000 = free) off.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct BogoParams {
+    pub buy_quantity: u32,
+    pub get_quantity: u32,
+}
+
+/// The whole line gets the percent of the highest tier reached.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct TieredParams {
+    pub tiers: Vec<DiscountTier>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct DiscountTier {
+    pub min_quantity: u32,
+    /// Basis points, like percent coupon values.
+    pub percent: u64,
+}
+
+/// `quantity` units of one product, priced with a coupon.
+#[derive(Debug, Serialize, PartialEq, Clone)]
+pub struct LineTotal {
+    pub quantity: u32,
+    pub unit_price: u64,
+    pub subtotal: u64,
+    pub discount: u64,
+    /// Whether `max_discount_amount` lowered the discount.
+    pub capped: bool,
+    pub total: u64,
+}
 
 impl fmt::Display for CouponType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             CouponType::Percent => write!(f, "percent"),
             CouponType::Fixed => write!(f, "fixed"),
+            CouponType::Bogo => write!(f, "bogo"),
+            CouponType::Tiered => write!(f, "tiered"),
         }
     }
 }
@@ -35,6 +150,8 @@ impl FromStr for CouponType {
         match s.to_lowercase().as_str() {
             "percent" => Ok(CouponType::Percent),
             "fixed" => Ok(CouponType::Fixed),
+            "bogo" => Ok(CouponType::Bogo),
+            "tiered" => Ok(CouponType::Tiered),
             _ => Err(()),
         }
     }
@@ -49,11 +166,27 @@ pub struct Coupon {
     pub coupon_type: CouponType,
     #[validate(custom = "validate_coupon_value")]
     pub value: u64,
+    #[serde(default)]
+    pub params: Option<CouponParams>,
+    /// Ceiling on what one application of the coupon takes off, in cents.
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub max_discount_amount: Option<u64>,
     pub one_shot: bool,
     pub valid_from: DateTime<Utc>,
     pub valid_until: DateTime<Utc>,
     pub uses_count: u32,
+    #[validate(range(min = 1))]
     pub max_uses: Option<u32>,
+    #[validate(range(min = 1))]
+    pub max_uses_per_customer: Option<u32>,
+    #[serde(flatten)]
+    pub rules: CouponRules,
+    #[serde(default)]
+    pub campaign_id: Option<Uuid>,
+    #[serde(flatten)]
+    pub stacking: CouponStacking,
+    pub version: u32,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
     pub deleted_at: Option<DateTime<Utc>>,
@@ -67,10 +200,117 @@ pub struct CreateCoupon {
     pub coupon_type: CouponType,
     #[validate(custom = "validate_coupon_value")]
     pub value: u64,
+    #[serde(default)]
+    pub params: Option<CouponParams>,
+    /// Ceiling on what one application of the coupon takes off, in cents.
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    pub max_discount_amount: Option<u64>,
     pub one_shot: bool,
     pub valid_from: DateTime<Utc>,
     pub valid_until: DateTime<Utc>,
+    #[validate(range(min = 1))]
     pub max_uses: Option<u32>,
+    #[validate(range(min = 1))]
+    pub max_uses_per_customer: Option<u32>,
+    #[serde(flatten)]
+    pub rules: CouponRules,
+    #[serde(default)]
+    pub campaign_id: Option<Uuid>,
+    #[serde(flatten)]
+    pub stacking: CouponStacking,
+}
+
+impl CreateCoupon {
+    /// Runs the derived rules and the ones spanning several fields, which
+    /// mirror the `chk_value_range`, `chk_validity_period` and
+    /// `chk_max_uses` constraints.
+    pub fn field_errors(&self) -> Vec<FieldError> {
+        let mut errors = match self.validate() {
+            Ok(()) => Vec::new(),
+            Err(e) => field_errors(&e),
+        };
+        if let Err(e) = self
+            .coupon_type
+            .validate_params(self.value, self.params.as_ref())
+        {
+            errors.push(FieldError::new("value", "coupon_type", &e));
+        }
+        errors.extend(limit_errors(
+            Some(self.valid_from),
+            Some(self.valid_until),
+            Some(self.one_shot),
+            self.max_uses,
+        ));
+        errors
+    }
+}
+
+/// What `chk_validity_period` and `chk_max_uses` would reject, for the
+/// fields that are known.
+fn limit_errors(
+    valid_from: Option<DateTime<Utc>>,
+    valid_until: Option<DateTime<Utc>>,
+    one_shot: Option<bool>,
+    max_uses: Option<u32>,
+) -> Vec<FieldError> {
+    let mut errors = Vec::new();
+    if let (Some(from), Some(until)) = (valid_from, valid_until) {
+        let latest = from.checked_add_months(Months::new(60));
+        if until <= from || latest.is_some_and(|latest| until > latest) {
+            errors.extend(constraint_error("chk_validity_period"));
+        }
+    }
+    if one_shot == Some(true) && max_uses.is_some() {
+        errors.extend(constraint_error("chk_max_uses"));
+    }
+    errors
+}
+
+/// Shared settings for coupons generated in bulk; only the code varies.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CouponTemplate {
+    #[serde(rename = "type")]
+    pub coupon_type: CouponType,
+    pub value: u64,
+    #[serde(default)]
+    pub params: Option<CouponParams>,
+    #[serde(default)]
+    pub max_discount_amount: Option<u64>,
+    #[serde(default)]
+    pub one_shot: bool,
+    pub valid_from: DateTime<Utc>,
+    pub valid_until: DateTime<Utc>,
+    #[serde(default)]
+    pub max_uses: Option<u32>,
+    #[serde(default)]
+    pub max_uses_per_customer: Option<u32>,
+    #[serde(flatten)]
+    pub rules: CouponRules,
+    #[serde(default)]
+    pub campaign_id: Option<Uuid>,
+    #[serde(flatten)]
+    pub stacking: CouponStacking,
+}
+
+impl CouponTemplate {
+    pub fn with_code(&self, code: String) -> CreateCoupon {
+        CreateCoupon {
+            code,
+            coupon_type: self.coupon_type.clone(),
+            value: self.value,
+            params: self.params.clone(),
+            max_discount_amount: self.max_discount_amount,
+            one_shot: self.one_shot,
+            valid_from: self.valid_from,
+            valid_until: self.valid_until,
+            max_uses: self.max_uses,
+            max_uses_per_customer: self.max_uses_per_customer,
+            rules: self.rules.clone(),
+            campaign_id: self.campaign_id,
+            stacking: self.stacking.clone(),
+        }
+    }
 }
 
 impl Coupon {
@@ -80,11 +320,18 @@ impl Coupon {
             code: coupon.code,
             coupon_type: coupon.coupon_type,
             value: coupon.value,
+            params: coupon.params,
+            max_discount_amount: coupon.max_discount_amount,
             one_shot: coupon.one_shot,
             valid_from: coupon.valid_from,
             valid_until: coupon.valid_until,
             uses_count: 0,
             max_uses: coupon.max_uses,
+            max_uses_per_customer: coupon.max_uses_per_customer,
+            rules: coupon.rules,
+            campaign_id: coupon.campaign_id,
+            stacking: coupon.stacking,
+            version: 1,
             created_at: Utc::now(),
             updated_at: None,
             deleted_at: None,
@@ -92,26 +339,320 @@ impl Coupon {
     }
 }
 
+impl Coupon {
+    /// Reasons the coupon itself cannot be redeemed at `now`, regardless of
+    /// the product it is applied to.
+    pub fn lifecycle_reasons(&self, now: DateTime<Utc>) -> Vec<IneligibilityReason> {
+        let mut reasons = Vec::new();
+
+        if self.deleted_at.is_some() {
+            reasons.push(IneligibilityReason::Deleted);
+        }
+        if now < self.valid_from {
+            reasons.push(IneligibilityReason::NotYetValid);
+        }
+        if now > self.valid_until {
+            reasons.push(IneligibilityReason::Expired);
+        }
+        let global_limit = match self.one_shot {
+            true => Some(1),
+            false => self.max_uses,
+        };
+        if global_limit.is_some_and(|limit| self.uses_count >= limit) {
+            reasons.push(IneligibilityReason::UsageLimitReached);
+        }
+
+        reasons
+    }
+
+    /// Whether an application of this coupon still counts towards a
+    /// product's price at `now`.
+    pub fn is_active_at(&self, now: DateTime<Utc>) -> bool {
+        self.deleted_at.is_none() && self.valid_from <= now && now <= self.valid_until
+    }
+
+    /// Limits `amount` to `max_discount_amount`; the flag tells whether the
+    /// cap was hit.
+    pub fn cap_discount(&self, amount: u64) -> (u64, bool) {
+        match self.max_discount_amount {
+            Some(max) if amount > max => (max, true),
+            _ => (amount, false),
+        }
+    }
+
+    /// Prices `quantity` units at `unit_price` with this coupon alone.
+    /// Percent and fixed coupons discount every unit as `pricing` would;
+    /// bogo and tiered coupons look at the whole line. `max_discount_amount`
+    /// limits the line's discount as a whole. Fails when the line is too
+    /// large to price.
+    pub fn line_total(
+        &self,
+        pricing: &PricingEngine,
+        unit_price: u64,
+        quantity: u32,
+    ) -> Result<LineTotal, String> {
+        let subtotal = unit_price
+            .checked_mul(quantity as u64)
+            .ok_or_else(|| validation_error(&[FieldError::overflow("quantity")]))?;
+        let discount = match (&self.coupon_type, &self.params) {
+            (CouponType::Bogo, Some(CouponParams::Bogo(bogo))) => {
+                let group = bogo
+                    .buy_quantity
+                    .checked_add(bogo.get_quantity)
+                    .ok_or_else(|| validation_error(&[FieldError::overflow("quantity")]))?;
+                let discounted_units = quantity / group * bogo.get_quantity;
+                pricing.percent_of(discounted_units as u64 * unit_price, self.value)
+            }
+            (CouponType::Tiered, Some(CouponParams::Tiered(tiered))) => tiered
+                .tiers
+                .iter()
+                .rev()
+                .find(|tier| quantity >= tier.min_quantity)
+                .map_or(0, |tier| pricing.percent_of(subtotal, tier.percent)),
+            _ => {
+                // `max_discount_amount` caps the line below, not each unit.
+                let unit = pricing.price(
+                    unit_price,
+                    vec![ProductDiscountInfo {
+                        max_discount_amount: None,
+                        ..ProductDiscountInfo::new(self, self.created_at)
+                    }],
+                );
+                unit.total_discount * quantity as u64
+            }
+        }
+        .min(subtotal - pricing.min_price.min(unit_price) * quantity as u64);
+        let (discount, capped) = self.cap_discount(discount);
+
+        Ok(LineTotal {
+            quantity,
+            unit_price,
+            subtotal,
+            discount,
+            capped,
+            total: subtotal - discount,
+        })
+    }
+}
+
 #[derive(Debug, Deserialize, Validate, Clone)]
 pub struct UpdateCoupon {
     #[serde(rename = "type")]
     pub coupon_type: Option<CouponType>,
+    #[validate(custom = "validate_coupon_value")]
     pub value: Option<u64>,
+    /// `Some(None)` clears the params, as `null` does in the request.
+    #[serde(default, deserialize_with = "explicit_null")]
+    pub params: Option<Option<CouponParams>>,
+    #[validate(range(min = 1))]
+    pub max_discount_amount: Option<u64>,
     pub one_shot: Option<bool>,
     pub valid_from: Option<DateTime<Utc>>,
     pub valid_until: Option<DateTime<Utc>>,
+    #[validate(range(min = 1))]
     pub max_uses: Option<u32>,
+    #[validate(range(min = 1))]
+    pub max_uses_per_customer: Option<u32>,
+    pub rules: UpdateCouponRules,
+    pub campaign_id: Option<Uuid>,
+    pub stacking: UpdateCouponStacking,
 }
 
 impl UpdateCoupon {
+    /// Runs the derived rules on what the update sends. Type, value and
+    /// params are checked together only when all three are sent; partial
+    /// changes are left to the `chk_value_range` constraint.
+    pub fn field_errors(&self) -> Vec<FieldError> {
+        let mut errors = match self.validate() {
+            Ok(()) => Vec::new(),
+            Err(e) => field_errors(&e),
+        };
+        if let (Some(coupon_type), Some(value), Some(params)) =
+            (&self.coupon_type, self.value, &self.params)
+            && let Err(e) = coupon_type.validate_params(value, params.as_ref())
+        {
+            errors.push(FieldError::new("value", "coupon_type", &e));
+        }
+        errors.extend(limit_errors(
+            self.valid_from,
+            self.valid_until,
+            self.one_shot,
+            self.max_uses,
+        ));
+        errors
+    }
+
     pub fn new(coupon: UpdateCoupon) -> Self {
         Self {
             coupon_type: coupon.coupon_type,
             value: coupon.value,
+            params: coupon.params,
+            max_discount_amount: coupon.max_discount_amount,
             one_shot: coupon.one_shot,
             valid_from: coupon.valid_from,
             valid_until: coupon.valid_until,
             max_uses: coupon.max_uses,
+            max_uses_per_customer: coupon.max_uses_per_customer,
+            rules: coupon.rules,
+            campaign_id: coupon.campaign_id,
+            stacking: coupon.stacking,
+        }
+    }
+}
+
+/// Reads a field that may be absent, `null` or set as `None`, `Some(None)`
+/// and `Some(Some(_))`; use with `#[serde(default)]`.
+pub fn explicit_null<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// Which products a coupon may be applied to. Empty lists and `None`
+/// mean "no restriction".
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct CouponRules {
+    #[serde(default)]
+    pub allowed_product_ids: Vec<Uuid>,
+    #[serde(default)]
+    pub denied_product_ids: Vec<Uuid>,
+    #[serde(default)]
+    pub min_product_price: Option<u64>,
+    #[serde(default)]
+    pub exclude_discounted: bool,
+}
+
+impl CouponRules {
+    /// Every rule the product breaks, in evaluation order.
+    pub fn ineligibility_reasons(
+        &self,
+        product_id: Uuid,
+        price: u64,
+        already_discounted: bool,
+    ) -> Vec<IneligibilityReason> {
+        let mut reasons = Vec::new();
+
+        if !self.allowed_product_ids.is_empty() && !self.allowed_product_ids.contains(&product_id) {
+            reasons.push(IneligibilityReason::ProductNotAllowed);
+        }
+        if self.denied_product_ids.contains(&product_id) {
+            reasons.push(IneligibilityReason::ProductExcluded);
         }
+        if self.min_product_price.is_some_and(|min| price < min) {
+            reasons.push(IneligibilityReason::BelowMinPrice);
+        }
+        if self.exclude_discounted && already_discounted {
+            reasons.push(IneligibilityReason::AlreadyDiscounted);
+        }
+
+        reasons
     }
 }
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct UpdateCouponRules {
+    #[serde(default)]
+    pub allowed_product_ids: Option<Vec<Uuid>>,
+    #[serde(default)]
+    pub denied_product_ids: Option<Vec<Uuid>>,
+    #[serde(default)]
+    pub min_product_price: Option<u64>,
+    #[serde(default)]
+    pub exclude_discounted: Option<bool>,
+}
+
+/// Whether a coupon may share a product with other coupons, and where it
+/// goes in the application order when it does.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct CouponStacking {
+    #[serde(default)]
+    pub stackable: bool,
+    #[serde(default)]
+    pub priority: i32,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct UpdateCouponStacking {
+    #[serde(default)]
+    pub stackable: Option<bool>,
+    #[serde(default)]
+    pub priority: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum IneligibilityReason {
+    ProductNotAllowed,
+    ProductExcluded,
+    BelowMinPrice,
+    AlreadyDiscounted,
+    Deleted,
+    NotYetValid,
+    Expired,
+    UsageLimitReached,
+    CustomerRequired,
+    CustomerLimitReached,
+    CampaignInactive,
+    CampaignBudgetExhausted,
+    /// No coupon has the code; only reported by quotes.
+    NotFound,
+}
+
+impl fmt::Display for IneligibilityReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IneligibilityReason::ProductNotAllowed => write!(f, "product_not_allowed"),
+            IneligibilityReason::ProductExcluded => write!(f, "product_excluded"),
+            IneligibilityReason::BelowMinPrice => write!(f, "below_min_price"),
+            IneligibilityReason::AlreadyDiscounted => write!(f, "already_discounted"),
+            IneligibilityReason::Deleted => write!(f, "deleted"),
+            IneligibilityReason::NotYetValid => write!(f, "not_yet_valid"),
+            IneligibilityReason::Expired => write!(f, "expired"),
+            IneligibilityReason::UsageLimitReached => write!(f, "usage_limit_reached"),
+            IneligibilityReason::CustomerRequired => write!(f, "customer_required"),
+            IneligibilityReason::CustomerLimitReached => write!(f, "customer_limit_reached"),
+            IneligibilityReason::CampaignInactive => write!(f, "campaign_inactive"),
+            IneligibilityReason::CampaignBudgetExhausted => write!(f, "campaign_budget_exhausted"),
+            IneligibilityReason::NotFound => write!(f, "not_found"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CouponRedemption {
+    pub id: Uuid,
+    pub coupon_id: Uuid,
+    pub product_id: Uuid,
+    pub application_id: Option<Uuid>,
+    pub order_id: Option<Uuid>,
+    pub discount_amount: u64,
+    pub customer_ref: Option<String>,
+    pub redeemed_at: DateTime<Utc>,
+    /// When the order it belongs to gave the coupon use back; reversed
+    /// redemptions no longer count as uses.
+    pub reversed_at: Option<DateTime<Utc>>,
+}
+
+impl CouponRedemption {
+    /// The use this redemption belongs to: its own, or its order's, as an
+    /// order uses a coupon once however many lines it discounts.
+    pub fn use_id(&self) -> Uuid {
+        self.order_id.unwrap_or(self.id)
+    }
+}
+
+/// Outcome of checking a coupon against a product without applying it.
+#[derive(Debug, Serialize)]
+pub struct CouponValidation {
+    pub code: String,
+    pub product_id: Uuid,
+    pub applicable: bool,
+    pub reasons: Vec<IneligibilityReason>,
+    pub price: u64,
+    pub discount_amount: u64,
+    /// Whether the coupon's `max_discount_amount` lowered `discount_amount`.
+    pub capped: bool,
+    pub final_price: u64,
+}
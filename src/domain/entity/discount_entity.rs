@@ -1,7 +1,20 @@
 use chrono::{DateTime, Utc};
 use serde::Serialize;
+use sqlx::Type;
+use std::cmp::Reverse;
+use std::fmt;
+use std::str::FromStr;
 use uuid::Uuid;
 
+use crate::domain::{
+    entity::{
+        coupon_entity::{Coupon, CouponType},
+        product_entity::Product,
+        sale_event_entity::SaleEvent,
+    },
+    pricing::pricing_engine::{PriceBreakdown, TaxBreakdown},
+};
+
 #[derive(Debug, Clone, Serialize)]
 pub struct ProductDiscount {
     pub id: Uuid,
@@ -9,6 +22,53 @@ pub struct ProductDiscount {
     pub coupon_id: Uuid,
     pub applied_at: DateTime<Utc>,
     pub removed_at: Option<DateTime<Utc>>,
+    pub removal_reason: Option<RemovalReason>,
+}
+
+/// Why an application stopped counting towards the product's price.
+#[derive(Debug, Serialize, PartialEq, Clone, Copy, Type)]
+#[sqlx(type_name = "application_removal_reason")]
+#[sqlx(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum RemovalReason {
+    /// Taken off through the API.
+    Removed,
+    Expired,
+    CouponDeleted,
+}
+
+impl fmt::Display for RemovalReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RemovalReason::Removed => write!(f, "removed"),
+            RemovalReason::Expired => write!(f, "expired"),
+            RemovalReason::CouponDeleted => write!(f, "coupon_deleted"),
+        }
+    }
+}
+
+/// One entry of a product's or a coupon's discount history.
+#[derive(Debug, Serialize, Clone)]
+pub struct DiscountApplication {
+    #[serde(flatten)]
+    pub discount: ProductDiscount,
+    pub coupon_code: String,
+}
+
+/// Time window for discount history. An application matches when it was
+/// in effect at some point inside the window; open bounds are unlimited.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DiscountPeriod {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl DiscountPeriod {
+    pub fn overlaps(&self, discount: &ProductDiscount) -> bool {
+        self.from
+            .is_none_or(|from| discount.removed_at.is_none_or(|removed| removed >= from))
+            && self.to.is_none_or(|to| discount.applied_at <= to)
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -21,18 +81,158 @@ pub struct ProductResponse {
     pub is_out_of_stock: bool,
     pub price: u64,
     pub final_price: u64,
-    pub discount: Option<ProductDiscountInfo>,
+    pub total_discount: u64,
+    /// Whether the minimum price held `final_price` up.
+    pub floor_applied: bool,
+    pub discounts: Vec<ProductDiscountInfo>,
     pub has_coupon_applied: bool,
+    pub tax_class_id: Option<Uuid>,
+    pub tax: TaxBreakdown,
+    pub version: u32,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Serialize)]
+impl ProductResponse {
+    /// `product` as served by the API, priced by `breakdown`.
+    pub fn new(product: Product, breakdown: PriceBreakdown) -> Self {
+        Self {
+            id: product.id,
+            name: product.name,
+            description: product.description,
+            stock: product.stock,
+            is_out_of_stock: product.stock == 0,
+            price: breakdown.base_price,
+            final_price: breakdown.final_price,
+            total_discount: breakdown.total_discount,
+            floor_applied: breakdown.floor_applied,
+            has_coupon_applied: breakdown
+                .discounts
+                .iter()
+                .any(|d| d.source == DiscountSource::Coupon),
+            discounts: breakdown.discounts,
+            tax_class_id: product.tax_class_id,
+            tax: breakdown.tax,
+            version: product.version,
+            created_at: product.created_at,
+            updated_at: product.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscountSource {
+    Coupon,
+    SaleEvent,
+}
+
+/// One discount on a product, from an applied coupon or a running sale
+/// event; `amount` is what it took off the price once stacked with the
+/// product's other discounts.
+#[derive(Debug, Serialize, Clone)]
 pub struct ProductDiscountInfo {
+    pub source: DiscountSource,
+    /// The coupon code, or the sale event name.
+    pub code: String,
     #[serde(rename = "type")]
-    pub discount_type: String,
+    pub discount_type: CouponType,
     pub value: u64,
+    pub stackable: bool,
+    pub priority: i32,
+    pub max_discount_amount: Option<u64>,
     pub applied_at: DateTime<Utc>,
+    pub amount: u64,
+    /// Whether `max_discount_amount` lowered `amount`.
+    pub capped: bool,
+}
+
+impl ProductDiscountInfo {
+    pub fn new(coupon: &Coupon, applied_at: DateTime<Utc>) -> Self {
+        Self {
+            source: DiscountSource::Coupon,
+            code: coupon.code.clone(),
+            discount_type: coupon.coupon_type.clone(),
+            value: coupon.value,
+            stackable: coupon.stacking.stackable,
+            priority: coupon.stacking.priority,
+            max_discount_amount: coupon.max_discount_amount,
+            applied_at,
+            amount: 0,
+            capped: false,
+        }
+    }
+
+    /// A running sale event, stacked with any coupon on the product.
+    pub fn from_sale_event(event: &SaleEvent) -> Self {
+        Self {
+            source: DiscountSource::SaleEvent,
+            code: event.name.clone(),
+            discount_type: event.discount_type.clone(),
+            value: event.value,
+            stackable: true,
+            priority: 0,
+            max_discount_amount: None,
+            applied_at: event.starts_at,
+            amount: 0,
+            capped: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StackingOrder {
+    /// Percent coupons first, then fixed ones; priority breaks ties.
+    PercentFirst,
+    /// Highest priority first, regardless of type.
+    Priority,
+}
+
+impl FromStr for StackingOrder {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "percent_first" => Ok(StackingOrder::PercentFirst),
+            "priority" => Ok(StackingOrder::Priority),
+            _ => Err(()),
+        }
+    }
+}
+
+/// How several coupons on the same product combine.
+#[derive(Debug, Clone, Copy)]
+pub struct StackingPolicy {
+    pub order: StackingOrder,
+    /// Cap on the combined discount of a stack, in basis points of the price.
+    pub max_combined_bps: u64,
+}
+
+impl Default for StackingPolicy {
+    fn default() -> Self {
+        Self {
+            order: StackingOrder::PercentFirst,
+            max_combined_bps: 8000,
+        }
+    }
+}
+
+impl StackingPolicy {
+    /// Puts `discounts` in application order.
+    pub fn sort(&self, discounts: &mut [ProductDiscountInfo]) {
+        match self.order {
+            StackingOrder::PercentFirst => discounts.sort_by_key(|d| {
+                (
+                    d.discount_type != CouponType::Percent,
+                    Reverse(d.priority),
+                    d.applied_at,
+                )
+            }),
+            StackingOrder::Priority => {
+                discounts.sort_by_key(|d| (Reverse(d.priority), d.applied_at))
+            }
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -0,0 +1,13 @@
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IdempotencyLookup {
+    /// The key is new (or expired) and the caller now holds its lock.
+    Acquired,
+    /// A previous request with the same key and payload already completed.
+    Replay { status: u16, body: Value },
+    /// A request with the same key is still being processed.
+    InProgress,
+    /// The key was already used with a different payload.
+    Mismatch,
+}
@@ -1,3 +1,10 @@
+pub mod campaign_entity;
 pub mod coupon_entity;
 pub mod discount_entity;
+pub mod idempotency_entity;
+pub mod order_entity;
 pub mod product_entity;
+pub mod quote_entity;
+pub mod rate_limit_entity;
+pub mod sale_event_entity;
+pub mod tax_entity;
@@ -0,0 +1,315 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use std::fmt;
+use uuid::Uuid;
+
+use crate::domain::{
+    entity::quote_entity::QuoteItem,
+    utils::validation::{FieldError, validation_error},
+};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Type)]
+#[sqlx(type_name = "order_status")]
+#[sqlx(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+    /// Placed: stock is taken and the coupon redeemed, awaiting payment.
+    Pending,
+    Paid,
+    Fulfilled,
+    Cancelled,
+    /// Some units were refunded; more can be.
+    PartiallyRefunded,
+    Refunded,
+}
+
+impl fmt::Display for OrderStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OrderStatus::Pending => write!(f, "pending"),
+            OrderStatus::Paid => write!(f, "paid"),
+            OrderStatus::Fulfilled => write!(f, "fulfilled"),
+            OrderStatus::Cancelled => write!(f, "cancelled"),
+            OrderStatus::PartiallyRefunded => write!(f, "partially_refunded"),
+            OrderStatus::Refunded => write!(f, "refunded"),
+        }
+    }
+}
+
+impl OrderStatus {
+    /// The status `action` takes an order in this status to, if it is
+    /// allowed. Only unpaid orders are cancelled; paid ones are refunded, so
+    /// the money returned is recorded. A refund only ends in `refunded` once
+    /// every unit is.
+    pub fn after(self, action: OrderAction, fully_refunded: bool) -> Option<OrderStatus> {
+        match (self, action) {
+            (OrderStatus::Pending, OrderAction::Pay) => Some(OrderStatus::Paid),
+            (OrderStatus::Paid, OrderAction::Fulfill) => Some(OrderStatus::Fulfilled),
+            (OrderStatus::Pending, OrderAction::Cancel) => Some(OrderStatus::Cancelled),
+            (
+                OrderStatus::Paid | OrderStatus::Fulfilled | OrderStatus::PartiallyRefunded,
+                OrderAction::Refund,
+            ) => match fully_refunded {
+                true => Some(OrderStatus::Refunded),
+                false => Some(OrderStatus::PartiallyRefunded),
+            },
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Type)]
+#[sqlx(type_name = "order_action")]
+#[sqlx(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum OrderAction {
+    Pay,
+    Fulfill,
+    Cancel,
+    Refund,
+}
+
+impl fmt::Display for OrderAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OrderAction::Pay => write!(f, "pay"),
+            OrderAction::Fulfill => write!(f, "fulfill"),
+            OrderAction::Cancel => write!(f, "cancel"),
+            OrderAction::Refund => write!(f, "refund"),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CreateOrder {
+    pub items: Vec<QuoteItem>,
+    #[serde(default)]
+    pub coupon_code: Option<String>,
+    #[serde(default)]
+    pub customer_id: Option<String>,
+}
+
+/// One product of an order, as it was priced when the order was placed.
+#[derive(Debug, Serialize, Clone)]
+pub struct OrderLine {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub name: String,
+    pub quantity: u32,
+    /// Base price of one unit.
+    pub price: u64,
+    /// One unit after the product's own discounts.
+    pub unit_price: u64,
+    pub subtotal: u64,
+    /// What the order coupon took off the line.
+    pub discount_total: u64,
+    pub total: u64,
+    pub tax_rate: u64,
+    pub tax_amount: u64,
+    pub refunded_quantity: u32,
+    pub refunded_amount: u64,
+}
+
+impl OrderLine {
+    /// Units not refunded yet.
+    pub fn remaining(&self) -> u32 {
+        self.quantity - self.refunded_quantity
+    }
+
+    /// What refunding `quantity` more units gives back: a share of `total`,
+    /// with whatever rounding left over going to the last unit refunded.
+    pub fn refund_amount(&self, quantity: u32) -> u64 {
+        match quantity == self.remaining() {
+            true => self.total - self.refunded_amount,
+            false => self.total * quantity as u64 / self.quantity as u64,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderLineRefund {
+    pub line_id: Uuid,
+    pub quantity: u32,
+}
+
+/// Options of a transition; only cancellations and refunds take any.
+#[derive(Debug, Deserialize, Default, Clone)]
+pub struct TransitionOrder {
+    /// Lines to refund; all that is left when empty.
+    #[serde(default)]
+    pub lines: Vec<OrderLineRefund>,
+    /// Gives the coupon use back when the order is cancelled or fully
+    /// refunded.
+    #[serde(default)]
+    pub restore_coupon: bool,
+}
+
+/// One transition in an order's history.
+#[derive(Debug, Serialize, Clone)]
+pub struct OrderEvent {
+    pub id: Uuid,
+    pub action: OrderAction,
+    pub from_status: OrderStatus,
+    pub to_status: OrderStatus,
+    /// Units the transition put back in stock, per line.
+    pub lines: Vec<OrderLineRefund>,
+    pub refund_amount: u64,
+    pub coupon_restored: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// What a transition changes outside the order itself.
+#[derive(Debug, Clone)]
+pub struct OrderChange {
+    pub event: OrderEvent,
+    /// Units to put back in stock, per product.
+    pub restock: Vec<(Uuid, u32)>,
+}
+
+/// A placed order. Amounts are in cents and `total` follows the store's
+/// tax convention, like a quote.
+#[derive(Debug, Serialize, Clone)]
+pub struct Order {
+    pub id: Uuid,
+    pub customer_ref: Option<String>,
+    pub status: OrderStatus,
+    pub coupon_code: Option<String>,
+    pub lines: Vec<OrderLine>,
+    pub subtotal: u64,
+    pub discount_total: u64,
+    pub total: u64,
+    pub prices_include_tax: bool,
+    pub tax_amount: u64,
+    pub net_total: u64,
+    pub gross_total: u64,
+    pub refunded_total: u64,
+    pub history: Vec<OrderEvent>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl Order {
+    /// Applies `action` to the order, updating its status, lines and
+    /// history, and returns what the repository must change around it.
+    pub fn transition(
+        &mut self,
+        action: OrderAction,
+        transition: TransitionOrder,
+        now: DateTime<Utc>,
+    ) -> Result<OrderChange, String> {
+        if action != OrderAction::Refund && !transition.lines.is_empty() {
+            return Err(validation_error(&[FieldError::new(
+                "lines",
+                "refund_only",
+                "Only a refund takes lines",
+            )]));
+        }
+        let returns_coupon = matches!(action, OrderAction::Cancel | OrderAction::Refund);
+        if transition.restore_coupon && !returns_coupon {
+            return Err(validation_error(&[FieldError::new(
+                "restore_coupon",
+                "cancel_or_refund",
+                "Only a cancel or a refund restores the coupon",
+            )]));
+        }
+
+        // Units each line gives back: everything left, unless a refund
+        // names its lines.
+        let mut returned: Vec<u32> = match action {
+            OrderAction::Cancel | OrderAction::Refund => {
+                self.lines.iter().map(OrderLine::remaining).collect()
+            }
+            OrderAction::Pay | OrderAction::Fulfill => vec![0; self.lines.len()],
+        };
+        if !transition.lines.is_empty() {
+            returned = vec![0; self.lines.len()];
+            let mut errors = Vec::new();
+            for (position, refund) in transition.lines.iter().enumerate() {
+                let line_id = format!("lines[{}].line_id", position);
+                let Some(index) = self.lines.iter().position(|l| l.id == refund.line_id) else {
+                    errors.push(FieldError::new(
+                        &line_id,
+                        "not_found",
+                        "Not a line of this order",
+                    ));
+                    continue;
+                };
+                if returned[index] > 0 {
+                    errors.push(FieldError::new(
+                        &line_id,
+                        "duplicate",
+                        "Line is listed more than once",
+                    ));
+                    continue;
+                }
+                let remaining = self.lines[index].remaining();
+                if refund.quantity == 0 || refund.quantity > remaining {
+                    let quantity = format!("lines[{}].quantity", position);
+                    errors.push(FieldError::range(&quantity, 1, remaining as u64));
+                    continue;
+                }
+                returned[index] = refund.quantity;
+            }
+            if !errors.is_empty() {
+                return Err(validation_error(&errors));
+            }
+        }
+
+        let fully_refunded = self
+            .lines
+            .iter()
+            .zip(&returned)
+            .all(|(line, units)| line.remaining() == *units);
+        let Some(to_status) = self.status.after(action, fully_refunded) else {
+            return Err(format!("Order is {}, cannot {}", self.status, action));
+        };
+        if transition.restore_coupon && !fully_refunded {
+            return Err(validation_error(&[FieldError::new(
+                "restore_coupon",
+                "partial_refund",
+                "The coupon is only restored when every unit is given back",
+            )]));
+        }
+        let coupon_restored = transition.restore_coupon
+            && self.coupon_code.is_some()
+            && !self.history.iter().any(|e| e.coupon_restored);
+
+        let mut lines = Vec::new();
+        let mut restock = Vec::new();
+        let mut refund_amount = 0;
+        for (line, units) in self.lines.iter_mut().zip(returned) {
+            if units == 0 {
+                continue;
+            }
+            if action == OrderAction::Refund {
+                let amount = line.refund_amount(units);
+                line.refunded_quantity += units;
+                line.refunded_amount += amount;
+                refund_amount += amount;
+            }
+            lines.push(OrderLineRefund {
+                line_id: line.id,
+                quantity: units,
+            });
+            restock.push((line.product_id, units));
+        }
+
+        let event = OrderEvent {
+            id: Uuid::new_v4(),
+            action,
+            from_status: self.status,
+            to_status,
+            lines,
+            refund_amount,
+            coupon_restored,
+            created_at: now,
+        };
+        self.status = to_status;
+        self.refunded_total += refund_amount;
+        self.updated_at = Some(now);
+        self.history.push(event.clone());
+
+        Ok(OrderChange { event, restock })
+    }
+}
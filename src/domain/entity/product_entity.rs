@@ -16,6 +16,9 @@ pub struct Product {
     #[validate(range(min = 0, max = 999999))]
     pub stock: u32,
     pub price: u64,
+    pub version: u32,
+    #[serde(default)]
+    pub tax_class_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: Option<DateTime<Utc>>,
     pub deleted_at: Option<DateTime<Utc>>,
@@ -56,7 +59,6 @@ pub struct UpdateProduct {
     pub stock: Option<u32>,
     #[validate(range(min = 1))]
     pub price: Option<u64>,
-    pub deleted_at: Option<DateTime<Utc>>,
 }
 
 impl UpdateProduct {
@@ -65,14 +67,12 @@ impl UpdateProduct {
         description: Option<String>,
         stock: Option<u32>,
         price: Option<u64>,
-        deleted_at: Option<DateTime<Utc>>,
     ) -> Self {
         UpdateProduct {
             name,
             description,
             stock,
             price,
-            deleted_at,
         }
     }
 }
@@ -98,6 +98,12 @@ pub struct ApplyPercentDiscount {
     pub percentage: u32,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct AssignTaxClass {
+    /// `null` takes the product out of any tax class.
+    pub tax_class_id: Option<Uuid>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct ApplyCoupon {
     pub code: String,
@@ -0,0 +1,336 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::domain::{
+    entity::{
+        coupon_entity::{Coupon, IneligibilityReason},
+        discount_entity::{DiscountSource, ProductDiscountInfo, ProductResponse},
+    },
+    pricing::{pricing_engine::PricingEngine, tax_policy::TaxPolicy},
+    utils::validation::{FieldError, validation_error},
+};
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct QuoteItem {
+    pub product_id: Uuid,
+    pub quantity: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CreateQuote {
+    pub items: Vec<QuoteItem>,
+    #[serde(default)]
+    pub coupon_codes: Vec<String>,
+    #[serde(default)]
+    pub customer_id: Option<String>,
+}
+
+/// What a quote coupon took off one line.
+#[derive(Debug, Serialize, Clone)]
+pub struct QuoteCouponDiscount {
+    pub code: String,
+    pub amount: u64,
+    /// Whether the coupon's `max_discount_amount` lowered `amount`.
+    pub capped: bool,
+}
+
+/// One product of a quote. Unit amounts come from the product's own
+/// pricing; the quote coupons then come off the whole line.
+#[derive(Debug, Serialize, Clone)]
+pub struct QuoteLine {
+    pub product_id: Uuid,
+    pub name: String,
+    pub quantity: u32,
+    /// Base price of one unit.
+    pub price: u64,
+    /// One unit after the product's discounts, as in `final_price`.
+    pub unit_price: u64,
+    pub discounts: Vec<ProductDiscountInfo>,
+    pub subtotal: u64,
+    pub coupons: Vec<QuoteCouponDiscount>,
+    pub discount_total: u64,
+    pub total: u64,
+    pub tax_rate: u64,
+    pub tax_amount: u64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum QuoteWarning {
+    /// The product is unknown or deleted; it has no line.
+    ProductNotFound { product_id: Uuid },
+    InsufficientStock {
+        product_id: Uuid,
+        requested: u32,
+        available: u32,
+    },
+}
+
+/// A requested coupon that discounted no line, and why.
+#[derive(Debug, Serialize, Clone)]
+pub struct QuoteCouponError {
+    pub code: String,
+    pub reasons: Vec<IneligibilityReason>,
+}
+
+impl QuoteWarning {
+    /// The error placing an order fails with instead.
+    pub fn order_error(&self) -> String {
+        match self {
+            QuoteWarning::ProductNotFound { .. } => "Product not found".to_string(),
+            QuoteWarning::InsufficientStock { .. } => "Insufficient stock".to_string(),
+        }
+    }
+}
+
+impl QuoteCouponError {
+    /// The error placing an order fails with instead: the one
+    /// `apply_coupon` would have given for the first reason.
+    pub fn order_error(&self) -> String {
+        match self.reasons.first() {
+            Some(IneligibilityReason::NotFound | IneligibilityReason::Deleted) => {
+                "Coupon not found".to_string()
+            }
+            Some(IneligibilityReason::NotYetValid | IneligibilityReason::Expired) => {
+                "Coupon is not valid".to_string()
+            }
+            Some(IneligibilityReason::UsageLimitReached) => {
+                "Coupon usage limit reached".to_string()
+            }
+            Some(IneligibilityReason::CustomerRequired) => {
+                "Customer reference is required for this coupon".to_string()
+            }
+            Some(IneligibilityReason::CustomerLimitReached) => {
+                "Customer usage limit reached".to_string()
+            }
+            Some(IneligibilityReason::CampaignInactive) => "Campaign is not active".to_string(),
+            Some(IneligibilityReason::CampaignBudgetExhausted) => {
+                "Campaign budget exhausted".to_string()
+            }
+            Some(reason) => format!("Coupon not eligible: {}", reason),
+            None => "Coupon not eligible".to_string(),
+        }
+    }
+}
+
+/// Cart totals priced from the current catalog; nothing is reserved or
+/// redeemed. `total` follows the store's tax convention, like
+/// `final_price`.
+#[derive(Debug, Serialize, Clone)]
+pub struct Quote {
+    pub lines: Vec<QuoteLine>,
+    pub subtotal: u64,
+    pub discount_total: u64,
+    pub total: u64,
+    pub prices_include_tax: bool,
+    pub tax_amount: u64,
+    pub net_total: u64,
+    pub gross_total: u64,
+    pub warnings: Vec<QuoteWarning>,
+    pub coupon_errors: Vec<QuoteCouponError>,
+}
+
+/// What a coupon takes off each line, by line index.
+pub type LineDiscounts = Vec<(usize, QuoteCouponDiscount)>;
+
+/// A priced product and its line; `stackable` holds one flag per quote
+/// coupon already on the line.
+pub struct PricedLine {
+    pub product: ProductResponse,
+    pub line: QuoteLine,
+    pub stackable: Vec<bool>,
+}
+
+/// A quote being priced: its lines before the quote coupons come off and
+/// taxes are worked out. Quotes and orders are both priced through it, from
+/// whatever they read the catalog and coupons with.
+pub struct QuoteDraft {
+    pub lines: Vec<PricedLine>,
+    pub warnings: Vec<QuoteWarning>,
+    pub coupon_errors: Vec<QuoteCouponError>,
+}
+
+impl QuoteDraft {
+    /// A line for each of `items` whose product is in `products`, priced as
+    /// the API serves it; the others get a warning.
+    pub fn new(
+        items: &[QuoteItem],
+        mut products: HashMap<Uuid, ProductResponse>,
+    ) -> Result<Self, String> {
+        let mut warnings = Vec::new();
+        let mut lines = Vec::new();
+        for item in items {
+            let Some(product) = products.remove(&item.product_id) else {
+                warnings.push(QuoteWarning::ProductNotFound {
+                    product_id: item.product_id,
+                });
+                continue;
+            };
+            if item.quantity > product.stock {
+                warnings.push(QuoteWarning::InsufficientStock {
+                    product_id: product.id,
+                    requested: item.quantity,
+                    available: product.stock,
+                });
+            }
+
+            let subtotal = product
+                .final_price
+                .checked_mul(item.quantity as u64)
+                .ok_or_else(overflow_error)?;
+            lines.push(PricedLine {
+                line: QuoteLine {
+                    product_id: product.id,
+                    name: product.name.clone(),
+                    quantity: item.quantity,
+                    price: product.price,
+                    unit_price: product.final_price,
+                    discounts: product.discounts.clone(),
+                    subtotal,
+                    coupons: Vec::new(),
+                    discount_total: 0,
+                    total: subtotal,
+                    tax_rate: product.tax.rate,
+                    tax_amount: 0,
+                },
+                product,
+                stackable: Vec::new(),
+            });
+        }
+
+        Ok(Self {
+            lines,
+            warnings,
+            coupon_errors: Vec::new(),
+        })
+    }
+
+    /// What `coupon` takes off each line it is eligible for, or why it
+    /// discounts no line. Each line discount is worked out on the unit price
+    /// and never takes the line below the minimum price. The coupon's
+    /// lifecycle, usage limits and campaign are the caller's to check.
+    pub fn coupon_discounts(
+        &self,
+        coupon: &Coupon,
+        pricing: &PricingEngine,
+    ) -> Result<Result<LineDiscounts, Vec<IneligibilityReason>>, String> {
+        let mut discounts = Vec::new();
+        let mut line_reasons = Vec::new();
+        for (index, priced) in self.lines.iter().enumerate() {
+            let product = &priced.product;
+            let mut reasons = coupon.rules.ineligibility_reasons(
+                product.id,
+                product.price,
+                product.has_coupon_applied,
+            );
+            // Coupons only share a line when all of them are stackable.
+            let shared = product.has_coupon_applied || !priced.stackable.is_empty();
+            let stacks = coupon.stacking.stackable
+                && product.discounts.iter().all(|d| d.stackable)
+                && priced.stackable.iter().all(|s| *s);
+            let on_product = product
+                .discounts
+                .iter()
+                .any(|d| d.source == DiscountSource::Coupon && d.code == coupon.code);
+            if ((shared && !stacks) || on_product)
+                && !reasons.contains(&IneligibilityReason::AlreadyDiscounted)
+            {
+                reasons.push(IneligibilityReason::AlreadyDiscounted);
+            }
+
+            if !reasons.is_empty() {
+                for reason in reasons {
+                    if !line_reasons.contains(&reason) {
+                        line_reasons.push(reason);
+                    }
+                }
+                continue;
+            }
+
+            let line = &priced.line;
+            let line_total = coupon.line_total(pricing, line.unit_price, line.quantity)?;
+            let floor = pricing.min_price.min(line.unit_price) * line.quantity as u64;
+            discounts.push((
+                index,
+                QuoteCouponDiscount {
+                    code: coupon.code.clone(),
+                    amount: line_total.discount.min(line.total.saturating_sub(floor)),
+                    capped: line_total.capped,
+                },
+            ));
+        }
+
+        match discounts.is_empty() {
+            true => Ok(Err(line_reasons)),
+            false => Ok(Ok(discounts)),
+        }
+    }
+
+    /// Takes a coupon's `discounts` off their lines.
+    pub fn add_coupon(&mut self, stackable: bool, discounts: LineDiscounts) {
+        for (index, discount) in discounts {
+            let priced = &mut self.lines[index];
+            priced.line.discount_total += discount.amount;
+            priced.line.total -= discount.amount;
+            priced.line.coupons.push(discount);
+            priced.stackable.push(stackable);
+        }
+    }
+
+    /// The quote, with each line taxed on its total.
+    pub fn finish(self, tax: TaxPolicy) -> Result<Quote, String> {
+        let mut lines = Vec::with_capacity(self.lines.len());
+        for priced in self.lines {
+            let mut line = priced.line;
+            // The tax is worked out on the line total times the rate, or
+            // times 10_000 when it is taken out of a gross total.
+            let factor = match tax.prices_include_tax {
+                true => 10_000,
+                false => line.tax_rate,
+            };
+            line.total.checked_mul(factor).ok_or_else(overflow_error)?;
+            line.tax_amount = match tax.prices_include_tax {
+                true => tax.tax_in_gross(line.total, line.tax_rate),
+                false => tax.tax_on_net(line.total, line.tax_rate),
+            };
+            lines.push(line);
+        }
+
+        let sum = |amount: fn(&QuoteLine) -> u64| {
+            lines
+                .iter()
+                .try_fold(0u64, |sum, line| sum.checked_add(amount(line)))
+                .ok_or_else(overflow_error)
+        };
+        let subtotal = sum(|l| l.subtotal)?;
+        let discount_total = sum(|l| l.discount_total)?;
+        let total = sum(|l| l.total)?;
+        let tax_amount = sum(|l| l.tax_amount)?;
+        let (net_total, gross_total) = match tax.prices_include_tax {
+            true => (total - tax_amount, total),
+            false => (
+                total,
+                total.checked_add(tax_amount).ok_or_else(overflow_error)?,
+            ),
+        };
+
+        Ok(Quote {
+            lines,
+            subtotal,
+            discount_total,
+            total,
+            prices_include_tax: tax.prices_include_tax,
+            tax_amount,
+            net_total,
+            gross_total,
+            warnings: self.warnings,
+            coupon_errors: self.coupon_errors,
+        })
+    }
+}
+
+/// The validation error for a quote whose amounts do not fit in a `u64`.
+pub fn overflow_error() -> String {
+    validation_error(&[FieldError::overflow("items")])
+}
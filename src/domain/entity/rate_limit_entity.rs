@@ -0,0 +1,91 @@
+use chrono::{DateTime, Utc};
+use std::str::FromStr;
+
+/// At most `requests` requests every `period_secs` seconds, written
+/// `requests/period_secs` in config.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    pub requests: u32,
+    pub period_secs: u64,
+}
+
+impl RateLimit {
+    /// Tokens a bucket under this limit gains per second.
+    fn refill_rate(&self) -> f64 {
+        self.requests as f64 / self.period_secs as f64
+    }
+}
+
+impl FromStr for RateLimit {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (requests, period_secs) = s.trim().split_once('/').ok_or(())?;
+        let limit = RateLimit {
+            requests: requests.trim().parse().map_err(|_| ())?,
+            period_secs: period_secs.trim().parse().map_err(|_| ())?,
+        };
+        match limit.requests > 0 && limit.period_secs > 0 {
+            true => Ok(limit),
+            false => Err(()),
+        }
+    }
+}
+
+/// A client's allowance under a `RateLimit`: it holds up to `requests`
+/// tokens, refilled evenly over the period, and each request takes one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TokenBucket {
+    pub tokens: f64,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Whether a request was let through, and what the client has left.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimitDecision {
+    pub allowed: bool,
+    pub limit: RateLimit,
+    pub remaining: u32,
+    /// Seconds until the bucket is full again.
+    pub reset_secs: u64,
+    /// Seconds until the next request can go through; 0 when allowed.
+    pub retry_after_secs: u64,
+}
+
+impl TokenBucket {
+    pub fn full(limit: &RateLimit, now: DateTime<Utc>) -> Self {
+        Self {
+            tokens: limit.requests as f64,
+            updated_at: now,
+        }
+    }
+
+    /// Tokens held at `now`, counting the refill since the last request.
+    pub fn tokens_at(&self, limit: &RateLimit, now: DateTime<Utc>) -> f64 {
+        let elapsed = (now - self.updated_at).num_milliseconds().max(0) as f64 / 1000.0;
+        (self.tokens + elapsed * limit.refill_rate()).min(limit.requests as f64)
+    }
+
+    /// Takes a token for a request made at `now`, if there is one.
+    pub fn take(&mut self, limit: &RateLimit, now: DateTime<Utc>) -> RateLimitDecision {
+        self.tokens = self.tokens_at(limit, now);
+        self.updated_at = now;
+
+        let allowed = self.tokens >= 1.0;
+        if allowed {
+            self.tokens -= 1.0;
+        }
+        let seconds_for = |tokens: f64| (tokens / limit.refill_rate()).ceil().max(0.0) as u64;
+
+        RateLimitDecision {
+            allowed,
+            limit: *limit,
+            remaining: self.tokens.floor() as u32,
+            reset_secs: seconds_for(limit.requests as f64 - self.tokens),
+            retry_after_secs: match allowed {
+                true => 0,
+                false => seconds_for(1.0 - self.tokens).max(1),
+            },
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::Type;
+use uuid::Uuid;
+
+use crate::domain::{
+    entity::{
+        coupon_entity::CouponType, discount_entity::ProductDiscountInfo, product_entity::Product,
+    },
+    pricing::pricing_engine::PricingEngine,
+};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Type)]
+#[sqlx(type_name = "sale_event_status")]
+#[sqlx(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum SaleEventStatus {
+    Active,
+    Cancelled,
+}
+
+/// Which products a sale event discounts, stored as JSON in
+/// `sale_events.target`.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SaleTarget {
+    All,
+    Products {
+        product_ids: Vec<Uuid>,
+    },
+    /// Base prices in cents, both ends included.
+    PriceRange {
+        min_price: u64,
+        max_price: u64,
+    },
+    /// Same matching as the listing `search`: name or description.
+    Search {
+        term: String,
+    },
+}
+
+impl SaleTarget {
+    pub fn matches(&self, product: &Product) -> bool {
+        match self {
+            SaleTarget::All => true,
+            SaleTarget::Products { product_ids } => product_ids.contains(&product.id),
+            SaleTarget::PriceRange {
+                min_price,
+                max_price,
+            } => (*min_price..=*max_price).contains(&product.price),
+            SaleTarget::Search { term } => {
+                let term = term.to_lowercase();
+                product.name.to_lowercase().contains(&term)
+                    || product
+                        .description
+                        .as_ref()
+                        .is_some_and(|d| d.to_lowercase().contains(&term))
+            }
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            SaleTarget::All => Ok(()),
+            SaleTarget::Products { product_ids } if product_ids.is_empty() => {
+                Err("product_ids must not be empty".to_string())
+            }
+            SaleTarget::Products { .. } => Ok(()),
+            SaleTarget::PriceRange {
+                min_price,
+                max_price,
+            } if min_price > max_price => Err("min_price must not be above max_price".to_string()),
+            SaleTarget::PriceRange { max_price, .. } if *max_price > i64::MAX as u64 => {
+                Err("max_price is too large".to_string())
+            }
+            SaleTarget::PriceRange { .. } => Ok(()),
+            SaleTarget::Search { term } if term.trim().is_empty() => {
+                Err("term must not be empty".to_string())
+            }
+            SaleTarget::Search { .. } => Ok(()),
+        }
+    }
+}
+
+/// A storewide sale: while it runs, every product its target selects is
+/// priced with its discount, without any application rows.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SaleEvent {
+    pub id: Uuid,
+    pub name: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    #[serde(rename = "type")]
+    pub discount_type: CouponType,
+    /// Basis points for percent events, cents for fixed ones.
+    pub value: u64,
+    pub target: SaleTarget,
+    pub status: SaleEventStatus,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl SaleEvent {
+    /// The event among `events` that takes the most off `product`, as
+    /// priced by `pricing`. Events never add up: overlapping sales give the
+    /// better one only.
+    pub fn best_for<'a>(
+        events: &'a [SaleEvent],
+        product: &Product,
+        pricing: &PricingEngine,
+    ) -> Option<&'a SaleEvent> {
+        events
+            .iter()
+            .filter(|e| e.target.matches(product))
+            .max_by_key(|e| {
+                let breakdown =
+                    pricing.price(product.price, vec![ProductDiscountInfo::from_sale_event(e)]);
+                (breakdown.total_discount, e.starts_at)
+            })
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CreateSaleEvent {
+    pub name: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    #[serde(rename = "type")]
+    pub discount_type: CouponType,
+    pub value: u64,
+    pub target: SaleTarget,
+}
+
+/// One product a sale event selects, priced with that event alone.
+#[derive(Debug, Serialize)]
+pub struct SaleEventPreview {
+    pub product_id: Uuid,
+    pub name: String,
+    pub price: u64,
+    pub discount_amount: u64,
+    pub sale_price: u64,
+}
@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A tax rate products can be assigned to, e.g. ICMS in SP or the standard
+/// VAT rate of one EU country.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TaxClass {
+    pub id: Uuid,
+    pub name: String,
+    /// Basis points: 1800 is 18%.
+    pub rate: u64,
+    pub region: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CreateTaxClass {
+    pub name: String,
+    pub rate: u64,
+    pub region: String,
+}
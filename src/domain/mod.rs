@@ -1,4 +1,5 @@
 pub mod entity;
+pub mod pricing;
 pub mod repository;
 pub mod usecase;
 pub mod utils;
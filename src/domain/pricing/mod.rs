@@ -0,0 +1,3 @@
+pub mod pricing_engine;
+pub mod rounding_policy;
+pub mod tax_policy;
@@ -0,0 +1,170 @@
+use serde::Serialize;
+
+use crate::domain::{
+    entity::{
+        coupon_entity::CouponType,
+        discount_entity::{ProductDiscountInfo, StackingPolicy},
+    },
+    pricing::{
+        rounding_policy::RoundingPolicy,
+        tax_policy::{DiscountTaxOrder, TaxPolicy},
+    },
+};
+
+/// How a product's price was reached. Amounts are in cents.
+#[derive(Debug, Serialize, Clone)]
+pub struct PriceBreakdown {
+    pub base_price: u64,
+    /// In application order, each with the amount it took off. With a tax
+    /// rate, amounts are on the net price when discounts go before tax and
+    /// on the gross price when they go after it.
+    pub discounts: Vec<ProductDiscountInfo>,
+    pub total_discount: u64,
+    /// Tax included when the store's prices are, excluded otherwise.
+    pub final_price: u64,
+    /// Whether `min_price` held the final price up.
+    pub floor_applied: bool,
+    pub tax: TaxBreakdown,
+}
+
+/// The discounted price with and without tax.
+#[derive(Debug, Serialize, Clone)]
+pub struct TaxBreakdown {
+    /// Basis points; 0 for products without a tax class.
+    pub rate: u64,
+    pub prices_include_tax: bool,
+    pub net_price: u64,
+    pub tax_amount: u64,
+    pub gross_price: u64,
+}
+
+/// The single place where discounts turn into a final price.
+#[derive(Debug, Clone, Copy)]
+pub struct PricingEngine {
+    pub stacking: StackingPolicy,
+    pub rounding: RoundingPolicy,
+    /// Lowest final price any combination of discounts may reach.
+    pub min_price: u64,
+    pub tax: TaxPolicy,
+}
+
+impl Default for PricingEngine {
+    fn default() -> Self {
+        Self {
+            stacking: StackingPolicy::default(),
+            rounding: RoundingPolicy::default(),
+            min_price: 1,
+            tax: TaxPolicy::default(),
+        }
+    }
+}
+
+impl PricingEngine {
+    /// Prices one unit at `base_price` with `discounts`, which may come in
+    /// any order. Each discount applies to the price left by the previous
+    /// ones, limited by its own `max_discount_amount`, by the stacking cap
+    /// and by `min_price`.
+    pub fn price(
+        &self,
+        base_price: u64,
+        mut discounts: Vec<ProductDiscountInfo>,
+    ) -> PriceBreakdown {
+        self.stacking.sort(&mut discounts);
+
+        let combined_cap = match discounts.len() > 1 {
+            true => self.percent_of(base_price, self.stacking.max_combined_bps),
+            false => base_price,
+        };
+        let floor = self.min_price.min(base_price);
+        let mut remaining = base_price;
+        let mut total_discount = 0;
+        let mut floor_applied = false;
+
+        for discount in discounts.iter_mut() {
+            let amount = self.amount_for(discount, remaining);
+            discount.capped = discount.max_discount_amount.is_some_and(|max| amount > max);
+            let amount = discount
+                .max_discount_amount
+                .map_or(amount, |max| amount.min(max))
+                .min(combined_cap.saturating_sub(total_discount));
+            let room = remaining - floor;
+            floor_applied |= amount > room;
+            discount.amount = amount.min(room);
+            remaining -= discount.amount;
+            total_discount += discount.amount;
+        }
+
+        PriceBreakdown {
+            base_price,
+            discounts,
+            total_discount,
+            final_price: remaining,
+            floor_applied,
+            tax: TaxBreakdown {
+                rate: 0,
+                prices_include_tax: self.tax.prices_include_tax,
+                net_price: remaining,
+                tax_amount: 0,
+                gross_price: remaining,
+            },
+        }
+    }
+
+    /// Like `price`, for a product taxed at `tax_rate` basis points.
+    /// Discounts apply to the net or the gross price depending on the
+    /// store's discount order, whichever way `base_price` is stored.
+    pub fn price_with_tax(
+        &self,
+        base_price: u64,
+        tax_rate: u64,
+        discounts: Vec<ProductDiscountInfo>,
+    ) -> PriceBreakdown {
+        let (net, gross) = self.tax.split(base_price, tax_rate);
+
+        let (mut breakdown, net_price, gross_price) = match self.tax.discount_order {
+            DiscountTaxOrder::BeforeTax => {
+                let breakdown = self.price(net, discounts);
+                let net_price = breakdown.final_price;
+                let gross_price = net_price + self.tax.tax_on_net(net_price, tax_rate);
+                (breakdown, net_price, gross_price)
+            }
+            DiscountTaxOrder::AfterTax => {
+                let breakdown = self.price(gross, discounts);
+                let gross_price = breakdown.final_price;
+                let net_price = gross_price - self.tax.tax_in_gross(gross_price, tax_rate);
+                (breakdown, net_price, gross_price)
+            }
+        };
+
+        breakdown.base_price = base_price;
+        breakdown.final_price = match self.tax.prices_include_tax {
+            true => gross_price,
+            false => net_price,
+        };
+        breakdown.tax = TaxBreakdown {
+            rate: tax_rate,
+            prices_include_tax: self.tax.prices_include_tax,
+            net_price,
+            tax_amount: gross_price - net_price,
+            gross_price,
+        };
+        breakdown
+    }
+
+    /// `bps` basis points of `amount`, rounded by the rounding policy.
+    /// Only the fraction left by the last 10 000 cents is rounded, so large
+    /// amounts do not overflow.
+    pub fn percent_of(&self, amount: u64, bps: u64) -> u64 {
+        amount / 10_000 * bps + self.rounding.divide(amount % 10_000 * bps, 10_000)
+    }
+
+    /// What `discount` takes off `price` before any cap. Quantity-based
+    /// coupon types take nothing off a single unit.
+    fn amount_for(&self, discount: &ProductDiscountInfo, price: u64) -> u64 {
+        match discount.discount_type {
+            CouponType::Percent => self.percent_of(price, discount.value),
+            CouponType::Fixed => discount.value,
+            CouponType::Bogo | CouponType::Tiered => 0,
+        }
+    }
+}
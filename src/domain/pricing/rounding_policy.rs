@@ -0,0 +1,35 @@
+use std::str::FromStr;
+
+/// How fractional cents of a percent discount are rounded.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RoundingPolicy {
+    /// Towards zero, so the customer never gets more than the exact percent.
+    #[default]
+    Down,
+    HalfUp,
+    Up,
+}
+
+impl RoundingPolicy {
+    /// `numerator / denominator`, rounded by this policy.
+    pub fn divide(&self, numerator: u64, denominator: u64) -> u64 {
+        match self {
+            RoundingPolicy::Down => numerator / denominator,
+            RoundingPolicy::HalfUp => (numerator + denominator / 2) / denominator,
+            RoundingPolicy::Up => numerator.div_ceil(denominator),
+        }
+    }
+}
+
+impl FromStr for RoundingPolicy {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "down" => Ok(RoundingPolicy::Down),
+            "half_up" => Ok(RoundingPolicy::HalfUp),
+            "up" => Ok(RoundingPolicy::Up),
+            _ => Err(()),
+        }
+    }
+}
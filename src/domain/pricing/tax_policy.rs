@@ -0,0 +1,57 @@
+use std::str::FromStr;
+
+use crate::domain::pricing::rounding_policy::RoundingPolicy;
+
+/// Whether discounts are taken off the price before or after tax.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DiscountTaxOrder {
+    /// Discounts reduce the net price and tax is charged on what is left.
+    #[default]
+    BeforeTax,
+    /// Discounts reduce the gross price and the tax is taken out of it.
+    AfterTax,
+}
+
+impl FromStr for DiscountTaxOrder {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "before_tax" => Ok(DiscountTaxOrder::BeforeTax),
+            "after_tax" => Ok(DiscountTaxOrder::AfterTax),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Store-level tax settings.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TaxPolicy {
+    /// Whether `products.price` is gross (tax included) or net.
+    pub prices_include_tax: bool,
+    pub discount_order: DiscountTaxOrder,
+}
+
+impl TaxPolicy {
+    /// Tax amounts are always rounded to the nearest cent, whatever the
+    /// discount rounding is.
+    const ROUNDING: RoundingPolicy = RoundingPolicy::HalfUp;
+
+    /// Tax charged on `net` at `rate` basis points.
+    pub fn tax_on_net(&self, net: u64, rate: u64) -> u64 {
+        Self::ROUNDING.divide(net * rate, 10_000)
+    }
+
+    /// Tax contained in `gross` at `rate` basis points.
+    pub fn tax_in_gross(&self, gross: u64, rate: u64) -> u64 {
+        gross - Self::ROUNDING.divide(gross * 10_000, 10_000 + rate)
+    }
+
+    /// `(net, gross)` of a stored price at `rate` basis points.
+    pub fn split(&self, price: u64, rate: u64) -> (u64, u64) {
+        match self.prices_include_tax {
+            true => (price - self.tax_in_gross(price, rate), price),
+            false => (price, price + self.tax_on_net(price, rate)),
+        }
+    }
+}
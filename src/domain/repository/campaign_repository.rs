@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use crate::domain::entity::{
+    campaign_entity::{Campaign, CampaignStatus, CreateCampaign},
+    discount_entity::PaginatedResponse,
+};
+
+#[async_trait]
+pub trait CampaignRepository: Send + Sync {
+    async fn create(&self, campaign: CreateCampaign) -> Result<Campaign, String>;
+    async fn find(&self, id: &str) -> Result<Campaign, String>;
+    async fn find_all(
+        &self,
+        page: Option<u32>,
+        limit: Option<u32>,
+        status: Option<CampaignStatus>,
+    ) -> Result<PaginatedResponse<Campaign>, String>;
+}
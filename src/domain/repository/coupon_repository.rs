@@ -1,14 +1,17 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use uuid::Uuid;
 
 use crate::domain::entity::{
-    coupon_entity::{Coupon, CreateCoupon, UpdateCoupon},
+    coupon_entity::{Coupon, CouponRedemption, CreateCoupon, UpdateCoupon},
     discount_entity::PaginatedResponse,
 };
 
 #[async_trait]
 pub trait CouponRepository: Send + Sync {
     async fn create(&self, coupon: CreateCoupon) -> Result<Coupon, String>;
+    /// Inserts every coupon or none of them.
+    async fn create_many(&self, coupons: Vec<CreateCoupon>) -> Result<Vec<Coupon>, String>;
     async fn find(&self, code: &str) -> Result<Coupon, String>;
     async fn find_all(
         &self,
@@ -19,8 +22,27 @@ pub trait CouponRepository: Send + Sync {
         valid_until: Option<DateTime<Utc>>,
         is_active: Option<bool>,
     ) -> Result<PaginatedResponse<Coupon>, String>;
-    async fn update(&self, id: String, data: UpdateCoupon) -> Result<Coupon, String>;
-    async fn delete(&self, id: String) -> Result<(), String>;
-    async fn find_valid_coupon_by_code(&self, code: &str) -> Result<Coupon, String>;
-    async fn increment_uses(&self, coupon_id: String);
+    async fn update(
+        &self,
+        id: String,
+        data: UpdateCoupon,
+        expected_versions: Option<Vec<u32>>,
+    ) -> Result<Coupon, String>;
+    async fn delete(&self, id: String, expected_versions: Option<Vec<u32>>) -> Result<(), String>;
+    /// Like `find`, but also returns soft-deleted coupons.
+    async fn find_with_deleted(&self, code: &str) -> Result<Coupon, String>;
+    async fn count_customer_redemptions(
+        &self,
+        coupon_id: Uuid,
+        customer_ref: &str,
+    ) -> Result<u64, String>;
+    async fn find_redemptions(
+        &self,
+        code: &str,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedResponse<CouponRedemption>, String>;
+    /// Sets `uses_count` to the number of redemptions in the ledger for
+    /// every coupon where they differ, returning how many were corrected.
+    async fn reconcile_uses_count(&self) -> Result<u64, String>;
 }
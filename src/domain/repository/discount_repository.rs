@@ -1,6 +1,17 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
 
-use crate::domain::entity::{coupon_entity::Coupon, discount_entity::ProductDiscount};
+use crate::domain::{
+    entity::{
+        coupon_entity::Coupon,
+        discount_entity::{
+            DiscountApplication, DiscountPeriod, PaginatedResponse, ProductDiscount,
+        },
+    },
+    pricing::pricing_engine::PricingEngine,
+};
 
 #[async_trait]
 pub trait DiscountRepository: Send + Sync {
@@ -8,6 +19,8 @@ pub trait DiscountRepository: Send + Sync {
         &self,
         product_id: String,
         coupon_id: String,
+        customer_ref: Option<String>,
+        pricing: PricingEngine,
     ) -> Result<ProductDiscount, String>;
 
     async fn remove_coupon(
@@ -16,8 +29,43 @@ pub trait DiscountRepository: Send + Sync {
         coupon_id: String,
     ) -> Result<ProductDiscount, String>;
 
-    async fn find_active_discount(
+    /// Applications whose coupon is still valid, oldest first.
+    async fn find_active_discounts(
         &self,
         product_id: String,
-    ) -> Result<Option<(ProductDiscount, Coupon)>, String>;
+    ) -> Result<Vec<(ProductDiscount, Coupon)>, String>;
+
+    /// `find_active_discounts` for a whole page of products in one lookup.
+    /// Products without discounts are left out of the map.
+    async fn find_active_discounts_for(
+        &self,
+        product_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<(ProductDiscount, Coupon)>>, String>;
+
+    /// Ends every open application whose coupon expired or was deleted by
+    /// `now`, recording why, and returns the applications it closed.
+    async fn close_dead_applications(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<ProductDiscount>, String>;
+
+    /// Every application a product ever had, removed ones included, newest
+    /// first. Trashed products keep their history.
+    async fn find_product_history(
+        &self,
+        product_id: String,
+        period: DiscountPeriod,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedResponse<DiscountApplication>, String>;
+
+    /// Every application of a coupon code, newest first, including those of
+    /// deleted coupons that used the same code.
+    async fn find_coupon_applications(
+        &self,
+        code: &str,
+        period: DiscountPeriod,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedResponse<DiscountApplication>, String>;
 }
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::domain::entity::idempotency_entity::IdempotencyLookup;
+
+#[async_trait]
+pub trait IdempotencyRepository: Send + Sync {
+    /// Claims `key` within `scope` for `request`. Expired keys and locks
+    /// held since before `stale_before` for the same request are taken over.
+    async fn acquire(
+        &self,
+        scope: String,
+        key: String,
+        request: Value,
+        expires_at: DateTime<Utc>,
+        stale_before: DateTime<Utc>,
+    ) -> Result<IdempotencyLookup, String>;
+    async fn complete(
+        &self,
+        scope: String,
+        key: String,
+        status: u16,
+        body: Value,
+    ) -> Result<(), String>;
+    async fn release(&self, scope: String, key: String) -> Result<(), String>;
+    async fn purge_expired(&self, now: DateTime<Utc>) -> Result<u64, String>;
+}
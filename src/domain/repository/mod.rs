@@ -1,3 +1,9 @@
+pub mod campaign_repository;
 pub mod coupon_repository;
 pub mod discount_repository;
+pub mod idempotency_repository;
+pub mod order_repository;
 pub mod product_repository;
+pub mod rate_limit_repository;
+pub mod sale_event_repository;
+pub mod tax_class_repository;
@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    entity::{
+        discount_entity::PaginatedResponse,
+        order_entity::{Order, OrderAction, TransitionOrder},
+        quote_entity::QuoteItem,
+    },
+    pricing::pricing_engine::PricingEngine,
+};
+
+#[async_trait]
+pub trait OrderRepository: Send + Sync {
+    /// Places an order for `items`, in one transaction: the coupon and
+    /// product rows are locked, the lines priced by `pricing` from what is
+    /// in effect under those locks, the stock taken, and the coupon redeemed
+    /// under the same checks as `apply_coupon`.
+    async fn create(
+        &self,
+        items: Vec<QuoteItem>,
+        coupon_code: Option<String>,
+        customer_ref: Option<String>,
+        pricing: PricingEngine,
+    ) -> Result<Order, String>;
+
+    async fn find(&self, id: &str) -> Result<Order, String>;
+
+    /// Orders newest first, optionally of one customer.
+    async fn find_all(
+        &self,
+        page: Option<u32>,
+        limit: Option<u32>,
+        customer_ref: Option<String>,
+    ) -> Result<PaginatedResponse<Order>, String>;
+
+    /// Applies `action` to order `id` in one transaction: the order row is
+    /// locked while the state machine is checked, and the stock and coupon
+    /// use the transition gives back are returned with it.
+    async fn transition(
+        &self,
+        id: &str,
+        action: OrderAction,
+        transition: TransitionOrder,
+    ) -> Result<Order, String>;
+}
@@ -1,14 +1,20 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
 
 use crate::domain::entity::{
     discount_entity::PaginatedResponse,
     product_entity::{CreateProduct, Product, UpdateProduct},
+    sale_event_entity::SaleTarget,
 };
 
 #[allow(dead_code)]
 #[async_trait]
 pub trait ProductRepository: Send + Sync {
     async fn find(&self, id: String) -> Result<Product, String>;
+    /// The live products among `ids`, in no particular order; unknown and
+    /// deleted ids are left out.
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Product>, String>;
     async fn find_all(
         &self,
         page: Option<u32>,
@@ -19,7 +25,33 @@ pub trait ProductRepository: Send + Sync {
         has_discount: bool,
     ) -> Result<PaginatedResponse<Product>, String>;
     async fn create(&self, product: CreateProduct) -> Result<Product, String>;
-    async fn update(&self, id: String, new_product: UpdateProduct) -> Result<Product, String>;
-    async fn delete(&self, id: String) -> Result<(), String>;
+    async fn update(
+        &self,
+        id: String,
+        new_product: UpdateProduct,
+        expected_versions: Option<Vec<u32>>,
+    ) -> Result<Product, String>;
+    async fn delete(&self, id: String, expected_versions: Option<Vec<u32>>) -> Result<(), String>;
+    async fn find_deleted(
+        &self,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedResponse<Product>, String>;
+    async fn restore(&self, id: String) -> Result<Product, String>;
+    async fn purge(&self, id: String) -> Result<(), String>;
+    async fn purge_deleted_before(&self, cutoff: DateTime<Utc>) -> Result<u64, String>;
     async fn has_discount(&self, product_id: String) -> bool;
+    /// Puts a live product in `tax_class_id`, or in none.
+    async fn set_tax_class(
+        &self,
+        id: String,
+        tax_class_id: Option<Uuid>,
+    ) -> Result<Product, String>;
+    /// Live products a sale event with `target` covers, newest first.
+    async fn find_by_sale_target(
+        &self,
+        target: &SaleTarget,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedResponse<Product>, String>;
 }
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::entity::rate_limit_entity::{RateLimit, RateLimitDecision};
+
+#[async_trait]
+pub trait RateLimitRepository: Send + Sync {
+    /// Takes a token from the bucket stored under `key`, starting a full one
+    /// under `limit` if there is none.
+    async fn take(
+        &self,
+        key: String,
+        limit: RateLimit,
+        now: DateTime<Utc>,
+    ) -> Result<RateLimitDecision, String>;
+}
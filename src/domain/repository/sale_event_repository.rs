@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::entity::sale_event_entity::{CreateSaleEvent, SaleEvent};
+
+#[async_trait]
+pub trait SaleEventRepository: Send + Sync {
+    async fn create(&self, event: CreateSaleEvent) -> Result<SaleEvent, String>;
+    async fn find(&self, id: &str) -> Result<SaleEvent, String>;
+    /// Events running at `now`, for pricing.
+    async fn find_running(&self, now: DateTime<Utc>) -> Result<Vec<SaleEvent>, String>;
+    /// Stops an event that has not ended yet; it no longer prices anything.
+    async fn cancel(&self, id: &str, now: DateTime<Utc>) -> Result<SaleEvent, String>;
+}
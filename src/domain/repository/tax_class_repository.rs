@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::domain::entity::tax_entity::{CreateTaxClass, TaxClass};
+
+#[async_trait]
+pub trait TaxClassRepository: Send + Sync {
+    async fn create(&self, tax_class: CreateTaxClass) -> Result<TaxClass, String>;
+    async fn find(&self, id: &str) -> Result<TaxClass, String>;
+    /// Every tax class, by region then name.
+    async fn find_all(&self) -> Result<Vec<TaxClass>, String>;
+    /// The tax classes among `ids`, keyed by id; unknown ids are left out.
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<HashMap<Uuid, TaxClass>, String>;
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::campaign_entity::{CampaignResponse, CreateCampaign},
+    repository::campaign_repository::CampaignRepository,
+    utils::validation::{FieldError, validation_error},
+};
+
+pub struct CreateCampaignUseCase {
+    pub repository: Arc<RwLock<dyn CampaignRepository + Send + Sync>>,
+}
+
+impl CreateCampaignUseCase {
+    pub fn new(repository: Arc<RwLock<dyn CampaignRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, campaign: CreateCampaign) -> Result<CampaignResponse, String> {
+        log::info!("Start request");
+
+        let name = campaign.name.trim();
+        let mut errors = Vec::new();
+        if name.is_empty() || name.chars().count() > 100 {
+            errors.push(FieldError::length("name", 1, 100));
+        }
+        if campaign.budget == 0 || campaign.budget > i64::MAX as u64 {
+            errors.push(FieldError::range("budget", 1, i64::MAX as u64));
+        }
+        if campaign.ends_at <= campaign.starts_at {
+            errors.push(FieldError::new(
+                "ends_at",
+                "period",
+                "ends_at must be after starts_at",
+            ));
+        }
+        if !errors.is_empty() {
+            return Err(validation_error(&errors));
+        }
+
+        let campaign = CreateCampaign {
+            name: name.to_string(),
+            ..campaign
+        };
+
+        let repository = self.repository.write().await;
+        let created = repository.create(campaign).await.map_err(|e| {
+            log::error!("Erro ao criar campanha: {}", e);
+            e
+        })?;
+
+        log::info!("End request");
+        Ok(created.into())
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::campaign_entity::CampaignResponse, repository::campaign_repository::CampaignRepository,
+};
+
+pub struct GetCampaignUseCase {
+    pub repository: Arc<RwLock<dyn CampaignRepository + Send + Sync>>,
+}
+
+impl GetCampaignUseCase {
+    pub fn new(repository: Arc<RwLock<dyn CampaignRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, id: String) -> Result<CampaignResponse, String> {
+        log::info!("Start request");
+        let repository = self.repository.read().await;
+        let campaign = repository.find(&id).await.map_err(|e| {
+            log::error!("Error: {}", e);
+            e
+        })?;
+        log::info!("End request");
+        Ok(campaign.into())
+    }
+}
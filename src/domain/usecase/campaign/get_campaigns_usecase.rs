@@ -0,0 +1,42 @@
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::{
+        campaign_entity::{CampaignResponse, CampaignStatus},
+        discount_entity::PaginatedResponse,
+    },
+    repository::campaign_repository::CampaignRepository,
+};
+
+pub struct GetCampaignsUseCase {
+    pub repository: Arc<RwLock<dyn CampaignRepository + Send + Sync>>,
+}
+
+impl GetCampaignsUseCase {
+    pub fn new(repository: Arc<RwLock<dyn CampaignRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        page: u32,
+        limit: u32,
+        status: Option<CampaignStatus>,
+    ) -> Result<PaginatedResponse<CampaignResponse>, String> {
+        log::info!("Start request");
+        let repository = self.repository.read().await;
+        let campaigns = repository
+            .find_all(Some(page), Some(limit), status)
+            .await
+            .map_err(|e| {
+                log::error!("Error: {}", e);
+                e
+            })?;
+        log::info!("End request");
+        Ok(PaginatedResponse {
+            data: campaigns.data.into_iter().map(Into::into).collect(),
+            meta: campaigns.meta,
+        })
+    }
+}
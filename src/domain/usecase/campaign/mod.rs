@@ -0,0 +1,3 @@
+pub mod create_campaign_usecase;
+pub mod get_campaign_usecase;
+pub mod get_campaigns_usecase;
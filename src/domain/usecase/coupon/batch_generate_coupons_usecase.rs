@@ -0,0 +1,121 @@
+use chrono::Months;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::coupon_entity::{Coupon, CouponTemplate},
+    repository::coupon_repository::CouponRepository,
+    utils::coupon_code_generator::{UNAMBIGUOUS_ALPHABET, generate_coupon_code},
+};
+
+pub const MAX_BATCH_SIZE: u32 = 10_000;
+const MIN_CODE_LENGTH: usize = 4;
+const MAX_CODE_LENGTH: usize = 20;
+const MAX_ATTEMPTS: u32 = 3;
+
+pub struct BatchGenerateCouponsUseCase {
+    pub repository: Arc<RwLock<dyn CouponRepository + Send + Sync>>,
+}
+
+impl BatchGenerateCouponsUseCase {
+    pub fn new(repository: Arc<RwLock<dyn CouponRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        template: CouponTemplate,
+        count: u32,
+        prefix: String,
+        code_length: usize,
+    ) -> Result<Vec<Coupon>, String> {
+        log::info!("Start request");
+
+        let prefix = prefix.to_uppercase();
+        validate_batch(&template, count, &prefix, code_length)?;
+
+        // A clash with an existing code rolls the whole batch back; a fresh
+        // draw is almost certain to succeed given the keyspace check above.
+        for attempt in 1..=MAX_ATTEMPTS {
+            let mut codes = HashSet::with_capacity(count as usize);
+            while codes.len() < count as usize {
+                codes.insert(generate_coupon_code(&prefix, code_length));
+            }
+
+            let coupons = codes
+                .into_iter()
+                .map(|code| template.with_code(code))
+                .collect();
+
+            let repository = self.repository.write().await;
+            match repository.create_many(coupons).await {
+                Ok(created) => {
+                    log::info!("End request");
+                    return Ok(created);
+                }
+                Err(e) if e == "Coupon code already exists" && attempt < MAX_ATTEMPTS => {
+                    log::warn!("Generated code collided, retrying batch ({})", attempt);
+                }
+                Err(e) => {
+                    log::error!("Error: {}", e);
+                    return Err(e);
+                }
+            }
+        }
+
+        Err("Coupon code already exists".to_string())
+    }
+}
+
+fn validate_batch(
+    template: &CouponTemplate,
+    count: u32,
+    prefix: &str,
+    code_length: usize,
+) -> Result<(), String> {
+    if count == 0 || count > MAX_BATCH_SIZE {
+        return Err(format!(
+            "Invalid batch: count must be between 1 and {}",
+            MAX_BATCH_SIZE
+        ));
+    }
+    if !prefix.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err("Invalid batch: prefix must be alphanumeric".to_string());
+    }
+    let total_length = prefix.len() + code_length;
+    if code_length == 0 || !(MIN_CODE_LENGTH..=MAX_CODE_LENGTH).contains(&total_length) {
+        return Err(format!(
+            "Invalid batch: prefix plus code_length must be between {} and {} characters",
+            MIN_CODE_LENGTH, MAX_CODE_LENGTH
+        ));
+    }
+    // Keep the keyspace at least ten times larger than the batch so random
+    // draws stay cheap and collisions with earlier batches stay unlikely.
+    let keyspace = (UNAMBIGUOUS_ALPHABET.len() as u128).pow(code_length as u32);
+    if keyspace < count as u128 * 10 {
+        return Err("Invalid batch: code_length is too short for this count".to_string());
+    }
+
+    template
+        .coupon_type
+        .validate_params(template.value, template.params.as_ref())
+        .map_err(|e| format!("Invalid batch: {}", e))?;
+    let max_until = template.valid_from.checked_add_months(Months::new(60));
+    if template.valid_until <= template.valid_from
+        || max_until.is_none_or(|max| template.valid_until > max)
+    {
+        return Err("Invalid batch: invalid validity period".to_string());
+    }
+    if template.one_shot && template.max_uses.is_some() {
+        return Err("Invalid batch: one_shot coupons cannot set max_uses".to_string());
+    }
+    if template.max_uses == Some(0) || template.max_uses_per_customer == Some(0) {
+        return Err("Invalid batch: usage limits must be positive".to_string());
+    }
+    if template.max_discount_amount == Some(0) {
+        return Err("Invalid batch: max_discount_amount must be positive".to_string());
+    }
+
+    Ok(())
+}
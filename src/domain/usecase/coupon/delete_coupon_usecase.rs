@@ -12,9 +12,13 @@ impl DeleteCouponUseCase {
         Self { repository }
     }
 
-    pub async fn execute(&self, code: String) -> Result<(), String> {
+    pub async fn execute(
+        &self,
+        code: String,
+        expected_versions: Option<Vec<u32>>,
+    ) -> Result<(), String> {
         let repository = self.repository.write().await;
-        let response = repository.delete(code).await;
+        let response = repository.delete(code, expected_versions).await;
         match response {
             Ok(_) => {
                 log::info!("End request");
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::{coupon_entity::CouponRedemption, discount_entity::PaginatedResponse},
+    repository::coupon_repository::CouponRepository,
+};
+
+pub struct GetCouponRedemptionsUseCase {
+    pub repository: Arc<RwLock<dyn CouponRepository + Send + Sync>>,
+}
+
+impl GetCouponRedemptionsUseCase {
+    pub fn new(repository: Arc<RwLock<dyn CouponRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        code: String,
+        page: u32,
+        limit: u32,
+    ) -> Result<PaginatedResponse<CouponRedemption>, String> {
+        log::info!("Start request");
+        let repository = self.repository.read().await;
+        let redemptions = repository
+            .find_redemptions(&code, Some(page), Some(limit))
+            .await
+            .map_err(|e| {
+                log::error!("Error: {}", e);
+                e
+            })?;
+        log::info!("End request");
+        Ok(redemptions)
+    }
+}
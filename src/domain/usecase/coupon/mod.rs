@@ -1,5 +1,10 @@
+pub mod batch_generate_coupons_usecase;
 pub mod create_coupon_usecase;
 pub mod delete_coupon_usecase;
+pub mod get_coupon_applications_usecase;
+pub mod get_coupon_redemptions_usecase;
 pub mod get_coupon_usecase;
 pub mod get_coupons_usecase;
+pub mod reconcile_coupon_uses_usecase;
 pub mod update_coupon_usecase;
+pub mod validate_coupon_usecase;
@@ -0,0 +1,19 @@
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::repository::coupon_repository::CouponRepository;
+
+pub struct ReconcileCouponUsesUseCase {
+    pub repository: Arc<RwLock<dyn CouponRepository + Send + Sync>>,
+}
+
+impl ReconcileCouponUsesUseCase {
+    pub fn new(repository: Arc<RwLock<dyn CouponRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self) -> Result<u64, String> {
+        let repository = self.repository.read().await;
+        repository.reconcile_uses_count().await
+    }
+}
@@ -3,10 +3,9 @@ use crate::{
     domain::{
         entity::coupon_entity::{CouponType, UpdateCoupon},
         repository::coupon_repository::CouponRepository,
+        utils::validation::validation_error,
     },
 };
-use chrono::{DateTime, Utc};
-use std::str::FromStr;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -22,47 +21,15 @@ impl UpdateCouponUseCase {
     pub async fn execute(
         &self,
         code: String,
-        coupon_type: Option<String>,
-        value: Option<u64>,
-        one_shot: Option<bool>,
-        valid_from: Option<String>,
-        valid_until: Option<String>,
-        max_uses: Option<u32>,
+        update_data: UpdateCoupon,
+        expected_versions: Option<Vec<u32>>,
     ) -> Result<Vec<PatchOperation>, String> {
         log::info!("Start request: UpdateCouponUseCase");
 
-        let coupon_type = match coupon_type {
-            Some(ct) => match CouponType::from_str(&ct) {
-                Ok(val) => Some(val),
-                Err(_) => return Err("Tipo de cupom inválido".to_string()),
-            },
-            None => None,
-        };
-
-        let valid_from = match valid_from {
-            Some(vf) => match vf.parse::<DateTime<Utc>>() {
-                Ok(dt) => Some(dt),
-                Err(e) => return Err(format!("valid_from inválida: {}", e)),
-            },
-            None => None,
-        };
-
-        let valid_until = match valid_until {
-            Some(vu) => match vu.parse::<DateTime<Utc>>() {
-                Ok(dt) => Some(dt),
-                Err(e) => return Err(format!("valid_until inválida: {}", e)),
-            },
-            None => None,
-        };
-
-        let update_data = UpdateCoupon {
-            coupon_type,
-            value,
-            one_shot,
-            valid_from,
-            valid_until,
-            max_uses,
-        };
+        let errors = update_data.field_errors();
+        if !errors.is_empty() {
+            return Err(validation_error(&errors));
+        }
 
         let mut patches = Vec::new();
 
@@ -78,6 +45,21 @@ impl UpdateCouponUseCase {
                 update_data.value.as_ref().unwrap(),
             ));
         }
+        // Percent and fixed coupons take no params, so switching to them
+        // clears any left from a bogo or tiered type.
+        let params = match update_data.coupon_type {
+            Some(CouponType::Percent | CouponType::Fixed) => Some(&None),
+            _ => update_data.params.as_ref(),
+        };
+        if let Some(params) = params {
+            patches.push(PatchOperation::replace("/params", params));
+        }
+        if let Some(max_discount_amount) = &update_data.max_discount_amount {
+            patches.push(PatchOperation::replace(
+                "/max_discount_amount",
+                max_discount_amount,
+            ));
+        }
         if update_data.one_shot.is_some() {
             patches.push(PatchOperation::replace(
                 "/one_shot",
@@ -102,10 +84,51 @@ impl UpdateCouponUseCase {
                 update_data.max_uses.as_ref().unwrap(),
             ));
         }
+        if let Some(max_uses_per_customer) = &update_data.max_uses_per_customer {
+            patches.push(PatchOperation::replace(
+                "/max_uses_per_customer",
+                max_uses_per_customer,
+            ));
+        }
+        if let Some(allowed_product_ids) = &update_data.rules.allowed_product_ids {
+            patches.push(PatchOperation::replace(
+                "/allowed_product_ids",
+                allowed_product_ids,
+            ));
+        }
+        if let Some(denied_product_ids) = &update_data.rules.denied_product_ids {
+            patches.push(PatchOperation::replace(
+                "/denied_product_ids",
+                denied_product_ids,
+            ));
+        }
+        if let Some(min_product_price) = &update_data.rules.min_product_price {
+            patches.push(PatchOperation::replace(
+                "/min_product_price",
+                min_product_price,
+            ));
+        }
+        if let Some(exclude_discounted) = &update_data.rules.exclude_discounted {
+            patches.push(PatchOperation::replace(
+                "/exclude_discounted",
+                exclude_discounted,
+            ));
+        }
+        if let Some(campaign_id) = &update_data.campaign_id {
+            patches.push(PatchOperation::replace("/campaign_id", campaign_id));
+        }
+        if let Some(stackable) = &update_data.stacking.stackable {
+            patches.push(PatchOperation::replace("/stackable", stackable));
+        }
+        if let Some(priority) = &update_data.stacking.priority {
+            patches.push(PatchOperation::replace("/priority", priority));
+        }
 
         let write_repository = self.repository.write().await;
 
-        let update_result = write_repository.update(code, update_data).await;
+        let update_result = write_repository
+            .update(code, update_data, expected_versions)
+            .await;
 
         log::info!("End request: UpdateCouponUseCase");
         match update_result {
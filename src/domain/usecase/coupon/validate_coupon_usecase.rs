@@ -0,0 +1,158 @@
+use chrono::Utc;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::domain::{
+    entity::{
+        coupon_entity::{CouponValidation, IneligibilityReason},
+        discount_entity::{DiscountSource, ProductDiscountInfo},
+    },
+    pricing::pricing_engine::PricingEngine,
+    repository::{
+        campaign_repository::CampaignRepository, coupon_repository::CouponRepository,
+        discount_repository::DiscountRepository, product_repository::ProductRepository,
+        sale_event_repository::SaleEventRepository, tax_class_repository::TaxClassRepository,
+    },
+    usecase::product::get_product_usecase::GetProductUseCase,
+};
+
+pub struct ValidateCouponUseCase {
+    pub coupon_repo: Arc<RwLock<dyn CouponRepository + Send + Sync>>,
+    pub product_repo: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+    pub discount_repo: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+    pub campaign_repo: Arc<RwLock<dyn CampaignRepository + Send + Sync>>,
+    pub sale_event_repo: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+    pub tax_class_repo: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+    pub pricing: PricingEngine,
+}
+
+impl ValidateCouponUseCase {
+    pub fn new(
+        coupon_repo: Arc<RwLock<dyn CouponRepository + Send + Sync>>,
+        product_repo: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+        discount_repo: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+        campaign_repo: Arc<RwLock<dyn CampaignRepository + Send + Sync>>,
+        sale_event_repo: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+        tax_class_repo: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+        pricing: PricingEngine,
+    ) -> Self {
+        Self {
+            coupon_repo,
+            product_repo,
+            discount_repo,
+            campaign_repo,
+            sale_event_repo,
+            tax_class_repo,
+            pricing,
+        }
+    }
+
+    /// Runs every check `apply_coupon` makes, read-only, and reports all the
+    /// failures instead of stopping at the first one.
+    pub async fn execute(
+        &self,
+        code: String,
+        product_id: Uuid,
+        customer_ref: Option<String>,
+    ) -> Result<CouponValidation, String> {
+        log::info!("Start request");
+        let now = Utc::now();
+
+        let coupon = self
+            .coupon_repo
+            .read()
+            .await
+            .find_with_deleted(&code)
+            .await?;
+
+        let product = GetProductUseCase::new(
+            self.product_repo.clone(),
+            self.discount_repo.clone(),
+            self.sale_event_repo.clone(),
+            self.tax_class_repo.clone(),
+            self.pricing,
+        )
+        .execute(product_id.to_string())
+        .await?;
+
+        let mut reasons = coupon.lifecycle_reasons(now);
+
+        match (coupon.max_uses_per_customer, customer_ref.as_deref()) {
+            (Some(per_customer), Some(customer_ref)) => {
+                let uses = self
+                    .coupon_repo
+                    .read()
+                    .await
+                    .count_customer_redemptions(coupon.id, customer_ref)
+                    .await?;
+                if uses >= per_customer as u64 {
+                    reasons.push(IneligibilityReason::CustomerLimitReached);
+                }
+            }
+            (Some(_), None) => reasons.push(IneligibilityReason::CustomerRequired),
+            (None, _) => {}
+        }
+
+        reasons.extend(coupon.rules.ineligibility_reasons(
+            product.id,
+            product.price,
+            product.has_coupon_applied,
+        ));
+        // Coupons only share a product when all of them are stackable.
+        let stacks = coupon.stacking.stackable && product.discounts.iter().all(|d| d.stackable);
+        if product.has_coupon_applied
+            && (!stacks
+                || product
+                    .discounts
+                    .iter()
+                    .any(|d| d.source == DiscountSource::Coupon && d.code == coupon.code))
+            && !reasons.contains(&IneligibilityReason::AlreadyDiscounted)
+        {
+            reasons.push(IneligibilityReason::AlreadyDiscounted);
+        }
+
+        let mut stack = product.discounts.clone();
+        stack.push(ProductDiscountInfo::new(&coupon, now));
+        let breakdown = self
+            .pricing
+            .price_with_tax(product.price, product.tax.rate, stack);
+        let (discount_amount, capped) = breakdown
+            .discounts
+            .iter()
+            .find(|d| d.code == coupon.code && d.applied_at == now)
+            .map_or((0, false), |d| (d.amount, d.capped));
+
+        if let Some(campaign_id) = coupon.campaign_id {
+            let campaign = self
+                .campaign_repo
+                .read()
+                .await
+                .find(&campaign_id.to_string())
+                .await?;
+            if !campaign.is_open(now) {
+                reasons.push(IneligibilityReason::CampaignInactive);
+            } else if discount_amount > campaign.remaining_budget() {
+                reasons.push(IneligibilityReason::CampaignBudgetExhausted);
+            }
+        }
+
+        let applicable = reasons.is_empty();
+        let (discount_amount, capped, final_price) = match applicable {
+            true => (discount_amount, capped, breakdown.final_price),
+            false => (0, false, product.final_price),
+        };
+
+        log::info!("End request");
+        Ok(CouponValidation {
+            code: coupon.code,
+            product_id: product.id,
+            applicable,
+            reasons,
+            price: product.price,
+            discount_amount,
+            capped,
+            final_price,
+        })
+    }
+}
@@ -1,20 +1,31 @@
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
-use crate::domain::repository::discount_repository::DiscountRepository;
+use crate::domain::{
+    pricing::pricing_engine::PricingEngine, repository::discount_repository::DiscountRepository,
+};
 
 pub struct ApplyPercentDiscountUseCase {
     pub repository: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+    pub pricing: PricingEngine,
 }
 
 impl ApplyPercentDiscountUseCase {
-    pub fn new(repository: Arc<RwLock<dyn DiscountRepository + Send + Sync>>) -> Self {
-        Self { repository }
+    pub fn new(
+        repository: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+        pricing: PricingEngine,
+    ) -> Self {
+        Self {
+            repository,
+            pricing,
+        }
     }
 
     pub async fn execute(&self, product_id: String, coupon_code: String) -> Result<(), String> {
         let repository = self.repository.write().await;
-        let response = repository.apply_coupon(product_id, coupon_code).await;
+        let response = repository
+            .apply_coupon(product_id, coupon_code, None, self.pricing)
+            .await;
         match response {
             Ok(_) => Ok(()),
             Err(e) => Err(e),
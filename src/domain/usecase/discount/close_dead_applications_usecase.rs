@@ -0,0 +1,22 @@
+use chrono::Utc;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::discount_entity::ProductDiscount, repository::discount_repository::DiscountRepository,
+};
+
+pub struct CloseDeadApplicationsUseCase {
+    pub repository: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+}
+
+impl CloseDeadApplicationsUseCase {
+    pub fn new(repository: Arc<RwLock<dyn DiscountRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<ProductDiscount>, String> {
+        let repository = self.repository.read().await;
+        repository.close_dead_applications(Utc::now()).await
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::discount_entity::{DiscountApplication, DiscountPeriod, PaginatedResponse},
+    repository::discount_repository::DiscountRepository,
+};
+
+pub struct GetProductDiscountsUseCase {
+    pub repository: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+}
+
+impl GetProductDiscountsUseCase {
+    pub fn new(repository: Arc<RwLock<dyn DiscountRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        product_id: String,
+        period: DiscountPeriod,
+        page: u32,
+        limit: u32,
+    ) -> Result<PaginatedResponse<DiscountApplication>, String> {
+        log::info!("Start request");
+        if period
+            .from
+            .zip(period.to)
+            .is_some_and(|(from, to)| from > to)
+        {
+            return Err("Invalid period: 'from' is after 'to'".to_string());
+        }
+
+        let repository = self.repository.read().await;
+        let history = repository
+            .find_product_history(product_id, period, Some(page), Some(limit))
+            .await
+            .map_err(|e| {
+                log::error!("Error: {}", e);
+                e
+            })?;
+        log::info!("End request");
+        Ok(history)
+    }
+}
@@ -1,3 +1,5 @@
 pub mod apply_coupon_discount_usecase;
 pub mod apply_percent_discount_usecase;
+pub mod close_dead_applications_usecase;
+pub mod get_product_discounts_usecase;
 pub mod remove_discount_usecase;
@@ -0,0 +1,44 @@
+use chrono::{Duration, Utc};
+use serde_json::Value;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::idempotency_entity::IdempotencyLookup,
+    repository::idempotency_repository::IdempotencyRepository,
+};
+
+/// A lock older than this is assumed to belong to a request that died
+/// before storing its response.
+const LOCK_TIMEOUT_SECS: i64 = 60;
+
+pub struct AcquireIdempotencyKeyUseCase {
+    pub repository: Arc<RwLock<dyn IdempotencyRepository + Send + Sync>>,
+}
+
+impl AcquireIdempotencyKeyUseCase {
+    pub fn new(repository: Arc<RwLock<dyn IdempotencyRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        scope: String,
+        key: String,
+        request: Value,
+        ttl_secs: i64,
+    ) -> Result<IdempotencyLookup, String> {
+        if key.trim().is_empty() || key.len() > 255 {
+            return Err("Idempotency-Key must have between 1 and 255 characters".to_string());
+        }
+
+        let now = Utc::now();
+        let expires_at = now + Duration::seconds(ttl_secs);
+        let stale_before = now - Duration::seconds(LOCK_TIMEOUT_SECS);
+
+        let repository = self.repository.read().await;
+        repository
+            .acquire(scope, key, request, expires_at, stale_before)
+            .await
+    }
+}
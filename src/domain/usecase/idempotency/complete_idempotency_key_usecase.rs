@@ -0,0 +1,31 @@
+use serde_json::Value;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::repository::idempotency_repository::IdempotencyRepository;
+
+pub struct CompleteIdempotencyKeyUseCase {
+    pub repository: Arc<RwLock<dyn IdempotencyRepository + Send + Sync>>,
+}
+
+impl CompleteIdempotencyKeyUseCase {
+    pub fn new(repository: Arc<RwLock<dyn IdempotencyRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    /// Stores the response so retries replay it. Server errors release the
+    /// key instead, letting the client retry the request for real.
+    pub async fn execute(
+        &self,
+        scope: String,
+        key: String,
+        status: u16,
+        body: Value,
+    ) -> Result<(), String> {
+        let repository = self.repository.read().await;
+        match status >= 500 {
+            true => repository.release(scope, key).await,
+            false => repository.complete(scope, key, status, body).await,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod acquire_idempotency_key_usecase;
+pub mod complete_idempotency_key_usecase;
+pub mod purge_expired_idempotency_keys_usecase;
@@ -0,0 +1,20 @@
+use chrono::Utc;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::repository::idempotency_repository::IdempotencyRepository;
+
+pub struct PurgeExpiredIdempotencyKeysUseCase {
+    pub repository: Arc<RwLock<dyn IdempotencyRepository + Send + Sync>>,
+}
+
+impl PurgeExpiredIdempotencyKeysUseCase {
+    pub fn new(repository: Arc<RwLock<dyn IdempotencyRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self) -> Result<u64, String> {
+        let repository = self.repository.read().await;
+        repository.purge_expired(Utc::now()).await
+    }
+}
@@ -1,3 +1,10 @@
+pub mod campaign;
 pub mod coupon;
 pub mod discount;
+pub mod idempotency;
+pub mod order;
 pub mod product;
+pub mod quote;
+pub mod rate_limit;
+pub mod sale_event;
+pub mod tax;
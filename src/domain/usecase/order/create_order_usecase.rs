@@ -0,0 +1,75 @@
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::{
+        order_entity::{CreateOrder, Order},
+        quote_entity::{CreateQuote, QuoteItem},
+    },
+    repository::order_repository::OrderRepository,
+    usecase::quote::create_quote_usecase::CreateQuoteUseCase,
+};
+
+pub struct CreateOrderUseCase {
+    pub repository: Arc<RwLock<dyn OrderRepository + Send + Sync>>,
+    pub quote: CreateQuoteUseCase,
+}
+
+impl CreateOrderUseCase {
+    pub fn new(
+        repository: Arc<RwLock<dyn OrderRepository + Send + Sync>>,
+        quote: CreateQuoteUseCase,
+    ) -> Self {
+        Self { repository, quote }
+    }
+
+    /// Prices the order as a quote, so anything the quote would only warn
+    /// about fails it before any lock is taken, then places it. The
+    /// repository prices the lines again under lock, and those are the
+    /// amounts the order keeps.
+    pub async fn execute(
+        &self,
+        order: CreateOrder,
+        customer_ref: Option<String>,
+    ) -> Result<Order, String> {
+        log::info!("Start request");
+        let coupon_code = order.coupon_code.clone();
+        let quote = self
+            .quote
+            .execute(
+                CreateQuote {
+                    items: order.items,
+                    coupon_codes: coupon_code.iter().cloned().collect(),
+                    customer_id: None,
+                },
+                customer_ref.clone(),
+            )
+            .await?;
+
+        if let Some(warning) = quote.warnings.first() {
+            return Err(warning.order_error());
+        }
+        if let Some(error) = quote.coupon_errors.first() {
+            return Err(error.order_error());
+        }
+
+        let items = quote
+            .lines
+            .iter()
+            .map(|line| QuoteItem {
+                product_id: line.product_id,
+                quantity: line.quantity,
+            })
+            .collect();
+        let repository = self.repository.write().await;
+        let order = repository
+            .create(items, coupon_code, customer_ref, self.quote.pricing)
+            .await
+            .map_err(|e| {
+                log::error!("Error: {}", e);
+                e
+            })?;
+        log::info!("End request");
+        Ok(order)
+    }
+}
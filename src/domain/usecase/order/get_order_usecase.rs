@@ -0,0 +1,25 @@
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{entity::order_entity::Order, repository::order_repository::OrderRepository};
+
+pub struct GetOrderUseCase {
+    pub repository: Arc<RwLock<dyn OrderRepository + Send + Sync>>,
+}
+
+impl GetOrderUseCase {
+    pub fn new(repository: Arc<RwLock<dyn OrderRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, id: String) -> Result<Order, String> {
+        log::info!("Start request");
+        let repository = self.repository.read().await;
+        let order = repository.find(&id).await.map_err(|e| {
+            log::error!("Error: {}", e);
+            e
+        })?;
+        log::info!("End request");
+        Ok(order)
+    }
+}
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::{discount_entity::PaginatedResponse, order_entity::Order},
+    repository::order_repository::OrderRepository,
+};
+
+pub struct GetOrdersUseCase {
+    pub repository: Arc<RwLock<dyn OrderRepository + Send + Sync>>,
+}
+
+impl GetOrdersUseCase {
+    pub fn new(repository: Arc<RwLock<dyn OrderRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        page: u32,
+        limit: u32,
+        customer_ref: Option<String>,
+    ) -> Result<PaginatedResponse<Order>, String> {
+        log::info!("Start request");
+        let repository = self.repository.read().await;
+        let orders = repository
+            .find_all(Some(page), Some(limit), customer_ref)
+            .await
+            .map_err(|e| {
+                log::error!("Error: {}", e);
+                e
+            })?;
+        log::info!("End request");
+        Ok(orders)
+    }
+}
@@ -0,0 +1,4 @@
+pub mod create_order_usecase;
+pub mod get_order_usecase;
+pub mod get_orders_usecase;
+pub mod transition_order_usecase;
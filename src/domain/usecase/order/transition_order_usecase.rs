@@ -0,0 +1,36 @@
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::order_entity::{Order, OrderAction, TransitionOrder},
+    repository::order_repository::OrderRepository,
+};
+
+pub struct TransitionOrderUseCase {
+    pub repository: Arc<RwLock<dyn OrderRepository + Send + Sync>>,
+}
+
+impl TransitionOrderUseCase {
+    pub fn new(repository: Arc<RwLock<dyn OrderRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        id: String,
+        action: OrderAction,
+        transition: TransitionOrder,
+    ) -> Result<Order, String> {
+        log::info!("Start request");
+        let repository = self.repository.write().await;
+        let order = repository
+            .transition(&id, action, transition)
+            .await
+            .map_err(|e| {
+                log::error!("Error: {}", e);
+                e
+            })?;
+        log::info!("End request");
+        Ok(order)
+    }
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::product_entity::{AssignTaxClass, Product},
+    repository::{product_repository::ProductRepository, tax_class_repository::TaxClassRepository},
+};
+
+pub struct AssignTaxClassUseCase {
+    pub product_repo: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+    pub tax_class_repo: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+}
+
+impl AssignTaxClassUseCase {
+    pub fn new(
+        product_repo: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+        tax_class_repo: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+    ) -> Self {
+        Self {
+            product_repo,
+            tax_class_repo,
+        }
+    }
+
+    pub async fn execute(&self, id: String, assign: AssignTaxClass) -> Result<Product, String> {
+        log::info!("Start request");
+
+        if let Some(tax_class_id) = assign.tax_class_id {
+            self.tax_class_repo
+                .read()
+                .await
+                .find(&tax_class_id.to_string())
+                .await?;
+        }
+
+        let repository = self.product_repo.write().await;
+        let product = repository
+            .set_tax_class(id, assign.tax_class_id)
+            .await
+            .map_err(|e| {
+                log::error!("Failed to assign tax class: {}", e);
+                e
+            })?;
+
+        log::info!("End request");
+        Ok(product)
+    }
+}
@@ -1,9 +1,11 @@
 use crate::domain::{
     entity::product_entity::{CreateProduct, Product},
     repository::product_repository::ProductRepository,
+    utils::validation::{field_errors, validation_error},
 };
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use validator::Validate;
 
 pub struct CreateProductUseCase {
     pub repository: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
@@ -23,8 +25,15 @@ impl CreateProductUseCase {
     ) -> Result<Product, std::io::Error> {
         log::info!("Start request");
 
-        let write_repository = self.repository.write().await;
         let product = CreateProduct::new(name, description, stock, price);
+        if let Err(e) = product.validate() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                validation_error(&field_errors(&e)),
+            ));
+        }
+
+        let write_repository = self.repository.write().await;
 
         let response = write_repository
             .create(product.clone())
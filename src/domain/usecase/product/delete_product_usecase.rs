@@ -12,9 +12,13 @@ impl DeleteProductUseCase {
         Self { repository }
     }
 
-    pub async fn execute(&self, id: String) -> Result<(), String> {
+    pub async fn execute(
+        &self,
+        id: String,
+        expected_versions: Option<Vec<u32>>,
+    ) -> Result<(), String> {
         let repository = self.repository.write().await;
-        let response = repository.delete(id).await;
+        let response = repository.delete(id, expected_versions).await;
         match response {
             Ok(_) => {
                 log::info!("End request");
@@ -2,17 +2,38 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use crate::domain::{
-    entity::{discount_entity::PaginatedResponse, product_entity::Product},
-    repository::product_repository::ProductRepository,
+    entity::discount_entity::{PaginatedResponse, ProductResponse},
+    pricing::pricing_engine::PricingEngine,
+    repository::{
+        discount_repository::DiscountRepository, product_repository::ProductRepository,
+        sale_event_repository::SaleEventRepository, tax_class_repository::TaxClassRepository,
+    },
+    usecase::product::price_products_usecase::PriceProductsUseCase,
 };
 
 pub struct GetAllProductsUseCase {
     pub repository: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+    pub discount_repo: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+    pub sale_event_repo: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+    pub tax_class_repo: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+    pub pricing: PricingEngine,
 }
 
 impl GetAllProductsUseCase {
-    pub fn new(repository: Arc<RwLock<dyn ProductRepository + Send + Sync>>) -> Self {
-        Self { repository }
+    pub fn new(
+        repository: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+        discount_repo: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+        sale_event_repo: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+        tax_class_repo: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+        pricing: PricingEngine,
+    ) -> Self {
+        Self {
+            repository,
+            discount_repo,
+            sale_event_repo,
+            tax_class_repo,
+            pricing,
+        }
     }
 
     pub async fn execute(
@@ -23,7 +44,7 @@ impl GetAllProductsUseCase {
         min_price: u32,
         max_price: u32,
         has_discount: bool,
-    ) -> Result<PaginatedResponse<Product>, String> {
+    ) -> Result<PaginatedResponse<ProductResponse>, String> {
         log::info!("Start request");
         let repository = self.repository.read().await;
         let works = repository
@@ -40,7 +61,21 @@ impl GetAllProductsUseCase {
                 log::error!("Error: {}", e);
                 e.to_string()
             })?;
+
+        // One lookup for the whole page instead of one per product.
+        let data = PriceProductsUseCase::new(
+            self.discount_repo.clone(),
+            self.sale_event_repo.clone(),
+            self.tax_class_repo.clone(),
+            self.pricing,
+        )
+        .execute(works.data)
+        .await?;
+
         log::info!("End request");
-        Ok(works)
+        Ok(PaginatedResponse {
+            data,
+            meta: works.meta,
+        })
     }
 }
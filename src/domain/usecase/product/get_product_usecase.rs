@@ -1,24 +1,41 @@
+use chrono::Utc;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use crate::domain::{
-    entity::discount_entity::{ProductDiscountInfo, ProductResponse},
-    repository::{discount_repository::DiscountRepository, product_repository::ProductRepository},
+    entity::{
+        discount_entity::{ProductDiscountInfo, ProductResponse},
+        sale_event_entity::SaleEvent,
+    },
+    pricing::pricing_engine::PricingEngine,
+    repository::{
+        discount_repository::DiscountRepository, product_repository::ProductRepository,
+        sale_event_repository::SaleEventRepository, tax_class_repository::TaxClassRepository,
+    },
 };
 
 pub struct GetProductUseCase {
     pub product_repo: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
     pub discount_repo: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+    pub sale_event_repo: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+    pub tax_class_repo: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+    pub pricing: PricingEngine,
 }
 
 impl GetProductUseCase {
     pub fn new(
         product_repo: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
         discount_repo: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+        sale_event_repo: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+        tax_class_repo: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+        pricing: PricingEngine,
     ) -> Self {
         Self {
             product_repo,
             discount_repo,
+            sale_event_repo,
+            tax_class_repo,
+            pricing,
         }
     }
 
@@ -28,44 +45,38 @@ impl GetProductUseCase {
 
         let product = product_repo.find(id).await?;
 
-        let discount_info = discount_repo
-            .find_active_discount(product.id.to_string())
+        let mut discounts: Vec<ProductDiscountInfo> = discount_repo
+            .find_active_discounts(product.id.to_string())
             .await?
-            .map(|(discount, coupon)| ProductDiscountInfo {
-                discount_type: coupon.coupon_type.to_string(),
-                value: coupon.value,
-                applied_at: discount.applied_at,
-            });
-
-        log::warn!("{:?}", product);
-
-        let has_coupon_applied = discount_info.is_some();
-
-        let final_price = if let Some(d) = &discount_info {
-            match d.discount_type.as_str() {
-                "percent" => {
-                    let discount_amount = (product.price * d.value) / 100;
-                    product.price.saturating_sub(discount_amount).max(1)
-                }
-                "fixed" => product.price.saturating_sub(d.value).max(1),
-                _ => product.price,
+            .iter()
+            .map(|(discount, coupon)| ProductDiscountInfo::new(coupon, discount.applied_at))
+            .collect();
+        let events = self
+            .sale_event_repo
+            .read()
+            .await
+            .find_running(Utc::now())
+            .await?;
+        if let Some(event) = SaleEvent::best_for(&events, &product, &self.pricing) {
+            discounts.push(ProductDiscountInfo::from_sale_event(event));
+        }
+        let tax_rate = match product.tax_class_id {
+            Some(tax_class_id) => {
+                self.tax_class_repo
+                    .read()
+                    .await
+                    .find(&tax_class_id.to_string())
+                    .await?
+                    .rate
             }
-        } else {
-            product.price
+            None => 0,
         };
+        let breakdown = self
+            .pricing
+            .price_with_tax(product.price, tax_rate, discounts);
+
+        log::warn!("{:?}", product);
 
-        Ok(ProductResponse {
-            id: product.id,
-            name: product.name,
-            description: product.description,
-            stock: product.stock,
-            is_out_of_stock: product.stock == 0,
-            price: product.price,
-            final_price,
-            discount: discount_info,
-            has_coupon_applied,
-            created_at: product.created_at,
-            updated_at: product.updated_at,
-        })
+        Ok(ProductResponse::new(product, breakdown))
     }
 }
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::{discount_entity::PaginatedResponse, product_entity::Product},
+    repository::product_repository::ProductRepository,
+};
+
+pub struct GetTrashProductsUseCase {
+    pub repository: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+}
+
+impl GetTrashProductsUseCase {
+    pub fn new(repository: Arc<RwLock<dyn ProductRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        page: u32,
+        limit: u32,
+    ) -> Result<PaginatedResponse<Product>, String> {
+        log::info!("Start request");
+        let repository = self.repository.read().await;
+        let products = repository
+            .find_deleted(Some(page), Some(limit))
+            .await
+            .map_err(|e| {
+                log::error!("Error: {}", e);
+                e
+            })?;
+        log::info!("End request");
+        Ok(products)
+    }
+}
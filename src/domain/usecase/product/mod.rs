@@ -1,6 +1,11 @@
+pub mod assign_tax_class_usecase;
 pub mod create_product_usecase;
 pub mod delete_product_usecase;
 pub mod get_all_product_usecase;
 pub mod get_product_usecase;
+pub mod get_trash_products_usecase;
+pub mod price_products_usecase;
+pub mod purge_expired_products_usecase;
+pub mod purge_product_usecase;
 pub mod restore_product_usecase;
 pub mod update_product_usecase;
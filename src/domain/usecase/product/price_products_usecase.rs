@@ -0,0 +1,108 @@
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::domain::{
+    entity::{
+        coupon_entity::Coupon,
+        discount_entity::{ProductDiscount, ProductDiscountInfo, ProductResponse},
+        product_entity::Product,
+        sale_event_entity::SaleEvent,
+        tax_entity::TaxClass,
+    },
+    pricing::pricing_engine::PricingEngine,
+    repository::{
+        discount_repository::DiscountRepository, sale_event_repository::SaleEventRepository,
+        tax_class_repository::TaxClassRepository,
+    },
+};
+
+/// Prices several products with a fixed number of lookups, whatever their
+/// count.
+pub struct PriceProductsUseCase {
+    pub discount_repo: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+    pub sale_event_repo: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+    pub tax_class_repo: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+    pub pricing: PricingEngine,
+}
+
+impl PriceProductsUseCase {
+    pub fn new(
+        discount_repo: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+        sale_event_repo: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+        tax_class_repo: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+        pricing: PricingEngine,
+    ) -> Self {
+        Self {
+            discount_repo,
+            sale_event_repo,
+            tax_class_repo,
+            pricing,
+        }
+    }
+
+    /// `products` as served by the API, in the same order.
+    pub async fn execute(&self, products: Vec<Product>) -> Result<Vec<ProductResponse>, String> {
+        let product_ids: Vec<Uuid> = products.iter().map(|p| p.id).collect();
+        let discounts = self
+            .discount_repo
+            .read()
+            .await
+            .find_active_discounts_for(&product_ids)
+            .await?;
+        let events = self
+            .sale_event_repo
+            .read()
+            .await
+            .find_running(Utc::now())
+            .await?;
+        let tax_class_ids: Vec<Uuid> = products.iter().filter_map(|p| p.tax_class_id).collect();
+        let tax_classes = self
+            .tax_class_repo
+            .read()
+            .await
+            .find_by_ids(&tax_class_ids)
+            .await?;
+
+        Ok(Self::price(
+            &self.pricing,
+            products,
+            discounts,
+            &events,
+            &tax_classes,
+        ))
+    }
+
+    /// `products` priced with the coupon applications, sale events and tax
+    /// classes already read for them.
+    pub fn price(
+        pricing: &PricingEngine,
+        products: Vec<Product>,
+        mut discounts: HashMap<Uuid, Vec<(ProductDiscount, Coupon)>>,
+        events: &[SaleEvent],
+        tax_classes: &HashMap<Uuid, TaxClass>,
+    ) -> Vec<ProductResponse> {
+        products
+            .into_iter()
+            .map(|product| {
+                let mut applied: Vec<ProductDiscountInfo> = discounts
+                    .remove(&product.id)
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|(discount, coupon)| ProductDiscountInfo::new(coupon, discount.applied_at))
+                    .collect();
+                if let Some(event) = SaleEvent::best_for(events, &product, pricing) {
+                    applied.push(ProductDiscountInfo::from_sale_event(event));
+                }
+                let tax_rate = product
+                    .tax_class_id
+                    .and_then(|id| tax_classes.get(&id))
+                    .map_or(0, |t| t.rate);
+                let breakdown = pricing.price_with_tax(product.price, tax_rate, applied);
+                ProductResponse::new(product, breakdown)
+            })
+            .collect()
+    }
+}
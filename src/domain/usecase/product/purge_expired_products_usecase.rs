@@ -0,0 +1,21 @@
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::repository::product_repository::ProductRepository;
+
+pub struct PurgeExpiredProductsUseCase {
+    pub repository: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+}
+
+impl PurgeExpiredProductsUseCase {
+    pub fn new(repository: Arc<RwLock<dyn ProductRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, retention_days: i64) -> Result<u64, String> {
+        let cutoff = Utc::now() - Duration::days(retention_days);
+        let repository = self.repository.write().await;
+        repository.purge_deleted_before(cutoff).await
+    }
+}
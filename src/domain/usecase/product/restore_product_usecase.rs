@@ -1,9 +1,8 @@
-use chrono::Utc;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use crate::domain::{
-    entity::product_entity::UpdateProduct, repository::product_repository::ProductRepository,
+    entity::product_entity::Product, repository::product_repository::ProductRepository,
 };
 
 pub struct RestoreProductUseCase {
@@ -15,11 +14,19 @@ impl RestoreProductUseCase {
         Self { repository }
     }
 
-    pub async fn execute(&self, id: String) -> Result<(), String> {
+    pub async fn execute(&self, id: String) -> Result<Product, String> {
+        log::info!("Start request");
         let repository = self.repository.write().await;
-        let product = UpdateProduct::new(None, None, None, None, Some(Utc::now()));
-
-        let _ = repository.update(id, product).await;
-        Ok(())
+        let response = repository.restore(id).await;
+        match response {
+            Ok(product) => {
+                log::info!("End request");
+                Ok(product)
+            }
+            Err(e) => {
+                log::error!("Failed to restore product: {}", e);
+                Err(e)
+            }
+        }
     }
 }
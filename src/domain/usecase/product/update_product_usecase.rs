@@ -1,11 +1,14 @@
 use crate::{
     application::usecase::patch_operation::PatchOperation,
     domain::{
-        entity::product_entity::UpdateProduct, repository::product_repository::ProductRepository,
+        entity::product_entity::UpdateProduct,
+        repository::product_repository::ProductRepository,
+        utils::validation::{field_errors, validation_error},
     },
 };
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use validator::Validate;
 
 pub struct UpdateProductUseCase {
     pub repository: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
@@ -23,16 +26,20 @@ impl UpdateProductUseCase {
         description: Option<String>,
         stock: Option<u32>,
         price: Option<u64>,
+        expected_versions: Option<Vec<u32>>,
     ) -> Result<Vec<PatchOperation>, String> {
         log::info!("Start request");
-        let write_repository = self.repository.write().await;
         let product = UpdateProduct::new(
             name.clone(),
             description.clone(),
             stock.clone(),
             price.clone(),
-            None,
         );
+        if let Err(e) = product.validate() {
+            return Err(validation_error(&field_errors(&e)));
+        }
+
+        let write_repository = self.repository.write().await;
 
         let mut patches = Vec::new();
 
@@ -49,7 +56,9 @@ impl UpdateProductUseCase {
             patches.push(PatchOperation::replace("/price", v));
         }
 
-        let update_result = write_repository.update(id, product.clone()).await;
+        let update_result = write_repository
+            .update(id, product.clone(), expected_versions)
+            .await;
 
         match update_result {
             Ok(_) => {
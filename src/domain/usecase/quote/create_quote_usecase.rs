@@ -0,0 +1,207 @@
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::domain::{
+    entity::{
+        coupon_entity::IneligibilityReason,
+        discount_entity::ProductResponse,
+        quote_entity::{
+            CreateQuote, LineDiscounts, Quote, QuoteCouponError, QuoteDraft, QuoteItem,
+            overflow_error,
+        },
+    },
+    pricing::pricing_engine::PricingEngine,
+    repository::{
+        campaign_repository::CampaignRepository, coupon_repository::CouponRepository,
+        discount_repository::DiscountRepository, product_repository::ProductRepository,
+        sale_event_repository::SaleEventRepository, tax_class_repository::TaxClassRepository,
+    },
+    usecase::product::price_products_usecase::PriceProductsUseCase,
+    utils::validation::{FieldError, validation_error},
+};
+
+const MAX_QUOTE_ITEMS: usize = 100;
+const MAX_QUOTE_COUPONS: usize = 5;
+
+pub struct CreateQuoteUseCase {
+    pub product_repo: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+    pub coupon_repo: Arc<RwLock<dyn CouponRepository + Send + Sync>>,
+    pub discount_repo: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+    pub campaign_repo: Arc<RwLock<dyn CampaignRepository + Send + Sync>>,
+    pub sale_event_repo: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+    pub tax_class_repo: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+    pub pricing: PricingEngine,
+}
+
+impl CreateQuoteUseCase {
+    pub fn new(
+        product_repo: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+        coupon_repo: Arc<RwLock<dyn CouponRepository + Send + Sync>>,
+        discount_repo: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+        campaign_repo: Arc<RwLock<dyn CampaignRepository + Send + Sync>>,
+        sale_event_repo: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+        tax_class_repo: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+        pricing: PricingEngine,
+    ) -> Self {
+        Self {
+            product_repo,
+            coupon_repo,
+            discount_repo,
+            campaign_repo,
+            sale_event_repo,
+            tax_class_repo,
+            pricing,
+        }
+    }
+
+    /// Prices the cart with the checks `apply_coupon` makes, read-only.
+    /// Problems with products or coupons are reported in the quote rather
+    /// than failing it.
+    pub async fn execute(
+        &self,
+        quote: CreateQuote,
+        customer_ref: Option<String>,
+    ) -> Result<Quote, String> {
+        log::info!("Start request");
+        let now = Utc::now();
+
+        let mut errors = Vec::new();
+        if quote.items.is_empty() || quote.items.len() > MAX_QUOTE_ITEMS {
+            errors.push(FieldError::length("items", 1, MAX_QUOTE_ITEMS as u64));
+        }
+        for (index, item) in quote.items.iter().enumerate() {
+            if item.quantity == 0 || item.quantity > 999_999 {
+                let field = format!("items[{}].quantity", index);
+                errors.push(FieldError::range(&field, 1, 999_999));
+            }
+        }
+        if quote.coupon_codes.len() > MAX_QUOTE_COUPONS {
+            errors.push(FieldError::length(
+                "coupon_codes",
+                0,
+                MAX_QUOTE_COUPONS as u64,
+            ));
+        }
+        if !errors.is_empty() {
+            return Err(validation_error(&errors));
+        }
+
+        // The same product twice is one line.
+        let mut items: Vec<QuoteItem> = Vec::new();
+        for item in quote.items {
+            match items.iter_mut().find(|i| i.product_id == item.product_id) {
+                Some(existing) => {
+                    existing.quantity = existing.quantity.saturating_add(item.quantity)
+                }
+                None => items.push(item),
+            }
+        }
+
+        let product_ids: Vec<Uuid> = items.iter().map(|i| i.product_id).collect();
+        let products = self
+            .product_repo
+            .read()
+            .await
+            .find_by_ids(&product_ids)
+            .await?;
+        let priced: HashMap<Uuid, ProductResponse> = PriceProductsUseCase::new(
+            self.discount_repo.clone(),
+            self.sale_event_repo.clone(),
+            self.tax_class_repo.clone(),
+            self.pricing,
+        )
+        .execute(products)
+        .await?
+        .into_iter()
+        .map(|p| (p.id, p))
+        .collect();
+
+        let mut draft = QuoteDraft::new(&items, priced)?;
+
+        let mut codes: Vec<String> = Vec::new();
+        for code in quote.coupon_codes {
+            if !codes.contains(&code) {
+                codes.push(code);
+            }
+        }
+        for code in codes {
+            match self
+                .coupon_discounts(&code, &draft, customer_ref.as_deref(), now)
+                .await?
+            {
+                Ok((stackable, discounts)) => draft.add_coupon(stackable, discounts),
+                Err(reasons) => draft.coupon_errors.push(QuoteCouponError { code, reasons }),
+            }
+        }
+
+        log::info!("End request");
+        draft.finish(self.pricing.tax)
+    }
+
+    /// What coupon `code` takes off each line of `draft` it is eligible
+    /// for, with its stackable flag, or why it discounts no line.
+    async fn coupon_discounts(
+        &self,
+        code: &str,
+        draft: &QuoteDraft,
+        customer_ref: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> Result<Result<(bool, LineDiscounts), Vec<IneligibilityReason>>, String> {
+        let coupon = match self.coupon_repo.read().await.find_with_deleted(code).await {
+            Ok(coupon) => coupon,
+            Err(e) if e == "Coupon not found" => {
+                return Ok(Err(vec![IneligibilityReason::NotFound]));
+            }
+            Err(e) => return Err(e),
+        };
+
+        let mut reasons = coupon.lifecycle_reasons(now);
+        match (coupon.max_uses_per_customer, customer_ref) {
+            (Some(per_customer), Some(customer_ref)) => {
+                let uses = self
+                    .coupon_repo
+                    .read()
+                    .await
+                    .count_customer_redemptions(coupon.id, customer_ref)
+                    .await?;
+                if uses >= per_customer as u64 {
+                    reasons.push(IneligibilityReason::CustomerLimitReached);
+                }
+            }
+            (Some(_), None) => reasons.push(IneligibilityReason::CustomerRequired),
+            (None, _) => {}
+        }
+        if !reasons.is_empty() {
+            return Ok(Err(reasons));
+        }
+
+        let discounts = match draft.coupon_discounts(&coupon, &self.pricing)? {
+            Ok(discounts) => discounts,
+            Err(reasons) => return Ok(Err(reasons)),
+        };
+
+        if let Some(campaign_id) = coupon.campaign_id {
+            let campaign = self
+                .campaign_repo
+                .read()
+                .await
+                .find(&campaign_id.to_string())
+                .await?;
+            let amount = discounts
+                .iter()
+                .try_fold(0u64, |sum, (_, d)| sum.checked_add(d.amount))
+                .ok_or_else(overflow_error)?;
+            if !campaign.is_open(now) {
+                return Ok(Err(vec![IneligibilityReason::CampaignInactive]));
+            }
+            if amount > campaign.remaining_budget() {
+                return Ok(Err(vec![IneligibilityReason::CampaignBudgetExhausted]));
+            }
+        }
+
+        Ok(Ok((coupon.stacking.stackable, discounts)))
+    }
+}
@@ -0,0 +1 @@
+pub mod take_rate_limit_token_usecase;
@@ -0,0 +1,27 @@
+use chrono::Utc;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::rate_limit_entity::{RateLimit, RateLimitDecision},
+    repository::rate_limit_repository::RateLimitRepository,
+};
+
+pub struct TakeRateLimitTokenUseCase {
+    pub repository: Arc<RwLock<dyn RateLimitRepository + Send + Sync>>,
+}
+
+impl TakeRateLimitTokenUseCase {
+    pub fn new(repository: Arc<RwLock<dyn RateLimitRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(
+        &self,
+        key: String,
+        limit: RateLimit,
+    ) -> Result<RateLimitDecision, String> {
+        let repository = self.repository.read().await;
+        repository.take(key, limit, Utc::now()).await
+    }
+}
@@ -0,0 +1,28 @@
+use chrono::Utc;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::sale_event_entity::SaleEvent, repository::sale_event_repository::SaleEventRepository,
+};
+
+pub struct CancelSaleEventUseCase {
+    pub repository: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+}
+
+impl CancelSaleEventUseCase {
+    pub fn new(repository: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, id: String) -> Result<SaleEvent, String> {
+        log::info!("Start request");
+        let repository = self.repository.write().await;
+        let event = repository.cancel(&id, Utc::now()).await.map_err(|e| {
+            log::error!("Error: {}", e);
+            e
+        })?;
+        log::info!("End request");
+        Ok(event)
+    }
+}
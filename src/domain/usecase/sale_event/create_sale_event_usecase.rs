@@ -0,0 +1,77 @@
+use chrono::Utc;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::{
+        coupon_entity::CouponType,
+        sale_event_entity::{CreateSaleEvent, SaleEvent, SaleTarget},
+    },
+    repository::sale_event_repository::SaleEventRepository,
+    utils::validation::{FieldError, validation_error},
+};
+
+pub struct CreateSaleEventUseCase {
+    pub repository: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+}
+
+impl CreateSaleEventUseCase {
+    pub fn new(repository: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, event: CreateSaleEvent) -> Result<SaleEvent, String> {
+        log::info!("Start request");
+
+        let name = event.name.trim();
+        let mut errors = Vec::new();
+        if name.is_empty() || name.chars().count() > 100 {
+            errors.push(FieldError::length("name", 1, 100));
+        }
+        if event.ends_at <= event.starts_at || event.ends_at <= Utc::now() {
+            errors.push(FieldError::new(
+                "ends_at",
+                "period",
+                "ends_at must be after starts_at and in the future",
+            ));
+        }
+        if !matches!(event.discount_type, CouponType::Percent | CouponType::Fixed) {
+            errors.push(FieldError::new(
+                "type",
+                "choice",
+                "Must be percent or fixed",
+            ));
+        } else if let Err(e) = event.discount_type.validate_params(event.value, None) {
+            errors.push(FieldError::new("value", "coupon_type", &e));
+        } else if event.value > i64::MAX as u64 {
+            errors.push(FieldError::range("value", 1, i64::MAX as u64));
+        }
+        if let Err(e) = event.target.validate() {
+            errors.push(FieldError::new("target", "target", &e));
+        }
+        if !errors.is_empty() {
+            return Err(validation_error(&errors));
+        }
+
+        let target = match event.target {
+            SaleTarget::Search { term } => SaleTarget::Search {
+                term: term.trim().to_lowercase(),
+            },
+            target => target,
+        };
+        let event = CreateSaleEvent {
+            name: name.to_string(),
+            target,
+            ..event
+        };
+
+        let repository = self.repository.write().await;
+        let created = repository.create(event).await.map_err(|e| {
+            log::error!("Erro ao criar evento: {}", e);
+            e
+        })?;
+
+        log::info!("End request");
+        Ok(created)
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::sale_event_entity::SaleEvent, repository::sale_event_repository::SaleEventRepository,
+};
+
+pub struct GetSaleEventUseCase {
+    pub repository: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+}
+
+impl GetSaleEventUseCase {
+    pub fn new(repository: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, id: String) -> Result<SaleEvent, String> {
+        log::info!("Start request");
+        let repository = self.repository.read().await;
+        let event = repository.find(&id).await.map_err(|e| {
+            log::error!("Error: {}", e);
+            e
+        })?;
+        log::info!("End request");
+        Ok(event)
+    }
+}
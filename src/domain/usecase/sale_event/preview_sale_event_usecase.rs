@@ -0,0 +1,80 @@
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::{
+        discount_entity::{PaginatedResponse, ProductDiscountInfo},
+        sale_event_entity::SaleEventPreview,
+    },
+    pricing::pricing_engine::PricingEngine,
+    repository::{
+        product_repository::ProductRepository, sale_event_repository::SaleEventRepository,
+    },
+};
+
+pub struct PreviewSaleEventUseCase {
+    pub sale_event_repo: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+    pub product_repo: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+    pub pricing: PricingEngine,
+}
+
+impl PreviewSaleEventUseCase {
+    pub fn new(
+        sale_event_repo: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+        product_repo: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+        pricing: PricingEngine,
+    ) -> Self {
+        Self {
+            sale_event_repo,
+            product_repo,
+            pricing,
+        }
+    }
+
+    /// The products the event selects, each priced with the event alone,
+    /// whether or not the event is running.
+    pub async fn execute(
+        &self,
+        id: String,
+        page: u32,
+        limit: u32,
+    ) -> Result<PaginatedResponse<SaleEventPreview>, String> {
+        log::info!("Start request");
+        let event = self.sale_event_repo.read().await.find(&id).await?;
+
+        let products = self
+            .product_repo
+            .read()
+            .await
+            .find_by_sale_target(&event.target, Some(page), Some(limit))
+            .await
+            .map_err(|e| {
+                log::error!("Error: {}", e);
+                e
+            })?;
+
+        let data = products
+            .data
+            .into_iter()
+            .map(|product| {
+                let breakdown = self.pricing.price(
+                    product.price,
+                    vec![ProductDiscountInfo::from_sale_event(&event)],
+                );
+                SaleEventPreview {
+                    product_id: product.id,
+                    name: product.name,
+                    price: breakdown.base_price,
+                    discount_amount: breakdown.total_discount,
+                    sale_price: breakdown.final_price,
+                }
+            })
+            .collect();
+
+        log::info!("End request");
+        Ok(PaginatedResponse {
+            data,
+            meta: products.meta,
+        })
+    }
+}
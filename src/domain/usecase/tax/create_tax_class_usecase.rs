@@ -0,0 +1,53 @@
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::tax_entity::{CreateTaxClass, TaxClass},
+    repository::tax_class_repository::TaxClassRepository,
+    utils::validation::{FieldError, validation_error},
+};
+
+pub struct CreateTaxClassUseCase {
+    pub repository: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+}
+
+impl CreateTaxClassUseCase {
+    pub fn new(repository: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self, tax_class: CreateTaxClass) -> Result<TaxClass, String> {
+        log::info!("Start request");
+
+        let name = tax_class.name.trim();
+        let region = tax_class.region.trim();
+        let mut errors = Vec::new();
+        if name.is_empty() || name.chars().count() > 100 {
+            errors.push(FieldError::length("name", 1, 100));
+        }
+        if region.is_empty() || region.chars().count() > 50 {
+            errors.push(FieldError::length("region", 1, 50));
+        }
+        if tax_class.rate > 10_000 {
+            errors.push(FieldError::range("rate", 0, 10_000));
+        }
+        if !errors.is_empty() {
+            return Err(validation_error(&errors));
+        }
+
+        let tax_class = CreateTaxClass {
+            name: name.to_string(),
+            region: region.to_uppercase(),
+            ..tax_class
+        };
+
+        let repository = self.repository.write().await;
+        let created = repository.create(tax_class).await.map_err(|e| {
+            log::error!("Erro ao criar classe de imposto: {}", e);
+            e
+        })?;
+
+        log::info!("End request");
+        Ok(created)
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::domain::{
+    entity::tax_entity::TaxClass, repository::tax_class_repository::TaxClassRepository,
+};
+
+pub struct GetTaxClassesUseCase {
+    pub repository: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+}
+
+impl GetTaxClassesUseCase {
+    pub fn new(repository: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<TaxClass>, String> {
+        log::info!("Start request");
+        let repository = self.repository.read().await;
+        let tax_classes = repository.find_all().await.map_err(|e| {
+            log::error!("Error: {}", e);
+            e
+        })?;
+        log::info!("End request");
+        Ok(tax_classes)
+    }
+}
@@ -0,0 +1,2 @@
+pub mod create_tax_class_usecase;
+pub mod get_tax_classes_usecase;
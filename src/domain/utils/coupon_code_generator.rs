@@ -0,0 +1,12 @@
+use rand::Rng;
+
+/// Uppercase letters and digits without the look-alikes 0/O and 1/I.
+pub const UNAMBIGUOUS_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+pub fn generate_coupon_code(prefix: &str, length: usize) -> String {
+    let mut rng = rand::thread_rng();
+    let suffix: String = (0..length)
+        .map(|_| UNAMBIGUOUS_ALPHABET[rng.gen_range(0..UNAMBIGUOUS_ALPHABET.len())] as char)
+        .collect();
+    format!("{}{}", prefix, suffix)
+}
@@ -8,9 +8,12 @@ lazy_static! {
 }
 
 pub fn validate_coupon_value(value: u64) -> Result<(), ValidationError> {
-    match value <= 1 {
-        true => return Err(ValidationError::new("Value must be positive")),
-        false => (),
+    if value == 0 {
+        let mut error = ValidationError::new("range");
+        error.message = Some("Value must be positive".into());
+        error.add_param("min".into(), &1);
+        error.add_param("value".into(), &value);
+        return Err(error);
     }
     Ok(())
 }
@@ -1,4 +1,6 @@
+pub mod coupon_code_generator;
 pub mod coupon_value_validate;
 pub mod normalize_name;
 pub mod statics;
 
+pub mod validation;
@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use validator::{ValidationError, ValidationErrors};
+
+/// Start of the errors that carry field errors; a JSON list of them follows
+/// after `": "`.
+pub const VALIDATION_FAILED: &str = "Validation failed";
+
+/// One rule a request field breaks.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FieldError {
+    pub field: String,
+    pub code: String,
+    pub message: String,
+    #[serde(default)]
+    pub params: Map<String, Value>,
+}
+
+impl FieldError {
+    pub fn new(field: &str, code: &str, message: &str) -> Self {
+        Self {
+            field: field.to_string(),
+            code: code.to_string(),
+            message: message.to_string(),
+            params: Map::new(),
+        }
+    }
+
+    pub fn required(field: &str) -> Self {
+        Self::new(field, "required", "Field is required")
+    }
+
+    pub fn coupon_type(field: &str) -> Self {
+        Self::new(field, "choice", "Must be percent, fixed, bogo or tiered")
+    }
+
+    pub fn date(field: &str) -> Self {
+        Self::new(field, "date", "Must be a date like YYYY-MM-DDTHH:MM:SSZ")
+    }
+
+    /// For amounts worked out from the field that do not fit in a `u64`.
+    pub fn overflow(field: &str) -> Self {
+        Self::new(field, "overflow", "Amounts are too large")
+    }
+
+    /// Like the `range` rule of a `Validate` derive.
+    pub fn range(field: &str, min: u64, max: u64) -> Self {
+        let message = format!("Must be between {} and {}", min, max);
+        Self::new(field, "range", &message).with_bounds(min, max)
+    }
+
+    /// Like the `length` rule of a `Validate` derive.
+    pub fn length(field: &str, min: u64, max: u64) -> Self {
+        let message = format!("Length must be between {} and {}", min, max);
+        Self::new(field, "length", &message).with_bounds(min, max)
+    }
+
+    fn with_bounds(mut self, min: u64, max: u64) -> Self {
+        self.params.insert("min".to_string(), json!(min));
+        self.params.insert("max".to_string(), json!(max));
+        self
+    }
+
+    fn from_validator(field: &str, error: &ValidationError) -> Self {
+        let params: Map<String, Value> = error
+            .params
+            .iter()
+            .map(|(name, value)| (name.to_string(), whole_number(value)))
+            .collect();
+        let message = match &error.message {
+            Some(message) => message.to_string(),
+            None => default_message(&error.code, &params),
+        };
+        Self {
+            field: field.to_string(),
+            code: error.code.to_string(),
+            message,
+            params,
+        }
+    }
+}
+
+/// Range bounds come as floats; `1.0` reads better as `1`.
+fn whole_number(value: &Value) -> Value {
+    match value.as_f64() {
+        Some(n) if value.is_f64() && n.fract() == 0.0 && n.abs() < i64::MAX as f64 => {
+            json!(n as i64)
+        }
+        _ => value.clone(),
+    }
+}
+
+fn default_message(code: &str, params: &Map<String, Value>) -> String {
+    match (code, params.get("min"), params.get("max")) {
+        ("range", Some(min), Some(max)) => format!("Must be between {} and {}", min, max),
+        ("range", Some(min), None) => format!("Must be at least {}", min),
+        ("range", None, Some(max)) => format!("Must be at most {}", max),
+        ("length", Some(min), Some(max)) => {
+            format!("Length must be between {} and {}", min, max)
+        }
+        ("length", Some(min), None) => format!("Length must be at least {}", min),
+        ("length", None, Some(max)) => format!("Length must be at most {}", max),
+        ("regex", _, _) => "Invalid format".to_string(),
+        _ => format!("Invalid value ({})", code),
+    }
+}
+
+/// Flattens what a `Validate` derive reports into one error per broken
+/// rule, sorted by field.
+pub fn field_errors(errors: &ValidationErrors) -> Vec<FieldError> {
+    let mut fields: Vec<_> = errors.field_errors().into_iter().collect();
+    fields.sort_by_key(|(field, _)| *field);
+    fields
+        .into_iter()
+        .flat_map(|(field, errors)| errors.iter().map(|e| FieldError::from_validator(field, e)))
+        .collect()
+}
+
+/// The error string use cases and repositories return for `errors`.
+pub fn validation_error(errors: &[FieldError]) -> String {
+    format!("{}: {}", VALIDATION_FAILED, json!(errors))
+}
+
+/// The field errors of an error built by `validation_error`.
+pub fn parse_validation_error(error: &str) -> Option<Vec<FieldError>> {
+    let errors = error.strip_prefix(VALIDATION_FAILED)?.strip_prefix(": ")?;
+    serde_json::from_str(errors).ok()
+}
+
+/// The field error a coupon or product `CHECK` constraint stands for.
+pub fn constraint_error(constraint: &str) -> Option<FieldError> {
+    let (field, code, message) = match constraint {
+        "chk_value_range" => (
+            "value",
+            "coupon_type",
+            "value or params do not match the coupon type",
+        ),
+        "chk_validity_period" => (
+            "valid_until",
+            "validity_period",
+            "valid_until must be after valid_from and at most 5 years later",
+        ),
+        "chk_max_uses" => (
+            "max_uses",
+            "max_uses",
+            "max_uses must be positive and is not allowed on one_shot coupons",
+        ),
+        "chk_max_uses_per_customer" => ("max_uses_per_customer", "range", "Must be at least 1"),
+        "chk_max_discount_amount" => ("max_discount_amount", "range", "Must be at least 1"),
+        "chk_min_product_price" => ("min_product_price", "range", "Must be at least 0"),
+        "chk_price_min" => ("price", "range", "Must be at least 1"),
+        "chk_stock_range" => ("stock", "range", "Must be between 0 and 999999"),
+        _ => return None,
+    };
+    Some(FieldError::new(field, code, message))
+}
@@ -1,5 +1,13 @@
-use axum::Json;
-use std::sync::Arc;
+use axum::{
+    Json,
+    body::{Body, to_bytes},
+    extract::Request,
+    http::{HeaderMap, HeaderName, HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::{Map, Value, json};
+use std::{any::Any, sync::Arc};
 
 use crate::interfaces::handler::generic_handler::{
     AdapterRequest, AdapterResponse, GenericHandler,
@@ -18,3 +26,313 @@ pub async fn handle(
     let response = axum_handler.inner.handle(request).await;
     Json(response)
 }
+
+pub fn adapter_headers(headers: &HeaderMap) -> Value {
+    let mut map = Map::new();
+    for (name, value) in headers {
+        if let Ok(value) = value.to_str() {
+            map.insert(name.as_str().to_string(), Value::String(value.to_string()));
+        }
+    }
+    Value::Object(map)
+}
+
+/// Representations a JSON response can be rendered in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Json,
+    MessagePack,
+    /// Only for paginated collections.
+    Csv,
+}
+
+impl Format {
+    const ALL: [Format; 3] = [Format::Json, Format::MessagePack, Format::Csv];
+
+    fn media_type(self) -> &'static str {
+        match self {
+            Format::Json => "application/json",
+            Format::MessagePack => "application/msgpack",
+            Format::Csv => "text/csv",
+        }
+    }
+}
+
+/// The formats the `Accept` header allows, most preferred first. A missing
+/// header accepts JSON.
+fn acceptable_formats(headers: &HeaderMap) -> Vec<Format> {
+    let accept = match headers.get(header::ACCEPT).and_then(|v| v.to_str().ok()) {
+        Some(accept) if !accept.trim().is_empty() => accept,
+        _ => return vec![Format::Json],
+    };
+
+    let ranges: Vec<(&str, &str, f32)> = accept
+        .split(',')
+        .filter_map(|range| {
+            let mut parts = range.split(';');
+            let (kind, subtype) = parts.next()?.trim().split_once('/')?;
+            let quality = parts
+                .filter_map(|p| p.trim().strip_prefix("q="))
+                .map(|q| q.trim().parse::<f32>().unwrap_or(0.0))
+                .next()
+                .unwrap_or(1.0);
+            Some((kind.trim(), subtype.trim(), quality))
+        })
+        .collect();
+
+    // Each format takes the quality of the most specific range matching it.
+    let mut formats: Vec<(Format, f32)> = Format::ALL
+        .into_iter()
+        .filter_map(|format| {
+            let (kind, subtype) = format.media_type().split_once('/')?;
+            ranges
+                .iter()
+                .filter_map(|(k, s, q)| {
+                    let specificity = match (*k, *s) {
+                        (k, s)
+                            if k.eq_ignore_ascii_case(kind) && s.eq_ignore_ascii_case(subtype) =>
+                        {
+                            2
+                        }
+                        (k, "*") if k.eq_ignore_ascii_case(kind) => 1,
+                        ("*", "*") => 0,
+                        _ => return None,
+                    };
+                    Some((specificity, *q))
+                })
+                .max_by_key(|(specificity, _)| *specificity)
+                .map(|(_, q)| (format, q))
+        })
+        .filter(|(_, q)| *q > 0.0)
+        .collect();
+    // Stable, so equally weighted formats keep JSON first.
+    formats.sort_by(|a, b| b.1.total_cmp(&a.1));
+    formats.into_iter().map(|(format, _)| format).collect()
+}
+
+/// Items and pagination of a `{ data: [...], meta: {...} }` body.
+fn paginated(body: &Value) -> Option<(&Vec<Value>, &Map<String, Value>)> {
+    Some((
+        body.get("data")?.as_array()?,
+        body.get("meta")?.as_object()?,
+    ))
+}
+
+/// One CSV row per item, with a column per key any item has. Nested values
+/// are written as JSON.
+fn to_csv(items: &[Value]) -> Result<Vec<u8>, csv::Error> {
+    let mut columns: Vec<&String> = Vec::new();
+    for item in items {
+        for key in item.as_object().into_iter().flat_map(|o| o.keys()) {
+            if !columns.contains(&key) {
+                columns.push(key);
+            }
+        }
+    }
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(&columns)?;
+    for item in items {
+        writer.write_record(
+            columns
+                .iter()
+                .map(|column| match item.get(column.as_str()) {
+                    None | Some(Value::Null) => String::new(),
+                    Some(Value::String(s)) => s.clone(),
+                    Some(value) => value.to_string(),
+                }),
+        )?;
+    }
+    writer.into_inner().map_err(|e| e.into_error().into())
+}
+
+/// Renders JSON responses in the representation the `Accept` header asks
+/// for: JSON, MessagePack, or CSV for paginated collections, whose
+/// pagination moves to `X-Page`, `X-Limit`, `X-Total-Items` and
+/// `X-Total-Pages` headers. Requests accepting none of them get a 406
+/// without reaching the handler; errors are left to `problem`.
+pub async fn negotiate(request: Request, next: Next) -> Response {
+    let formats = acceptable_formats(request.headers());
+    if formats.is_empty() {
+        return (
+            StatusCode::NOT_ACCEPTABLE,
+            [(header::VARY, "accept")],
+            Json(json!({
+                "error": "Accept must allow application/json, application/msgpack or text/csv"
+            })),
+        )
+            .into_response();
+    }
+
+    let mut response = next.run(request).await;
+    response
+        .headers_mut()
+        .append(header::VARY, HeaderValue::from_static("accept"));
+
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"));
+    if !is_json || formats[0] == Format::Json || !response.status().is_success() {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let body: Value = match to_bytes(body, usize::MAX)
+        .await
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+    {
+        Some(body) => body,
+        None => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    };
+
+    let format = formats.iter().copied().find(|format| match format {
+        Format::Csv => paginated(&body).is_some(),
+        Format::Json | Format::MessagePack => true,
+    });
+
+    let rendered = match (format, paginated(&body)) {
+        (Some(Format::MessagePack), _) => rmp_serde::to_vec_named(&body).map_err(|e| e.to_string()),
+        (Some(Format::Csv), Some((items, meta))) => {
+            for (key, value) in meta {
+                let name = format!("x-{}", key.replace('_', "-"));
+                if let (Ok(name), Ok(value)) = (
+                    HeaderName::try_from(name),
+                    HeaderValue::try_from(value.to_string()),
+                ) {
+                    parts.headers.insert(name, value);
+                }
+            }
+            to_csv(items).map_err(|e| e.to_string())
+        }
+        (Some(_), _) => Ok(body.to_string().into_bytes()),
+        (None, _) => {
+            return (
+                StatusCode::NOT_ACCEPTABLE,
+                [(header::VARY, "accept")],
+                Json(json!({
+                    "error": "text/csv is only available for paginated collections"
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    match rendered {
+        Ok(bytes) => {
+            let format = format.unwrap_or(Format::Json);
+            let content_type = match format {
+                Format::Csv => "text/csv; charset=utf-8",
+                _ => format.media_type(),
+            };
+            parts
+                .headers
+                .insert(header::CONTENT_TYPE, HeaderValue::from_static(content_type));
+            parts.headers.remove(header::CONTENT_LENGTH);
+            Response::from_parts(parts, Body::from(bytes))
+        }
+        Err(e) => {
+            log::error!("Error rendering response: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Renders error responses as RFC 7807 `application/problem+json`.
+/// Controllers answer `{ "error": ... }`: the message becomes `detail`, any
+/// `details` are appended to it, the field errors in `fields` become
+/// `errors`, and other members, like a coupon's `reason`, are kept as
+/// extensions.
+pub async fn problem(request: Request, next: Next) -> Response {
+    let instance = request.uri().path().to_string();
+    let response = next.run(request).await;
+
+    let status = response.status();
+    let is_problem = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .is_some_and(|v| v == "application/problem+json");
+    if !(status.is_client_error() || status.is_server_error()) || is_problem {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX).await.unwrap_or_default();
+    let body = serde_json::from_slice(&bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).trim().to_string()));
+
+    parts.headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/problem+json"),
+    );
+    parts.headers.remove(header::CONTENT_LENGTH);
+    let problem = problem_document(status, &instance, body);
+    Response::from_parts(parts, Body::from(problem.to_string()))
+}
+
+fn problem_document(status: StatusCode, instance: &str, body: Value) -> Value {
+    let text = |value: Value| match value {
+        Value::String(s) => s,
+        value => value.to_string(),
+    };
+
+    let mut problem = Map::new();
+    let mut detail = None;
+    let mut errors = Vec::new();
+    match body {
+        Value::Object(mut body) => {
+            detail = body.remove("error").map(text);
+            if let Some(details) = body.remove("details").map(text) {
+                detail = Some(match detail {
+                    Some(detail) => format!("{}: {}", detail, details),
+                    None => details,
+                });
+            }
+            if let Some(Value::Array(fields)) = body.remove("fields") {
+                errors = fields;
+            }
+            problem.extend(body);
+        }
+        Value::String(message) if !message.is_empty() => detail = Some(message),
+        _ => {}
+    }
+
+    let title = match errors.is_empty() {
+        true => status.canonical_reason().unwrap_or("Error").to_string(),
+        false => "Validation Error".to_string(),
+    };
+    problem.insert(
+        "type".to_string(),
+        json!(format!(
+            "/problems/{}",
+            title.to_lowercase().replace(' ', "-")
+        )),
+    );
+    problem.insert("title".to_string(), json!(title));
+    problem.insert("status".to_string(), json!(status.as_u16()));
+    if let Some(detail) = detail {
+        problem.insert("detail".to_string(), json!(detail));
+    }
+    problem.insert("instance".to_string(), json!(instance));
+    if !errors.is_empty() {
+        problem.insert("errors".to_string(), json!(errors));
+    }
+    Value::Object(problem)
+}
+
+/// Answers a request whose handler panicked with a 500, which `problem`
+/// renders like any other error.
+pub fn panic_response(panic: Box<dyn Any + Send + 'static>) -> Response {
+    let message = match panic.downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => panic.downcast_ref::<String>().cloned().unwrap_or_default(),
+    };
+    log::error!("Handler panicked: {}", message);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(json!({"error": "Internal server error"})),
+    )
+        .into_response()
+}
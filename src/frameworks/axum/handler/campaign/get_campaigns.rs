@@ -0,0 +1,39 @@
+use axum::{Json, extract::Query, http::StatusCode, response::IntoResponse};
+use serde_json::{Value, json};
+use std::sync::Arc;
+
+use crate::{
+    frameworks::adapter::axum::{AxumHandler, handle},
+    interfaces::handler::generic_handler::AdapterRequest,
+};
+
+pub async fn get_campaigns_handler(
+    handler: Arc<AxumHandler>,
+    query: Query<Value>,
+) -> impl IntoResponse {
+    let parse_u32 = |key: &str| match query.get(key) {
+        Some(value) => match value.as_str().unwrap_or("").parse::<u32>() {
+            Ok(parsed_value) => json!(parsed_value),
+            Err(_) => json!(null),
+        },
+        None => json!(null),
+    };
+
+    let request = AdapterRequest {
+        query: Some(json!({
+            "page": parse_u32("page"),
+            "limit": parse_u32("limit"),
+            "status": query.get("status").cloned().unwrap_or(Value::Null),
+        })),
+        params: None,
+        body: None,
+        headers: None,
+    };
+
+    let Json(adapter_response) = handle(handler, Json(request)).await;
+
+    let status = StatusCode::from_u16(adapter_response.status as u16)
+        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+    (status, Json(adapter_response.data)).into_response()
+}
@@ -0,0 +1,3 @@
+pub mod create_campaign;
+pub mod get_campaign_by_id;
+pub mod get_campaigns;
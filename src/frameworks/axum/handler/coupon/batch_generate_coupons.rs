@@ -0,0 +1,57 @@
+use axum::{
+    body::Bytes,
+    extract::Json,
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use serde_json::{Value, from_slice, json};
+use std::sync::Arc;
+
+use crate::{
+    frameworks::adapter::axum::{AxumHandler, handle},
+    interfaces::handler::generic_handler::AdapterRequest,
+};
+
+pub async fn batch_generate_coupons_handler(
+    handler: Arc<AxumHandler>,
+    body: Bytes,
+) -> impl IntoResponse {
+    let body: Value = match from_slice(&body) {
+        Ok(v) => v,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "error": "Invalid JSON format" })),
+            )
+                .into_response();
+        }
+    };
+
+    let request = AdapterRequest {
+        query: None,
+        params: None,
+        body: Some(body),
+        headers: None,
+    };
+
+    let Json(adapter_response) = handle(handler, Json(request)).await;
+
+    let status = StatusCode::from_u16(adapter_response.status as u16)
+        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+    match adapter_response.binary {
+        Some(csv) => (
+            status,
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+                (
+                    header::CONTENT_DISPOSITION,
+                    "attachment; filename=\"coupons.csv\"",
+                ),
+            ],
+            csv,
+        )
+            .into_response(),
+        None => (status, Json(adapter_response.data)).into_response(),
+    }
+}
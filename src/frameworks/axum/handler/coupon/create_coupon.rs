@@ -1,16 +1,20 @@
-use axum::{body::Bytes, extract::Json, response::IntoResponse};
+use axum::{body::Bytes, extract::Json, http::HeaderMap, response::IntoResponse};
 use serde_json::{Value, from_slice, json};
 use std::sync::Arc;
 
 use crate::{
-    frameworks::adapter::axum::{AxumHandler, handle},
+    frameworks::adapter::axum::{AxumHandler, adapter_headers, handle},
     interfaces::handler::generic_handler::AdapterRequest,
 };
 
 use crate::interfaces::handler::generic_handler::StatusCode as AdapterStatusCode;
 use axum::http::StatusCode as AxumStatusCode;
 
-pub async fn create_coupon_handler(handler: Arc<AxumHandler>, body: Bytes) -> impl IntoResponse {
+pub async fn create_coupon_handler(
+    handler: Arc<AxumHandler>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> impl IntoResponse {
     let body: Value = match from_slice(&body) {
         Ok(v) => v,
         Err(_) => {
@@ -26,6 +30,7 @@ pub async fn create_coupon_handler(handler: Arc<AxumHandler>, body: Bytes) -> im
         query: None,
         params: None,
         body: Some(body),
+        headers: Some(adapter_headers(&headers)),
     };
 
     let response = handle(handler, Json(request)).await.0;
@@ -33,6 +38,7 @@ pub async fn create_coupon_handler(handler: Arc<AxumHandler>, body: Bytes) -> im
     let status = match response.status {
         AdapterStatusCode::Ok | AdapterStatusCode::Created => AxumStatusCode::CREATED,
         AdapterStatusCode::Conflict => AxumStatusCode::CONFLICT,
+        AdapterStatusCode::UnprocessableEntity => AxumStatusCode::UNPROCESSABLE_ENTITY,
         _ => {
             if let Some(error_str) = response.data.get("error").and_then(|v| v.as_str()) {
                 match error_str {
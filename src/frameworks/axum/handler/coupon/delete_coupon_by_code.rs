@@ -1,24 +1,26 @@
 use axum::{
     extract::{Json, Path},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     response::IntoResponse,
 };
 use serde_json::json;
 use std::sync::Arc;
 
 use crate::{
-    frameworks::adapter::axum::{AxumHandler, handle},
+    frameworks::adapter::axum::{AxumHandler, adapter_headers, handle},
     interfaces::handler::generic_handler::AdapterRequest,
 };
 
 pub async fn delete_coupon_by_id_handler(
     handler: Arc<AxumHandler>,
     Path(code): Path<String>,
+    headers: HeaderMap,
 ) -> impl IntoResponse {
     let request = AdapterRequest {
         query: None,
         params: Some(json!({"code": code})),
         body: None,
+        headers: Some(adapter_headers(&headers)),
     };
 
     let Json(adapter_response) = handle(handler, Json(request)).await;
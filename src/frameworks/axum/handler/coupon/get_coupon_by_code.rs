@@ -1,6 +1,6 @@
 use axum::{
     extract::{Json, Path},
-    http::StatusCode,
+    http::{StatusCode, header},
     response::IntoResponse,
 };
 use serde_json::json;
@@ -8,7 +8,7 @@ use std::sync::Arc;
 
 use crate::{
     frameworks::adapter::axum::{AxumHandler, handle},
-    interfaces::handler::generic_handler::AdapterRequest,
+    interfaces::handler::{etag::format_etag, generic_handler::AdapterRequest},
 };
 
 pub async fn get_coupon_by_id_handler(
@@ -19,15 +19,26 @@ pub async fn get_coupon_by_id_handler(
         query: None,
         params: Some(json!({"code": code})),
         body: None,
+        headers: None,
     };
 
     let Json(adapter_response) = handle(handler, Json(request)).await;
     let status = StatusCode::from_u16(adapter_response.status as u16)
         .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
 
-    if status == StatusCode::NO_CONTENT {
-        status.into_response()
-    } else {
-        (status, Json(adapter_response.data)).into_response()
+    let version = adapter_response
+        .data
+        .get("version")
+        .and_then(|v| v.as_u64());
+
+    match (status, version) {
+        (StatusCode::NO_CONTENT, _) => status.into_response(),
+        (StatusCode::OK, Some(version)) => (
+            status,
+            [(header::ETAG, format_etag(version as u32))],
+            Json(adapter_response.data),
+        )
+            .into_response(),
+        _ => (status, Json(adapter_response.data)).into_response(),
     }
 }
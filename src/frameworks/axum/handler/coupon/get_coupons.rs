@@ -1,21 +1,50 @@
-use axum::{Json, extract::Query};
-use serde_json::Value;
+use axum::{Json, extract::Query, http::StatusCode, response::IntoResponse};
+use serde_json::{Value, json};
 use std::sync::Arc;
 
 use crate::{
     frameworks::adapter::axum::{AxumHandler, handle},
-    interfaces::handler::generic_handler::{AdapterRequest, AdapterResponse},
+    interfaces::handler::generic_handler::AdapterRequest,
 };
 
 pub async fn get_coupons_handler(
     handler: Arc<AxumHandler>,
     query: Query<Value>,
-) -> Json<AdapterResponse> {
+) -> impl IntoResponse {
+    let parse_u32 = |key: &str| match query.get(key) {
+        Some(value) => match value.as_str().unwrap_or("").parse::<u32>() {
+            Ok(parsed_value) => json!(parsed_value),
+            Err(_) => json!(null),
+        },
+        None => json!(null),
+    };
+
+    let is_active = match query.get("is_active") {
+        Some(value) => match value.as_str().unwrap_or("").parse::<bool>() {
+            Ok(parsed_value) => json!(parsed_value),
+            Err(_) => json!(null),
+        },
+        None => json!(null),
+    };
+
     let request = AdapterRequest {
-        query: None,
+        query: Some(json!({
+            "page": parse_u32("page"),
+            "limit": parse_u32("limit"),
+            "search": query.get("search").cloned().unwrap_or(Value::Null),
+            "valid_from": query.get("valid_from").cloned().unwrap_or(Value::Null),
+            "valid_until": query.get("valid_until").cloned().unwrap_or(Value::Null),
+            "is_active": is_active,
+        })),
         params: None,
         body: None,
+        headers: None,
     };
 
-    return handle(handler, axum::Json(request)).await;
+    let Json(adapter_response) = handle(handler, Json(request)).await;
+
+    let status = StatusCode::from_u16(adapter_response.status as u16)
+        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+    (status, Json(adapter_response.data)).into_response()
 }
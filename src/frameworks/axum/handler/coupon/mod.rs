@@ -1,5 +1,9 @@
+pub mod batch_generate_coupons;
 pub mod create_coupon;
 pub mod delete_coupon_by_code;
+pub mod get_coupon_applications;
 pub mod get_coupon_by_code;
+pub mod get_coupon_redemptions;
 pub mod get_coupons;
 pub mod update_coupon_by_code;
+pub mod validate_coupon;
@@ -1,20 +1,21 @@
 use axum::{
     body::Bytes,
     extract::{Json, Path},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     response::IntoResponse,
 };
 use serde_json::{Value, from_slice, json};
 use std::sync::Arc;
 
 use crate::{
-    frameworks::adapter::axum::{AxumHandler, handle},
+    frameworks::adapter::axum::{AxumHandler, adapter_headers, handle},
     interfaces::handler::generic_handler::AdapterRequest,
 };
 
 pub async fn update_coupon_by_id_handler(
     handler: Arc<AxumHandler>,
     Path(code): Path<String>,
+    headers: HeaderMap,
     body: Bytes,
 ) -> impl IntoResponse {
     let body: Value = from_slice(&body).unwrap_or_else(|_| json!({}));
@@ -23,6 +24,7 @@ pub async fn update_coupon_by_id_handler(
         query: None,
         params: Some(json!({"code": code})),
         body: Some(body),
+        headers: Some(adapter_headers(&headers)),
     };
 
     let Json(adapter_response) = handle(handler, Json(request)).await;
@@ -23,6 +23,7 @@ pub async fn apply_percent_discount_handler(
         query: None,
         params: Some(json!({ "product_id": id })),
         body: Some(body),
+        headers: None,
     };
 
     let Json(adapter_response) = handle(handler, Json(request)).await;
@@ -0,0 +1,46 @@
+use axum::{
+    Json,
+    extract::{Path, Query},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use serde_json::{Value, json};
+use std::sync::Arc;
+
+use crate::{
+    frameworks::adapter::axum::{AxumHandler, handle},
+    interfaces::handler::generic_handler::AdapterRequest,
+};
+
+pub async fn get_product_discounts_handler(
+    handler: Arc<AxumHandler>,
+    Path(id): Path<String>,
+    query: Query<Value>,
+) -> impl IntoResponse {
+    let parse_u32 = |key: &str| match query.get(key) {
+        Some(value) => match value.as_str().unwrap_or("").parse::<u32>() {
+            Ok(parsed_value) => json!(parsed_value),
+            Err(_) => json!(null),
+        },
+        None => json!(null),
+    };
+
+    let request = AdapterRequest {
+        query: Some(json!({
+            "page": parse_u32("page"),
+            "limit": parse_u32("limit"),
+            "from": query.get("from"),
+            "to": query.get("to"),
+        })),
+        params: Some(json!({ "id": id })),
+        body: None,
+        headers: None,
+    };
+
+    let Json(adapter_response) = handle(handler, Json(request)).await;
+
+    let status = StatusCode::from_u16(adapter_response.status as u16)
+        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+    (status, Json(adapter_response.data)).into_response()
+}
@@ -1,3 +1,4 @@
 pub mod apply_coupon_discount;
 pub mod apply_percent_discount;
+pub mod get_product_discounts;
 pub mod remove_discount_active;
@@ -22,6 +22,7 @@ pub async fn remove_discount_handler(
         query: None,
         params: Some(json!({ "product_id": id })),
         body: Some(body),
+        headers: None,
     };
 
     let Json(adapter_response) = handle(handler, Json(request)).await;
@@ -0,0 +1,44 @@
+use axum::{
+    body::Bytes,
+    extract::Json,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+};
+use serde_json::{Value, from_slice, json};
+use std::sync::Arc;
+
+use crate::{
+    frameworks::adapter::axum::{AxumHandler, adapter_headers, handle},
+    interfaces::handler::generic_handler::AdapterRequest,
+};
+
+pub async fn create_order_handler(
+    handler: Arc<AxumHandler>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> impl IntoResponse {
+    let body: Value = match from_slice(&body) {
+        Ok(v) => v,
+        Err(_) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "error": "Invalid JSON format" })),
+            )
+                .into_response();
+        }
+    };
+
+    let request = AdapterRequest {
+        query: None,
+        params: None,
+        body: Some(body),
+        headers: Some(adapter_headers(&headers)),
+    };
+
+    let Json(adapter_response) = handle(handler, Json(request)).await;
+
+    let status = StatusCode::from_u16(adapter_response.status as u16)
+        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+    (status, Json(adapter_response.data)).into_response()
+}
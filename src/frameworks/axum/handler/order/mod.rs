@@ -0,0 +1,4 @@
+pub mod create_order;
+pub mod get_order_by_id;
+pub mod get_orders;
+pub mod transition_order;
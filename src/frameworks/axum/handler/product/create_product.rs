@@ -1,20 +1,25 @@
 use axum::{
     Json,
     body::Bytes,
+    http::HeaderMap,
     response::{IntoResponse, Response},
 };
 use serde_json::{Value, from_slice, json};
 use std::sync::Arc;
 
 use crate::{
-    frameworks::adapter::axum::{AxumHandler, handle},
+    frameworks::adapter::axum::{AxumHandler, adapter_headers, handle},
     interfaces::handler::generic_handler::AdapterRequest,
 };
 
 use crate::interfaces::handler::generic_handler::StatusCode as AdapterStatusCode;
 use axum::http::StatusCode as AxumStatusCode;
 
-pub async fn create_product_handler(handler: Arc<AxumHandler>, body: Bytes) -> Response {
+pub async fn create_product_handler(
+    handler: Arc<AxumHandler>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
     let body: Value = match from_slice(&body) {
         Ok(v) => v,
         Err(_) => {
@@ -30,6 +35,7 @@ pub async fn create_product_handler(handler: Arc<AxumHandler>, body: Bytes) -> R
         query: None,
         params: None,
         body: Some(body),
+        headers: Some(adapter_headers(&headers)),
     };
 
     let response = handle(handler, Json(request)).await.0;
@@ -38,6 +44,7 @@ pub async fn create_product_handler(handler: Arc<AxumHandler>, body: Bytes) -> R
         AdapterStatusCode::Ok => AxumStatusCode::OK,
         AdapterStatusCode::Created => AxumStatusCode::CREATED,
         AdapterStatusCode::Conflict => AxumStatusCode::CONFLICT,
+        AdapterStatusCode::UnprocessableEntity => AxumStatusCode::UNPROCESSABLE_ENTITY,
         _ => {
             if let Some(error_str) = response.data.get("error").and_then(|v| v.as_str()) {
                 match error_str {
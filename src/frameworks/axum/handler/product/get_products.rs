@@ -1,16 +1,16 @@
-use axum::{Json, extract::Query};
+use axum::{Json, extract::Query, http::StatusCode, response::IntoResponse};
 use serde_json::{Value, json};
 use std::sync::Arc;
 
 use crate::{
     frameworks::adapter::axum::{AxumHandler, handle},
-    interfaces::handler::generic_handler::{AdapterRequest, AdapterResponse},
+    interfaces::handler::generic_handler::AdapterRequest,
 };
 
 pub async fn get_all_products_handler(
     handler: Arc<AxumHandler>,
     query: Query<Value>,
-) -> Json<AdapterResponse> {
+) -> impl IntoResponse {
     let search = query.get("search").cloned().unwrap_or_else(|| json!(null));
     let page = match query.get("page") {
         Some(value) => match value.as_str().unwrap_or("").parse::<u32>() {
@@ -64,7 +64,13 @@ pub async fn get_all_products_handler(
         query: Some(products),
         params: None,
         body: None,
+        headers: None,
     };
 
-    return handle(handler, axum::Json(request)).await;
+    let Json(adapter_response) = handle(handler, Json(request)).await;
+
+    let status = StatusCode::from_u16(adapter_response.status as u16)
+        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+    (status, Json(adapter_response.data)).into_response()
 }
@@ -1,6 +1,9 @@
+pub mod assign_tax_class;
 pub mod create_product;
 pub mod delete_product_by_id;
 pub mod get_product_by_id;
 pub mod get_products;
+pub mod get_trash_products;
+pub mod purge_product_by_id;
 pub mod restore_product_by_id;
 pub mod update_product_by_id;
@@ -0,0 +1,4 @@
+pub mod cancel_sale_event;
+pub mod create_sale_event;
+pub mod get_sale_event_by_id;
+pub mod preview_sale_event;
@@ -0,0 +1,23 @@
+use axum::{Json, http::StatusCode, response::IntoResponse};
+use std::sync::Arc;
+
+use crate::{
+    frameworks::adapter::axum::{AxumHandler, handle},
+    interfaces::handler::generic_handler::AdapterRequest,
+};
+
+pub async fn get_tax_classes_handler(handler: Arc<AxumHandler>) -> impl IntoResponse {
+    let request = AdapterRequest {
+        query: None,
+        params: None,
+        body: None,
+        headers: None,
+    };
+
+    let Json(adapter_response) = handle(handler, Json(request)).await;
+
+    let status = StatusCode::from_u16(adapter_response.status as u16)
+        .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+    (status, Json(adapter_response.data)).into_response()
+}
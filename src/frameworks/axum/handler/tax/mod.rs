@@ -0,0 +1,2 @@
+pub mod create_tax_class;
+pub mod get_tax_classes;
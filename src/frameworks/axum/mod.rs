@@ -1,2 +1,3 @@
 pub mod server;
 pub mod handler;
+pub mod rate_limit;
@@ -0,0 +1,364 @@
+use axum::{
+    Json,
+    extract::{ConnectInfo, MatchedPath, Request},
+    http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use serde_json::json;
+use std::{
+    convert::Infallible,
+    future::Future,
+    net::{IpAddr, Ipv6Addr, SocketAddr},
+    pin::Pin,
+    str::FromStr,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tokio::sync::RwLock;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::domain::{
+    entity::rate_limit_entity::{RateLimit, RateLimitDecision},
+    repository::rate_limit_repository::RateLimitRepository,
+    usecase::rate_limit::take_rate_limit_token_usecase::TakeRateLimitTokenUseCase,
+};
+
+/// The limit of one route, written `METHOD /path=requests/secs` in config,
+/// with the path as it is routed (`/api/v1/coupons/:code`). Without a method
+/// it applies to every method, and `*` stands for every route no other rule
+/// names, each route still limited on its own.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimitRule {
+    pub method: Option<Method>,
+    pub path: String,
+    pub limit: RateLimit,
+}
+
+impl FromStr for RateLimitRule {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (route, limit) = s.rsplit_once('=').ok_or(())?;
+        let mut parts = route.split_whitespace();
+        let (method, path) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(path), None, None) => (None, path),
+            (Some(method), Some(path), None) => (
+                Some(Method::from_bytes(method.to_uppercase().as_bytes()).map_err(|_| ())?),
+                path,
+            ),
+            _ => return Err(()),
+        };
+        if path != "*" && !path.starts_with('/') {
+            return Err(());
+        }
+        Ok(RateLimitRule {
+            method,
+            path: path.to_string(),
+            limit: limit.parse()?,
+        })
+    }
+}
+
+/// A proxy address or network, written `10.0.0.1` or `10.0.0.0/8`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrustedProxy {
+    network: IpAddr,
+    prefix: u8,
+}
+
+impl TrustedProxy {
+    fn contains(&self, ip: IpAddr) -> bool {
+        match (self.network, ip.to_canonical()) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix as u32).unwrap_or(0);
+                u32::from(network) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix as u32).unwrap_or(0);
+                u128::from(network) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for TrustedProxy {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (network, prefix) = match s.trim().split_once('/') {
+            Some((network, prefix)) => (network, Some(prefix)),
+            None => (s.trim(), None),
+        };
+        let network: IpAddr = network.parse().map_err(|_| ())?;
+        let network = network.to_canonical();
+        let bits = if network.is_ipv4() { 32 } else { 128 };
+        let prefix = match prefix {
+            Some(prefix) => prefix.parse().map_err(|_| ())?,
+            None => bits,
+        };
+        match prefix <= bits {
+            true => Ok(TrustedProxy { network, prefix }),
+            false => Err(()),
+        }
+    }
+}
+
+/// Which requests are limited and how clients are told apart.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitPolicy {
+    pub rules: Vec<RateLimitRule>,
+    /// Proxies whose `X-Forwarded-For` is believed.
+    pub trusted_proxies: Vec<TrustedProxy>,
+    /// API keys limited on their own; any other key counts as no key.
+    pub api_keys: Vec<String>,
+}
+
+impl RateLimitPolicy {
+    /// Parses comma-separated `RATE_LIMITS` rules, `TRUSTED_PROXIES` and
+    /// `RATE_LIMIT_API_KEYS`.
+    pub fn parse(rules: &str, trusted_proxies: &str, api_keys: &str) -> Result<Self, String> {
+        let entries = |list: &str| -> Vec<String> {
+            list.split(',')
+                .map(|entry| entry.trim().to_string())
+                .filter(|entry| !entry.is_empty())
+                .collect()
+        };
+        Ok(RateLimitPolicy {
+            rules: entries(rules)
+                .iter()
+                .map(|rule| {
+                    rule.parse()
+                        .map_err(|_| format!("Invalid rate limit rule: {}", rule))
+                })
+                .collect::<Result<_, _>>()?,
+            trusted_proxies: entries(trusted_proxies)
+                .iter()
+                .map(|proxy| {
+                    proxy
+                        .parse()
+                        .map_err(|_| format!("Invalid trusted proxy: {}", proxy))
+                })
+                .collect::<Result<_, _>>()?,
+            api_keys: entries(api_keys),
+        })
+    }
+
+    /// The rule for a route: one naming its method, then one for any method,
+    /// then `*`.
+    fn rule(&self, method: &Method, path: &str) -> Option<&RateLimitRule> {
+        let rule = |method: Option<&Method>, path: &str| {
+            self.rules
+                .iter()
+                .find(|r| r.method.as_ref() == method && r.path == path)
+        };
+        rule(Some(method), path)
+            .or_else(|| rule(None, path))
+            .or_else(|| rule(Some(method), "*"))
+            .or_else(|| rule(None, "*"))
+    }
+
+    fn is_trusted(&self, ip: IpAddr) -> bool {
+        self.trusted_proxies.iter().any(|proxy| proxy.contains(ip))
+    }
+
+    /// The address a request comes from. When the peer is a trusted proxy,
+    /// `X-Forwarded-For` is read from the right, skipping trusted hops, so a
+    /// client can't pick its own address by sending the header.
+    fn client_ip(&self, peer: IpAddr, headers: &HeaderMap) -> IpAddr {
+        let mut client = peer;
+        if !self.is_trusted(client) {
+            return client;
+        }
+        let hops = headers
+            .get_all("x-forwarded-for")
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(|v| v.split(','))
+            .collect::<Vec<_>>();
+        for hop in hops.into_iter().rev() {
+            match hop.trim().parse::<IpAddr>() {
+                Ok(ip) => client = ip.to_canonical(),
+                Err(_) => break,
+            }
+            if !self.is_trusted(client) {
+                break;
+            }
+        }
+        client
+    }
+
+    /// Clients sending a configured API key, in `X-Api-Key` or as a bearer
+    /// token, are limited by key wherever they connect from; others by
+    /// address, so a made-up key can't buy a fresh bucket. IPv6 clients are
+    /// limited by their /64, as one host usually holds the whole prefix.
+    fn client_key(&self, request: &Request) -> Option<String> {
+        let headers = request.headers();
+        let api_key = headers
+            .get("x-api-key")
+            .and_then(|v| v.to_str().ok())
+            .or_else(|| {
+                headers
+                    .get(header::AUTHORIZATION)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.strip_prefix("Bearer "))
+            })
+            .map(str::trim)
+            .filter(|key| self.api_keys.iter().any(|known| known == key));
+        if let Some(api_key) = api_key {
+            return Some(format!("key:{}", api_key));
+        }
+
+        let peer = request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()?
+            .0
+            .ip()
+            .to_canonical();
+        match self.client_ip(peer, headers) {
+            IpAddr::V6(ip) => {
+                let prefix = Ipv6Addr::from(u128::from(ip) & (u128::MAX << 64));
+                Some(format!("ip:{}/64", prefix))
+            }
+            ip => Some(format!("ip:{}", ip)),
+        }
+    }
+}
+
+/// Limits requests per client and route with token buckets, answering
+/// `RateLimit-Limit`, `RateLimit-Remaining`, `RateLimit-Reset` and
+/// `RateLimit-Policy` on limited routes, and a 429 with `Retry-After` once
+/// a client's bucket is empty. It reads the matched route, so it goes in
+/// `Router::route_layer`; the server must be run with `ConnectInfo` to
+/// limit clients by address.
+#[derive(Clone)]
+pub struct RateLimitLayer {
+    policy: Arc<RateLimitPolicy>,
+    usecase: Arc<TakeRateLimitTokenUseCase>,
+}
+
+impl RateLimitLayer {
+    pub fn new(
+        policy: RateLimitPolicy,
+        repository: Arc<RwLock<dyn RateLimitRepository + Send + Sync>>,
+    ) -> Self {
+        Self {
+            policy: Arc::new(policy),
+            usecase: Arc::new(TakeRateLimitTokenUseCase::new(repository)),
+        }
+    }
+}
+
+impl<S> Layer<S> for RateLimitLayer {
+    type Service = RateLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RateLimitService {
+            inner,
+            policy: self.policy.clone(),
+            usecase: self.usecase.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct RateLimitService<S> {
+    inner: S,
+    policy: Arc<RateLimitPolicy>,
+    usecase: Arc<TakeRateLimitTokenUseCase>,
+}
+
+impl<S> Service<Request> for RateLimitService<S>
+where
+    S: Service<Request, Response = Response, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        // The clone may not be ready; call the one `poll_ready` was asked of.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let policy = self.policy.clone();
+        let usecase = self.usecase.clone();
+
+        Box::pin(async move {
+            let route = request
+                .extensions()
+                .get::<MatchedPath>()
+                .map(|path| path.as_str().to_string());
+            let rule = route
+                .as_deref()
+                .and_then(|route| policy.rule(request.method(), route));
+            let (rule, route, client) = match (rule, route.as_deref(), policy.client_key(&request))
+            {
+                (Some(rule), Some(route), Some(client)) => (rule, route, client),
+                _ => return inner.call(request).await,
+            };
+
+            // Each route has its own buckets, shared by all methods when the
+            // rule names none.
+            let method = rule
+                .method
+                .as_ref()
+                .map_or("*", |_| request.method().as_str());
+            let key = format!("{} {}|{}", method, route, client);
+            let decision = match usecase.execute(key, rule.limit).await {
+                Ok(decision) => decision,
+                Err(e) => {
+                    // Better to serve unlimited than not at all.
+                    log::error!("Error checking rate limit: {}", e);
+                    return inner.call(request).await;
+                }
+            };
+
+            let mut response = match decision.allowed {
+                true => inner.call(request).await?,
+                false => {
+                    let mut response = (
+                        StatusCode::TOO_MANY_REQUESTS,
+                        Json(json!({
+                            "error": format!(
+                                "Rate limit of {} requests per {} seconds exceeded, retry in {} seconds",
+                                decision.limit.requests,
+                                decision.limit.period_secs,
+                                decision.retry_after_secs
+                            )
+                        })),
+                    )
+                        .into_response();
+                    response
+                        .headers_mut()
+                        .insert(header::RETRY_AFTER, decision.retry_after_secs.into());
+                    response
+                }
+            };
+            insert_headers(response.headers_mut(), &decision);
+            Ok(response)
+        })
+    }
+}
+
+fn insert_headers(headers: &mut HeaderMap, decision: &RateLimitDecision) {
+    let policy = format!(
+        "{};w={}",
+        decision.limit.requests, decision.limit.period_secs
+    );
+    for (name, value) in [
+        ("ratelimit-limit", decision.limit.requests.to_string()),
+        ("ratelimit-remaining", decision.remaining.to_string()),
+        ("ratelimit-reset", decision.reset_secs.to_string()),
+        ("ratelimit-policy", policy),
+    ] {
+        if let Ok(value) = HeaderValue::try_from(value) {
+            headers.insert(HeaderName::from_static(name), value);
+        }
+    }
+}
@@ -1,67 +1,147 @@
 use axum::{
     body::Bytes,
     extract::{Path, Query},
-    http::Method,
-    routing::{Router, delete, get, patch, post},
+    http::{HeaderMap, Method},
+    middleware,
+    routing::{Router, delete, get, patch, post, put},
 };
 use serde_json::Value;
 use std::{net::SocketAddr, sync::Arc, time::Duration};
 use tokio::sync::RwLock;
-use tower_http::cors::{Any, CorsLayer};
+use tower_http::{
+    catch_panic::CatchPanicLayer,
+    cors::{Any, CorsLayer},
+};
 
 use super::handler::{
+    campaign::{
+        create_campaign::create_campaign_handler, get_campaign_by_id::get_campaign_by_id_handler,
+        get_campaigns::get_campaigns_handler,
+    },
     coupon::{
+        batch_generate_coupons::batch_generate_coupons_handler,
         create_coupon::create_coupon_handler, delete_coupon_by_code::delete_coupon_by_id_handler,
-        get_coupon_by_code::get_coupon_by_id_handler, get_coupons::get_coupons_handler,
+        get_coupon_applications::get_coupon_applications_handler,
+        get_coupon_by_code::get_coupon_by_id_handler,
+        get_coupon_redemptions::get_coupon_redemptions_handler, get_coupons::get_coupons_handler,
         update_coupon_by_code::update_coupon_by_id_handler,
+        validate_coupon::validate_coupon_handler,
     },
     discount::{
         apply_coupon_discount::apply_coupon_discount_handler,
         apply_percent_discount::apply_percent_discount_handler,
+        get_product_discounts::get_product_discounts_handler,
         remove_discount_active::remove_discount_handler,
     },
+    order::{
+        create_order::create_order_handler, get_order_by_id::get_order_by_id_handler,
+        get_orders::get_orders_handler, transition_order::transition_order_handler,
+    },
     product::{
-        create_product::create_product_handler, delete_product_by_id::delete_product_by_id_handler,
+        assign_tax_class::assign_tax_class_handler, create_product::create_product_handler,
+        delete_product_by_id::delete_product_by_id_handler,
         get_product_by_id::get_product_by_id_handler, get_products::get_all_products_handler,
+        get_trash_products::get_trash_products_handler,
+        purge_product_by_id::purge_product_by_id_handler,
         restore_product_by_id::restore_product_by_id_handler,
         update_product_by_id::update_product_by_id_handler,
     },
+    quote::create_quote::create_quote_handler,
+    sale_event::{
+        cancel_sale_event::cancel_sale_event_handler, create_sale_event::create_sale_event_handler,
+        get_sale_event_by_id::get_sale_event_by_id_handler,
+        preview_sale_event::preview_sale_event_handler,
+    },
+    tax::{create_tax_class::create_tax_class_handler, get_tax_classes::get_tax_classes_handler},
 };
 use crate::{
     application::repository::{
+        campaign_postgres_repository::PostgresCampaignRepository,
         coupon_postgres_repository::PostgresCouponRepository,
         discount_postgres_repository::PostgresDiscountRepository,
+        idempotency_postgres_repository::PostgresIdempotencyRepository,
+        order_postgres_repository::PostgresOrderRepository,
         product_postgres_repository::PostgresProductRepository,
+        rate_limit_in_memory_repository::InMemoryRateLimitRepository,
+        sale_event_postgres_repository::PostgresSaleEventRepository,
+        tax_class_postgres_repository::PostgresTaxClassRepository,
     },
-    domain::repository::{
-        coupon_repository::CouponRepository, discount_repository::DiscountRepository,
-        product_repository::ProductRepository,
+    domain::{
+        entity::{discount_entity::StackingPolicy, order_entity::OrderAction},
+        pricing::{pricing_engine::PricingEngine, tax_policy::TaxPolicy},
+        repository::{
+            campaign_repository::CampaignRepository, coupon_repository::CouponRepository,
+            discount_repository::DiscountRepository, idempotency_repository::IdempotencyRepository,
+            order_repository::OrderRepository, product_repository::ProductRepository,
+            rate_limit_repository::RateLimitRepository, sale_event_repository::SaleEventRepository,
+            tax_class_repository::TaxClassRepository,
+        },
     },
     frameworks::{
-        adapter::axum::AxumHandler,
+        adapter::axum::{AxumHandler, negotiate, panic_response, problem},
+        axum::rate_limit::{RateLimitLayer, RateLimitPolicy},
+        jobs::{
+            application_expiry_job::spawn_application_expiry_job,
+            coupon_uses_reconcile_job::spawn_coupon_uses_reconcile_job,
+            idempotency_purge_job::spawn_idempotency_purge_job,
+            trash_purge_job::spawn_trash_purge_job,
+        },
         sqlx::{pool::add_pool, run_schema::run_schema},
     },
-    interfaces::controller::{
-        coupon::{
-            create_coupon_controller::CreateCouponController,
-            delete_coupon_controller::DeleteCouponController,
-            get_coupon_controller::GetCouponController,
-            get_coupons_controller::GetAllCouponsController,
-            update_coupon_controller::UpdateCouponController,
-        },
-        discount::{
-            apply_coupon_discount_controller::ApplyCouponDiscountController,
-            apply_percent_discount_controller::ApplyPercentDiscountController,
-            remove_discount_controller::RemoveDiscountController,
-        },
-        product::{
-            create_product_controller::CreateProductController,
-            delete_product_controller::DeleteProductController,
-            get_product_controller::GetProductController,
-            get_products_controller::GetAllProductsController,
-            restore_product_controller::RestoreProductController,
-            update_product_controller::UpdateProductController,
+    interfaces::{
+        controller::{
+            campaign::{
+                create_campaign_controller::CreateCampaignController,
+                get_campaign_controller::GetCampaignController,
+                get_campaigns_controller::GetCampaignsController,
+            },
+            coupon::{
+                batch_generate_coupons_controller::BatchGenerateCouponsController,
+                create_coupon_controller::CreateCouponController,
+                delete_coupon_controller::DeleteCouponController,
+                get_coupon_applications_controller::GetCouponApplicationsController,
+                get_coupon_controller::GetCouponController,
+                get_coupon_redemptions_controller::GetCouponRedemptionsController,
+                get_coupons_controller::GetAllCouponsController,
+                update_coupon_controller::UpdateCouponController,
+                validate_coupon_controller::ValidateCouponController,
+            },
+            discount::{
+                apply_coupon_discount_controller::ApplyCouponDiscountController,
+                apply_percent_discount_controller::ApplyPercentDiscountController,
+                get_product_discounts_controller::GetProductDiscountsController,
+                remove_discount_controller::RemoveDiscountController,
+            },
+            order::{
+                create_order_controller::CreateOrderController,
+                get_order_controller::GetOrderController,
+                get_orders_controller::GetOrdersController,
+                transition_order_controller::TransitionOrderController,
+            },
+            product::{
+                assign_tax_class_controller::AssignTaxClassController,
+                create_product_controller::CreateProductController,
+                delete_product_controller::DeleteProductController,
+                get_product_controller::GetProductController,
+                get_products_controller::GetAllProductsController,
+                get_trash_products_controller::GetTrashProductsController,
+                purge_product_controller::PurgeProductController,
+                restore_product_controller::RestoreProductController,
+                update_product_controller::UpdateProductController,
+            },
+            quote::create_quote_controller::CreateQuoteController,
+            sale_event::{
+                cancel_sale_event_controller::CancelSaleEventController,
+                create_sale_event_controller::CreateSaleEventController,
+                get_sale_event_controller::GetSaleEventController,
+                preview_sale_event_controller::PreviewSaleEventController,
+            },
+            tax::{
+                create_tax_class_controller::CreateTaxClassController,
+                get_tax_classes_controller::GetTaxClassesController,
+            },
         },
+        handler::idempotent_handler::IdempotentHandler,
     },
 };
 
@@ -70,14 +150,92 @@ pub async fn run(port: u16) -> Result<(), Box<dyn std::error::Error>> {
         .unwrap()
         .parse()
         .expect("DATABASE_URL must be a string");
+    let trash_retention_days: i64 = dotenv::var("TRASH_RETENTION_DAYS")
+        .unwrap_or_else(|_| "30".to_string())
+        .parse()
+        .expect("TRASH_RETENTION_DAYS must be a number");
+    let trash_purge_interval_secs: u64 = dotenv::var("TRASH_PURGE_INTERVAL_SECS")
+        .unwrap_or_else(|_| "3600".to_string())
+        .parse()
+        .expect("TRASH_PURGE_INTERVAL_SECS must be a number");
+    let idempotency_ttl_secs: i64 = dotenv::var("IDEMPOTENCY_TTL_SECS")
+        .unwrap_or_else(|_| "86400".to_string())
+        .parse()
+        .expect("IDEMPOTENCY_TTL_SECS must be a number");
+    let idempotency_purge_interval_secs: u64 = dotenv::var("IDEMPOTENCY_PURGE_INTERVAL_SECS")
+        .unwrap_or_else(|_| "3600".to_string())
+        .parse()
+        .expect("IDEMPOTENCY_PURGE_INTERVAL_SECS must be a number");
+    let application_expiry_interval_secs: u64 = dotenv::var("APPLICATION_EXPIRY_INTERVAL_SECS")
+        .unwrap_or_else(|_| "300".to_string())
+        .parse()
+        .expect("APPLICATION_EXPIRY_INTERVAL_SECS must be a number");
+    let uses_reconcile_interval_secs: u64 = dotenv::var("USES_RECONCILE_INTERVAL_SECS")
+        .unwrap_or_else(|_| "3600".to_string())
+        .parse()
+        .expect("USES_RECONCILE_INTERVAL_SECS must be a number");
+    let pricing_engine = PricingEngine {
+        stacking: StackingPolicy {
+            order: dotenv::var("DISCOUNT_STACKING_ORDER")
+                .unwrap_or_else(|_| "percent_first".to_string())
+                .parse()
+                .expect("DISCOUNT_STACKING_ORDER must be percent_first or priority"),
+            max_combined_bps: dotenv::var("MAX_COMBINED_DISCOUNT_BPS")
+                .unwrap_or_else(|_| "8000".to_string())
+                .parse()
+                .expect("MAX_COMBINED_DISCOUNT_BPS must be a number"),
+        },
+        rounding: dotenv::var("PRICE_ROUNDING")
+            .unwrap_or_else(|_| "down".to_string())
+            .parse()
+            .expect("PRICE_ROUNDING must be down, half_up or up"),
+        min_price: dotenv::var("MIN_PRICE")
+            .unwrap_or_else(|_| "1".to_string())
+            .parse()
+            .expect("MIN_PRICE must be a number"),
+        tax: TaxPolicy {
+            prices_include_tax: dotenv::var("PRICES_INCLUDE_TAX")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()
+                .expect("PRICES_INCLUDE_TAX must be true or false"),
+            discount_order: dotenv::var("DISCOUNT_TAX_ORDER")
+                .unwrap_or_else(|_| "before_tax".to_string())
+                .parse()
+                .expect("DISCOUNT_TAX_ORDER must be before_tax or after_tax"),
+        },
+    };
+    let rate_limit_policy = RateLimitPolicy::parse(
+        &dotenv::var("RATE_LIMITS").unwrap_or_default(),
+        &dotenv::var("TRUSTED_PROXIES").unwrap_or_default(),
+        &dotenv::var("RATE_LIMIT_API_KEYS").unwrap_or_default(),
+    )
+    .expect("RATE_LIMITS must be METHOD /path=requests/secs rules and TRUSTED_PROXIES addresses");
 
     let cors = CorsLayer::new()
         .allow_origin(Any)
-        .allow_methods(vec![Method::GET, Method::POST, Method::PUT, Method::DELETE])
+        .allow_methods(vec![
+            Method::GET,
+            Method::POST,
+            Method::PUT,
+            Method::PATCH,
+            Method::DELETE,
+        ])
         .allow_headers(vec![
             axum::http::header::AUTHORIZATION,
             axum::http::header::ACCEPT,
             axum::http::header::CONTENT_TYPE,
+            axum::http::header::IF_MATCH,
+            axum::http::HeaderName::from_static("idempotency-key"),
+            axum::http::HeaderName::from_static("x-customer-id"),
+            axum::http::HeaderName::from_static("x-api-key"),
+        ])
+        .expose_headers(vec![
+            axum::http::header::ETAG,
+            axum::http::header::RETRY_AFTER,
+            axum::http::HeaderName::from_static("ratelimit-limit"),
+            axum::http::HeaderName::from_static("ratelimit-remaining"),
+            axum::http::HeaderName::from_static("ratelimit-reset"),
+            axum::http::HeaderName::from_static("ratelimit-policy"),
         ])
         .max_age(Duration::from_secs(3601));
 
@@ -96,6 +254,11 @@ pub async fn run(port: u16) -> Result<(), Box<dyn std::error::Error>> {
     let postgres_product_repository = PostgresProductRepository::new(arc_pool.clone());
     let postgres_coupon_repository = PostgresCouponRepository::new(arc_pool.clone());
     let postgres_discount_repository = PostgresDiscountRepository::new(arc_pool.clone());
+    let postgres_idempotency_repository = PostgresIdempotencyRepository::new(arc_pool.clone());
+    let postgres_campaign_repository = PostgresCampaignRepository::new(arc_pool.clone());
+    let postgres_sale_event_repository = PostgresSaleEventRepository::new(arc_pool.clone());
+    let postgres_tax_class_repository = PostgresTaxClassRepository::new(arc_pool.clone());
+    let postgres_order_repository = PostgresOrderRepository::new(arc_pool.clone());
 
     /*
      * Repositories In Memory
@@ -112,6 +275,39 @@ pub async fn run(port: u16) -> Result<(), Box<dyn std::error::Error>> {
         Arc::new(RwLock::new(postgres_coupon_repository));
     let discount_repository: Arc<RwLock<dyn DiscountRepository + Send + Sync>> =
         Arc::new(RwLock::new(postgres_discount_repository));
+    let idempotency_repository: Arc<RwLock<dyn IdempotencyRepository + Send + Sync>> =
+        Arc::new(RwLock::new(postgres_idempotency_repository));
+    let campaign_repository: Arc<RwLock<dyn CampaignRepository + Send + Sync>> =
+        Arc::new(RwLock::new(postgres_campaign_repository));
+    let sale_event_repository: Arc<RwLock<dyn SaleEventRepository + Send + Sync>> =
+        Arc::new(RwLock::new(postgres_sale_event_repository));
+    let tax_class_repository: Arc<RwLock<dyn TaxClassRepository + Send + Sync>> =
+        Arc::new(RwLock::new(postgres_tax_class_repository));
+    let order_repository: Arc<RwLock<dyn OrderRepository + Send + Sync>> =
+        Arc::new(RwLock::new(postgres_order_repository));
+    let rate_limit_repository: Arc<RwLock<dyn RateLimitRepository + Send + Sync>> =
+        Arc::new(RwLock::new(InMemoryRateLimitRepository::new()));
+
+    /*
+     * Background Jobs
+     */
+    spawn_trash_purge_job(
+        product_repository.clone(),
+        trash_retention_days,
+        Duration::from_secs(trash_purge_interval_secs),
+    );
+    spawn_idempotency_purge_job(
+        idempotency_repository.clone(),
+        Duration::from_secs(idempotency_purge_interval_secs),
+    );
+    spawn_application_expiry_job(
+        discount_repository.clone(),
+        Duration::from_secs(application_expiry_interval_secs),
+    );
+    spawn_coupon_uses_reconcile_job(
+        coupon_repository.clone(),
+        Duration::from_secs(uses_reconcile_interval_secs),
+    );
 
     /*
      * Product Controllers
@@ -123,6 +319,9 @@ pub async fn run(port: u16) -> Result<(), Box<dyn std::error::Error>> {
         update_product_controller,
         delete_product_controller,
         restore_product_controller,
+        get_trash_products_controller,
+        purge_product_controller,
+        assign_tax_class_controller,
     ) = (
         Arc::new(CreateProductController {
             product_repository: product_repository.clone(),
@@ -130,9 +329,16 @@ pub async fn run(port: u16) -> Result<(), Box<dyn std::error::Error>> {
         Arc::new(GetProductController {
             product_repository: product_repository.clone(),
             discount_repository: discount_repository.clone(),
+            sale_event_repository: sale_event_repository.clone(),
+            tax_class_repository: tax_class_repository.clone(),
+            pricing_engine,
         }),
         Arc::new(GetAllProductsController {
             product_repository: product_repository.clone(),
+            discount_repository: discount_repository.clone(),
+            sale_event_repository: sale_event_repository.clone(),
+            tax_class_repository: tax_class_repository.clone(),
+            pricing_engine,
         }),
         Arc::new(UpdateProductController {
             product_repository: product_repository.clone(),
@@ -143,6 +349,16 @@ pub async fn run(port: u16) -> Result<(), Box<dyn std::error::Error>> {
         Arc::new(RestoreProductController {
             product_repository: product_repository.clone(),
         }),
+        Arc::new(GetTrashProductsController {
+            product_repository: product_repository.clone(),
+        }),
+        Arc::new(PurgeProductController {
+            product_repository: product_repository.clone(),
+        }),
+        Arc::new(AssignTaxClassController {
+            product_repository: product_repository.clone(),
+            tax_class_repository: tax_class_repository.clone(),
+        }),
     );
 
     /*
@@ -154,6 +370,10 @@ pub async fn run(port: u16) -> Result<(), Box<dyn std::error::Error>> {
         get_coupons_controller,
         update_coupon_controller,
         delete_coupon_controller,
+        get_coupon_redemptions_controller,
+        get_coupon_applications_controller,
+        batch_generate_coupons_controller,
+        validate_coupon_controller,
     ) = (
         Arc::new(CreateCouponController {
             coupon_repository: coupon_repository.clone(),
@@ -170,6 +390,135 @@ pub async fn run(port: u16) -> Result<(), Box<dyn std::error::Error>> {
         Arc::new(DeleteCouponController {
             coupon_repository: coupon_repository.clone(),
         }),
+        Arc::new(GetCouponRedemptionsController {
+            coupon_repository: coupon_repository.clone(),
+        }),
+        Arc::new(GetCouponApplicationsController {
+            discount_repository: discount_repository.clone(),
+        }),
+        Arc::new(BatchGenerateCouponsController {
+            coupon_repository: coupon_repository.clone(),
+        }),
+        Arc::new(ValidateCouponController {
+            coupon_repository: coupon_repository.clone(),
+            product_repository: product_repository.clone(),
+            discount_repository: discount_repository.clone(),
+            campaign_repository: campaign_repository.clone(),
+            sale_event_repository: sale_event_repository.clone(),
+            tax_class_repository: tax_class_repository.clone(),
+            pricing_engine,
+        }),
+    );
+
+    /*
+     * Campaign Controllers
+     */
+    let (create_campaign_controller, get_campaign_controller, get_campaigns_controller) = (
+        Arc::new(CreateCampaignController {
+            campaign_repository: campaign_repository.clone(),
+        }),
+        Arc::new(GetCampaignController {
+            campaign_repository: campaign_repository.clone(),
+        }),
+        Arc::new(GetCampaignsController {
+            campaign_repository: campaign_repository.clone(),
+        }),
+    );
+
+    /*
+     * Sale Event Controllers
+     */
+    let (
+        create_sale_event_controller,
+        get_sale_event_controller,
+        preview_sale_event_controller,
+        cancel_sale_event_controller,
+    ) = (
+        Arc::new(CreateSaleEventController {
+            sale_event_repository: sale_event_repository.clone(),
+        }),
+        Arc::new(GetSaleEventController {
+            sale_event_repository: sale_event_repository.clone(),
+        }),
+        Arc::new(PreviewSaleEventController {
+            sale_event_repository: sale_event_repository.clone(),
+            product_repository: product_repository.clone(),
+            pricing_engine,
+        }),
+        Arc::new(CancelSaleEventController {
+            sale_event_repository: sale_event_repository.clone(),
+        }),
+    );
+
+    /*
+     * Quote Controllers
+     */
+    let create_quote_controller = Arc::new(CreateQuoteController {
+        product_repository: product_repository.clone(),
+        coupon_repository: coupon_repository.clone(),
+        discount_repository: discount_repository.clone(),
+        campaign_repository: campaign_repository.clone(),
+        sale_event_repository: sale_event_repository.clone(),
+        tax_class_repository: tax_class_repository.clone(),
+        pricing_engine,
+    });
+
+    /*
+     * Order Controllers
+     */
+    let (
+        create_order_controller,
+        get_order_controller,
+        get_orders_controller,
+        pay_order_controller,
+        fulfill_order_controller,
+        cancel_order_controller,
+        refund_order_controller,
+    ) = (
+        Arc::new(CreateOrderController {
+            order_repository: order_repository.clone(),
+            product_repository: product_repository.clone(),
+            coupon_repository: coupon_repository.clone(),
+            discount_repository: discount_repository.clone(),
+            campaign_repository: campaign_repository.clone(),
+            sale_event_repository: sale_event_repository.clone(),
+            tax_class_repository: tax_class_repository.clone(),
+            pricing_engine,
+        }),
+        Arc::new(GetOrderController {
+            order_repository: order_repository.clone(),
+        }),
+        Arc::new(GetOrdersController {
+            order_repository: order_repository.clone(),
+        }),
+        Arc::new(TransitionOrderController {
+            order_repository: order_repository.clone(),
+            action: OrderAction::Pay,
+        }),
+        Arc::new(TransitionOrderController {
+            order_repository: order_repository.clone(),
+            action: OrderAction::Fulfill,
+        }),
+        Arc::new(TransitionOrderController {
+            order_repository: order_repository.clone(),
+            action: OrderAction::Cancel,
+        }),
+        Arc::new(TransitionOrderController {
+            order_repository: order_repository.clone(),
+            action: OrderAction::Refund,
+        }),
+    );
+
+    /*
+     * Tax Class Controllers
+     */
+    let (create_tax_class_controller, get_tax_classes_controller) = (
+        Arc::new(CreateTaxClassController {
+            tax_class_repository: tax_class_repository.clone(),
+        }),
+        Arc::new(GetTaxClassesController {
+            tax_class_repository: tax_class_repository.clone(),
+        }),
     );
 
     /*
@@ -179,16 +528,22 @@ pub async fn run(port: u16) -> Result<(), Box<dyn std::error::Error>> {
         apply_percent_discount_controller,
         apply_coupon_discount_controller,
         remove_discount_controller,
+        get_product_discounts_controller,
     ) = (
         Arc::new(ApplyPercentDiscountController {
             discount_repository: discount_repository.clone(),
+            pricing_engine,
         }),
         Arc::new(ApplyCouponDiscountController {
             discount_repository: discount_repository.clone(),
+            pricing_engine,
         }),
         Arc::new(RemoveDiscountController {
             discount_repository: discount_repository.clone(),
         }),
+        Arc::new(GetProductDiscountsController {
+            discount_repository: discount_repository.clone(),
+        }),
     );
 
     /*
@@ -201,9 +556,17 @@ pub async fn run(port: u16) -> Result<(), Box<dyn std::error::Error>> {
         make_update_product_by_id_handler,
         make_delete_product_by_id_handler,
         make_restore_product_by_id_handler,
+        make_get_trash_products_handler,
+        make_purge_product_by_id_handler,
+        make_assign_tax_class_handler,
     ) = (
         Arc::new(AxumHandler {
-            inner: create_product_controller,
+            inner: Arc::new(IdempotentHandler {
+                inner: create_product_controller,
+                idempotency_repository: idempotency_repository.clone(),
+                scope: "POST /api/v1/products".to_string(),
+                ttl_secs: idempotency_ttl_secs,
+            }),
         }),
         Arc::new(AxumHandler {
             inner: get_product_controller,
@@ -220,6 +583,15 @@ pub async fn run(port: u16) -> Result<(), Box<dyn std::error::Error>> {
         Arc::new(AxumHandler {
             inner: restore_product_controller,
         }),
+        Arc::new(AxumHandler {
+            inner: get_trash_products_controller,
+        }),
+        Arc::new(AxumHandler {
+            inner: purge_product_controller,
+        }),
+        Arc::new(AxumHandler {
+            inner: assign_tax_class_controller,
+        }),
     );
 
     /*
@@ -231,9 +603,18 @@ pub async fn run(port: u16) -> Result<(), Box<dyn std::error::Error>> {
         make_get_coupons_handler,
         make_update_coupon_by_id_handler,
         make_delete_coupon_by_id_handler,
+        make_get_coupon_redemptions_handler,
+        make_get_coupon_applications_handler,
+        make_batch_generate_coupons_handler,
+        make_validate_coupon_handler,
     ) = (
         Arc::new(AxumHandler {
-            inner: create_coupon_controller,
+            inner: Arc::new(IdempotentHandler {
+                inner: create_coupon_controller,
+                idempotency_repository: idempotency_repository.clone(),
+                scope: "POST /api/v1/coupons".to_string(),
+                ttl_secs: idempotency_ttl_secs,
+            }),
         }),
         Arc::new(AxumHandler {
             inner: get_coupon_controller,
@@ -247,6 +628,115 @@ pub async fn run(port: u16) -> Result<(), Box<dyn std::error::Error>> {
         Arc::new(AxumHandler {
             inner: delete_coupon_controller,
         }),
+        Arc::new(AxumHandler {
+            inner: get_coupon_redemptions_controller,
+        }),
+        Arc::new(AxumHandler {
+            inner: get_coupon_applications_controller,
+        }),
+        Arc::new(AxumHandler {
+            inner: batch_generate_coupons_controller,
+        }),
+        Arc::new(AxumHandler {
+            inner: validate_coupon_controller,
+        }),
+    );
+
+    /*
+     * Campaign Generic Handlers
+     */
+    let (make_create_campaign_handler, make_get_campaign_by_id_handler, make_get_campaigns_handler) = (
+        Arc::new(AxumHandler {
+            inner: create_campaign_controller,
+        }),
+        Arc::new(AxumHandler {
+            inner: get_campaign_controller,
+        }),
+        Arc::new(AxumHandler {
+            inner: get_campaigns_controller,
+        }),
+    );
+
+    /*
+     * Sale Event Generic Handlers
+     */
+    let (
+        make_create_sale_event_handler,
+        make_get_sale_event_by_id_handler,
+        make_preview_sale_event_handler,
+        make_cancel_sale_event_handler,
+    ) = (
+        Arc::new(AxumHandler {
+            inner: create_sale_event_controller,
+        }),
+        Arc::new(AxumHandler {
+            inner: get_sale_event_controller,
+        }),
+        Arc::new(AxumHandler {
+            inner: preview_sale_event_controller,
+        }),
+        Arc::new(AxumHandler {
+            inner: cancel_sale_event_controller,
+        }),
+    );
+
+    /*
+     * Quote Generic Handlers
+     */
+    let make_create_quote_handler = Arc::new(AxumHandler {
+        inner: create_quote_controller,
+    });
+
+    /*
+     * Order Generic Handlers
+     */
+    let (
+        make_create_order_handler,
+        make_get_order_by_id_handler,
+        make_get_orders_handler,
+        make_pay_order_handler,
+        make_fulfill_order_handler,
+        make_cancel_order_handler,
+        make_refund_order_handler,
+    ) = (
+        Arc::new(AxumHandler {
+            inner: Arc::new(IdempotentHandler {
+                inner: create_order_controller,
+                idempotency_repository: idempotency_repository.clone(),
+                scope: "POST /api/v1/orders".to_string(),
+                ttl_secs: idempotency_ttl_secs,
+            }),
+        }),
+        Arc::new(AxumHandler {
+            inner: get_order_controller,
+        }),
+        Arc::new(AxumHandler {
+            inner: get_orders_controller,
+        }),
+        Arc::new(AxumHandler {
+            inner: pay_order_controller,
+        }),
+        Arc::new(AxumHandler {
+            inner: fulfill_order_controller,
+        }),
+        Arc::new(AxumHandler {
+            inner: cancel_order_controller,
+        }),
+        Arc::new(AxumHandler {
+            inner: refund_order_controller,
+        }),
+    );
+
+    /*
+     * Tax Class Generic Handlers
+     */
+    let (make_create_tax_class_handler, make_get_tax_classes_handler) = (
+        Arc::new(AxumHandler {
+            inner: create_tax_class_controller,
+        }),
+        Arc::new(AxumHandler {
+            inner: get_tax_classes_controller,
+        }),
     );
 
     /*
@@ -256,38 +746,62 @@ pub async fn run(port: u16) -> Result<(), Box<dyn std::error::Error>> {
         make_apply_percent_discount_handler,
         make_apply_coupon_discount_handler,
         make_remove_discount_handler,
+        make_get_product_discounts_handler,
     ) = (
         Arc::new(AxumHandler {
             inner: apply_percent_discount_controller,
         }),
         Arc::new(AxumHandler {
-            inner: apply_coupon_discount_controller,
+            inner: Arc::new(IdempotentHandler {
+                inner: apply_coupon_discount_controller,
+                idempotency_repository: idempotency_repository.clone(),
+                scope: "POST /api/v1/products/:id/discount/coupon".to_string(),
+                ttl_secs: idempotency_ttl_secs,
+            }),
         }),
         Arc::new(AxumHandler {
             inner: remove_discount_controller,
         }),
+        Arc::new(AxumHandler {
+            inner: get_product_discounts_controller,
+        }),
     );
 
     /*
      * Product Routes (Axum Adapters)
      */
-    let create_product_route =
-        move |body: Bytes| create_product_handler(make_create_product_handler.clone(), body);
+    let create_product_route = move |headers: HeaderMap, body: Bytes| {
+        create_product_handler(make_create_product_handler.clone(), headers, body)
+    };
     let get_product_route = move |param: Path<String>| {
         get_product_by_id_handler(make_get_product_by_id_handler.clone(), param)
     };
     let get_all_products_route = move |query: Query<Value>| {
         get_all_products_handler(make_get_all_products_handler.clone(), query)
     };
-    let update_product_route = move |param: Path<String>, body: Bytes| {
-        update_product_by_id_handler(make_update_product_by_id_handler.clone(), param, body)
+    let update_product_route = move |param: Path<String>, headers: HeaderMap, body: Bytes| {
+        update_product_by_id_handler(
+            make_update_product_by_id_handler.clone(),
+            param,
+            headers,
+            body,
+        )
     };
-    let delete_product_route = move |param: Path<String>| {
-        delete_product_by_id_handler(make_delete_product_by_id_handler.clone(), param)
+    let delete_product_route = move |param: Path<String>, headers: HeaderMap| {
+        delete_product_by_id_handler(make_delete_product_by_id_handler.clone(), param, headers)
     };
     let restore_product_route = move |param: Path<String>| {
         restore_product_by_id_handler(make_restore_product_by_id_handler.clone(), param)
     };
+    let get_trash_products_route = move |query: Query<Value>| {
+        get_trash_products_handler(make_get_trash_products_handler.clone(), query)
+    };
+    let purge_product_route = move |param: Path<String>| {
+        purge_product_by_id_handler(make_purge_product_by_id_handler.clone(), param)
+    };
+    let assign_tax_class_route = move |param: Path<String>, body: Bytes| {
+        assign_tax_class_handler(make_assign_tax_class_handler.clone(), param, body)
+    };
 
     /*
      * Discount Routes (Axum Adapters)
@@ -295,37 +809,136 @@ pub async fn run(port: u16) -> Result<(), Box<dyn std::error::Error>> {
     let apply_percent_discount_route = move |param: Path<String>, body: Bytes| {
         apply_percent_discount_handler(make_apply_percent_discount_handler.clone(), param, body)
     };
-    let apply_coupon_discount_route = move |param: Path<String>, body: Bytes| {
-        apply_coupon_discount_handler(make_apply_coupon_discount_handler.clone(), param, body)
-    };
+    let apply_coupon_discount_route =
+        move |param: Path<String>, headers: HeaderMap, body: Bytes| {
+            apply_coupon_discount_handler(
+                make_apply_coupon_discount_handler.clone(),
+                param,
+                headers,
+                body,
+            )
+        };
     let remove_discount_route = move |param: Path<String>, body: Bytes| {
         remove_discount_handler(make_remove_discount_handler.clone(), param, body)
     };
+    let get_product_discounts_route = move |param: Path<String>, query: Query<Value>| {
+        get_product_discounts_handler(make_get_product_discounts_handler.clone(), param, query)
+    };
 
     /*
      * Coupons Routes (Axum Adapters)
      */
-    let create_coupon_route =
-        move |body: Bytes| create_coupon_handler(make_create_coupon_handler.clone(), body);
+    let create_coupon_route = move |headers: HeaderMap, body: Bytes| {
+        create_coupon_handler(make_create_coupon_handler.clone(), headers, body)
+    };
     let get_coupon_route = move |param: Path<String>| {
         get_coupon_by_id_handler(make_get_coupon_by_id_handler.clone(), param)
     };
     let list_coupons_route =
         move |query: Query<Value>| get_coupons_handler(make_get_coupons_handler.clone(), query);
-    let update_coupon_route = move |param: Path<String>, body: Bytes| {
-        update_coupon_by_id_handler(make_update_coupon_by_id_handler.clone(), param, body)
+    let update_coupon_route = move |param: Path<String>, headers: HeaderMap, body: Bytes| {
+        update_coupon_by_id_handler(
+            make_update_coupon_by_id_handler.clone(),
+            param,
+            headers,
+            body,
+        )
+    };
+    let delete_coupon_route = move |param: Path<String>, headers: HeaderMap| {
+        delete_coupon_by_id_handler(make_delete_coupon_by_id_handler.clone(), param, headers)
     };
-    let delete_coupon_route = move |param: Path<String>| {
-        delete_coupon_by_id_handler(make_delete_coupon_by_id_handler.clone(), param)
+
+    let get_coupon_redemptions_route = move |param: Path<String>, query: Query<Value>| {
+        get_coupon_redemptions_handler(make_get_coupon_redemptions_handler.clone(), param, query)
+    };
+    let get_coupon_applications_route = move |param: Path<String>, query: Query<Value>| {
+        get_coupon_applications_handler(make_get_coupon_applications_handler.clone(), param, query)
+    };
+    let validate_coupon_route = move |param: Path<String>, headers: HeaderMap, body: Bytes| {
+        validate_coupon_handler(make_validate_coupon_handler.clone(), param, headers, body)
+    };
+    let batch_generate_coupons_route = move |body: Bytes| {
+        batch_generate_coupons_handler(make_batch_generate_coupons_handler.clone(), body)
     };
 
+    /*
+     * Campaign Routes (Axum Adapters)
+     */
+    let create_campaign_route =
+        move |body: Bytes| create_campaign_handler(make_create_campaign_handler.clone(), body);
+    let get_campaign_route = move |param: Path<String>| {
+        get_campaign_by_id_handler(make_get_campaign_by_id_handler.clone(), param)
+    };
+    let list_campaigns_route =
+        move |query: Query<Value>| get_campaigns_handler(make_get_campaigns_handler.clone(), query);
+
+    /*
+     * Sale Event Routes (Axum Adapters)
+     */
+    let create_sale_event_route =
+        move |body: Bytes| create_sale_event_handler(make_create_sale_event_handler.clone(), body);
+    let get_sale_event_route = move |param: Path<String>| {
+        get_sale_event_by_id_handler(make_get_sale_event_by_id_handler.clone(), param)
+    };
+    let preview_sale_event_route = move |param: Path<String>, query: Query<Value>| {
+        preview_sale_event_handler(make_preview_sale_event_handler.clone(), param, query)
+    };
+    let cancel_sale_event_route = move |param: Path<String>| {
+        cancel_sale_event_handler(make_cancel_sale_event_handler.clone(), param)
+    };
+
+    /*
+     * Quote Routes (Axum Adapters)
+     */
+    let create_quote_route = move |headers: HeaderMap, body: Bytes| {
+        create_quote_handler(make_create_quote_handler.clone(), headers, body)
+    };
+
+    /*
+     * Order Routes (Axum Adapters)
+     */
+    let create_order_route = move |headers: HeaderMap, body: Bytes| {
+        create_order_handler(make_create_order_handler.clone(), headers, body)
+    };
+    let get_order_route = move |param: Path<String>| {
+        get_order_by_id_handler(make_get_order_by_id_handler.clone(), param)
+    };
+    let list_orders_route =
+        move |query: Query<Value>| get_orders_handler(make_get_orders_handler.clone(), query);
+    let pay_order_route = move |param: Path<String>, body: Bytes| {
+        transition_order_handler(make_pay_order_handler.clone(), param, body)
+    };
+    let fulfill_order_route = move |param: Path<String>, body: Bytes| {
+        transition_order_handler(make_fulfill_order_handler.clone(), param, body)
+    };
+    let cancel_order_route = move |param: Path<String>, body: Bytes| {
+        transition_order_handler(make_cancel_order_handler.clone(), param, body)
+    };
+    let refund_order_route = move |param: Path<String>, body: Bytes| {
+        transition_order_handler(make_refund_order_handler.clone(), param, body)
+    };
+
+    /*
+     * Tax Class Routes (Axum Adapters)
+     */
+    let create_tax_class_route =
+        move |body: Bytes| create_tax_class_handler(make_create_tax_class_handler.clone(), body);
+    let list_tax_classes_route =
+        move || get_tax_classes_handler(make_get_tax_classes_handler.clone());
+
     let api_routes = Router::new()
         .route("/api/v1/products", post(create_product_route))
         .route("/api/v1/products", get(get_all_products_route))
+        .route("/api/v1/products/trash", get(get_trash_products_route))
         .route("/api/v1/products/:id", get(get_product_route))
         .route("/api/v1/products/:id", patch(update_product_route))
         .route("/api/v1/products/:id", delete(delete_product_route))
         .route("/api/v1/products/:id/restore", post(restore_product_route))
+        .route("/api/v1/products/:id/purge", delete(purge_product_route))
+        .route(
+            "/api/v1/products/:id/tax-class",
+            put(assign_tax_class_route),
+        )
         .route(
             "/api/v1/products/:id/discount/percent",
             post(apply_percent_discount_route),
@@ -338,18 +951,75 @@ pub async fn run(port: u16) -> Result<(), Box<dyn std::error::Error>> {
             "/api/v1/products/:id/discount",
             delete(remove_discount_route),
         )
+        .route(
+            "/api/v1/products/:id/discounts",
+            get(get_product_discounts_route),
+        )
         .route("/api/v1/coupons", post(create_coupon_route))
         .route("/api/v1/coupons", get(list_coupons_route))
+        .route(
+            "/api/v1/coupons/batch-generate",
+            post(batch_generate_coupons_route),
+        )
         .route("/api/v1/coupons/:code", get(get_coupon_route))
         .route("/api/v1/coupons/:code", patch(update_coupon_route))
         .route("/api/v1/coupons/:code", delete(delete_coupon_route))
-        .route("/api/v1/health", get(health_check));
+        .route(
+            "/api/v1/coupons/:code/validate",
+            post(validate_coupon_route),
+        )
+        .route(
+            "/api/v1/coupons/:code/redemptions",
+            get(get_coupon_redemptions_route),
+        )
+        .route(
+            "/api/v1/coupons/:code/applications",
+            get(get_coupon_applications_route),
+        )
+        .route("/api/v1/campaigns", post(create_campaign_route))
+        .route("/api/v1/campaigns", get(list_campaigns_route))
+        .route("/api/v1/campaigns/:id", get(get_campaign_route))
+        .route("/api/v1/sale-events", post(create_sale_event_route))
+        .route("/api/v1/sale-events/:id", get(get_sale_event_route))
+        .route(
+            "/api/v1/sale-events/:id/products",
+            get(preview_sale_event_route),
+        )
+        .route(
+            "/api/v1/sale-events/:id/cancel",
+            post(cancel_sale_event_route),
+        )
+        .route("/api/v1/quotes", post(create_quote_route))
+        .route("/api/v1/orders", post(create_order_route))
+        .route("/api/v1/orders", get(list_orders_route))
+        .route("/api/v1/orders/:id", get(get_order_route))
+        .route("/api/v1/orders/:id/pay", post(pay_order_route))
+        .route("/api/v1/orders/:id/fulfill", post(fulfill_order_route))
+        .route("/api/v1/orders/:id/cancel", post(cancel_order_route))
+        .route("/api/v1/orders/:id/refund", post(refund_order_route))
+        .route("/api/v1/tax-classes", post(create_tax_class_route))
+        .route("/api/v1/tax-classes", get(list_tax_classes_route))
+        .route("/api/v1/health", get(health_check))
+        .route_layer(RateLimitLayer::new(
+            rate_limit_policy,
+            rate_limit_repository,
+        ));
 
-    let app = Router::new().merge(api_routes).layer(cors);
+    let app = Router::new()
+        .merge(api_routes)
+        .layer(middleware::from_fn(negotiate))
+        .layer(CatchPanicLayer::custom(panic_response))
+        .layer(middleware::from_fn(problem))
+        .layer(cors);
 
     let addr = SocketAddr::from(([0, 0, 0, 0], port));
     let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
-    axum::serve(listener, app).await.unwrap();
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+    .unwrap();
     Ok(())
 }
 
@@ -0,0 +1,47 @@
+use std::{sync::Arc, time::Duration};
+use tokio::{sync::RwLock, task::JoinHandle};
+
+use crate::domain::{
+    entity::discount_entity::RemovalReason, repository::discount_repository::DiscountRepository,
+    usecase::discount::close_dead_applications_usecase::CloseDeadApplicationsUseCase,
+};
+
+/// Periodically closes coupon applications whose coupon has expired or been
+/// deleted, so they stop counting as active discounts.
+pub fn spawn_application_expiry_job(
+    discount_repository: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+    every: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let usecase = CloseDeadApplicationsUseCase::new(discount_repository);
+        let mut interval = tokio::time::interval(every);
+
+        loop {
+            interval.tick().await;
+            match usecase.execute().await {
+                Ok(closed) if closed.is_empty() => {}
+                Ok(closed) => {
+                    let expired = closed
+                        .iter()
+                        .filter(|a| a.removal_reason == Some(RemovalReason::Expired))
+                        .count();
+                    log::info!(
+                        "Closed {} coupon application(s): {} expired, {} coupon_deleted",
+                        closed.len(),
+                        expired,
+                        closed.len() - expired
+                    );
+                    for application in &closed {
+                        log::debug!(
+                            "Closed application {} (product {}, coupon {})",
+                            application.id,
+                            application.product_id,
+                            application.coupon_id
+                        );
+                    }
+                }
+                Err(e) => log::error!("Failed to close dead coupon applications: {}", e),
+            }
+        }
+    })
+}
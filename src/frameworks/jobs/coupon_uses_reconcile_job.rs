@@ -0,0 +1,28 @@
+use std::{sync::Arc, time::Duration};
+use tokio::{sync::RwLock, task::JoinHandle};
+
+use crate::domain::{
+    repository::coupon_repository::CouponRepository,
+    usecase::coupon::reconcile_coupon_uses_usecase::ReconcileCouponUsesUseCase,
+};
+
+/// Periodically brings each coupon's `uses_count` back in line with its
+/// redemption ledger, which is the source of truth.
+pub fn spawn_coupon_uses_reconcile_job(
+    coupon_repository: Arc<RwLock<dyn CouponRepository + Send + Sync>>,
+    every: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let usecase = ReconcileCouponUsesUseCase::new(coupon_repository);
+        let mut interval = tokio::time::interval(every);
+
+        loop {
+            interval.tick().await;
+            match usecase.execute().await {
+                Ok(0) => {}
+                Ok(corrected) => log::warn!("Reconciled uses_count of {} coupon(s)", corrected),
+                Err(e) => log::error!("Failed to reconcile coupon uses: {}", e),
+            }
+        }
+    })
+}
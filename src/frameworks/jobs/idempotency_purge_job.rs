@@ -0,0 +1,27 @@
+use std::{sync::Arc, time::Duration};
+use tokio::{sync::RwLock, task::JoinHandle};
+
+use crate::domain::{
+    repository::idempotency_repository::IdempotencyRepository,
+    usecase::idempotency::purge_expired_idempotency_keys_usecase::PurgeExpiredIdempotencyKeysUseCase,
+};
+
+/// Periodically deletes stored idempotent responses whose TTL has elapsed.
+pub fn spawn_idempotency_purge_job(
+    idempotency_repository: Arc<RwLock<dyn IdempotencyRepository + Send + Sync>>,
+    every: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let usecase = PurgeExpiredIdempotencyKeysUseCase::new(idempotency_repository);
+        let mut interval = tokio::time::interval(every);
+
+        loop {
+            interval.tick().await;
+            match usecase.execute().await {
+                Ok(0) => {}
+                Ok(purged) => log::info!("Purged {} expired idempotency key(s)", purged),
+                Err(e) => log::error!("Failed to purge idempotency keys: {}", e),
+            }
+        }
+    })
+}
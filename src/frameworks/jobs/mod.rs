@@ -0,0 +1,4 @@
+pub mod application_expiry_job;
+pub mod coupon_uses_reconcile_job;
+pub mod idempotency_purge_job;
+pub mod trash_purge_job;
@@ -0,0 +1,29 @@
+use std::{sync::Arc, time::Duration};
+use tokio::{sync::RwLock, task::JoinHandle};
+
+use crate::domain::{
+    repository::product_repository::ProductRepository,
+    usecase::product::purge_expired_products_usecase::PurgeExpiredProductsUseCase,
+};
+
+/// Periodically hard-deletes products that have been in the trash for longer
+/// than `retention_days`.
+pub fn spawn_trash_purge_job(
+    product_repository: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+    retention_days: i64,
+    every: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let usecase = PurgeExpiredProductsUseCase::new(product_repository);
+        let mut interval = tokio::time::interval(every);
+
+        loop {
+            interval.tick().await;
+            match usecase.execute(retention_days).await {
+                Ok(0) => {}
+                Ok(purged) => log::info!("Purged {} product(s) from trash", purged),
+                Err(e) => log::error!("Failed to purge trash: {}", e),
+            }
+        }
+    })
+}
@@ -1,3 +1,4 @@
 pub mod adapter;
 pub mod axum;
+pub mod jobs;
 pub mod sqlx;
@@ -0,0 +1,86 @@
+use async_trait::async_trait;
+use serde_json::{from_value, json};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        entity::campaign_entity::CreateCampaign,
+        repository::campaign_repository::CampaignRepository,
+        usecase::campaign::create_campaign_usecase::CreateCampaignUseCase,
+    },
+    interfaces::handler::{
+        generic_handler::{AdapterRequest, AdapterResponse, GenericHandler, StatusCode},
+        validation::{is_validation_error, validation_response},
+    },
+};
+
+#[derive(Clone)]
+pub struct CreateCampaignController {
+    pub campaign_repository: Arc<RwLock<dyn CampaignRepository + Send + Sync>>,
+}
+
+#[async_trait]
+impl GenericHandler for CreateCampaignController {
+    async fn handle(&self, request: AdapterRequest) -> AdapterResponse {
+        log::info!("Start request");
+
+        let body = match request.body {
+            Some(body) if body.is_object() => body,
+            _ => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing campaign data"}),
+                    binary: None,
+                };
+            }
+        };
+
+        let campaign: CreateCampaign = match from_value(body) {
+            Ok(campaign) => campaign,
+            Err(e) => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Invalid field types", "details": e.to_string()}),
+                    binary: None,
+                };
+            }
+        };
+
+        let usecase = CreateCampaignUseCase::new(self.campaign_repository.clone());
+        let response = usecase.execute(campaign).await;
+
+        log::info!("End request");
+
+        match response {
+            Ok(campaign) => match serde_json::to_value(campaign) {
+                Ok(json_value) => AdapterResponse {
+                    status: StatusCode::Created,
+                    data: json_value,
+                    binary: None,
+                },
+                Err(_) => AdapterResponse {
+                    status: StatusCode::InternalServerError,
+                    data: json!({"error": "Failed to serialize campaign"}),
+                    binary: None,
+                },
+            },
+            Err(e) if is_validation_error(&e) => validation_response(&e),
+            Err(e) if e == "Campaign not found" => AdapterResponse {
+                status: StatusCode::NotFound,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(e) if e == "Campaign already exists" => AdapterResponse {
+                status: StatusCode::Conflict,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(_) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": "Internal server error"}),
+                binary: None,
+            },
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        repository::campaign_repository::CampaignRepository,
+        usecase::campaign::get_campaign_usecase::GetCampaignUseCase,
+    },
+    interfaces::handler::generic_handler::{
+        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    },
+};
+
+#[derive(Clone)]
+pub struct GetCampaignController {
+    pub campaign_repository: Arc<RwLock<dyn CampaignRepository + Send + Sync>>,
+}
+
+#[async_trait]
+impl GenericHandler for GetCampaignController {
+    async fn handle(&self, request: AdapterRequest) -> AdapterResponse {
+        log::info!("Start request");
+        let id = match request.params.and_then(|p| p.get("id").cloned()) {
+            Some(Value::String(id)) => id,
+            _ => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing or invalid campaign id"}),
+                    binary: None,
+                };
+            }
+        };
+
+        let usecase = GetCampaignUseCase::new(self.campaign_repository.clone());
+        let response = usecase.execute(id).await;
+
+        log::info!("End request");
+
+        match response {
+            Ok(campaign) => match serde_json::to_value(campaign) {
+                Ok(json_value) => AdapterResponse {
+                    status: StatusCode::Ok,
+                    data: json_value,
+                    binary: None,
+                },
+                Err(_) => AdapterResponse {
+                    status: StatusCode::InternalServerError,
+                    data: json!({"error": "Failed to serialize campaign"}),
+                    binary: None,
+                },
+            },
+            Err(e) if e == "Campaign not found" => AdapterResponse {
+                status: StatusCode::NotFound,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(_) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": "Internal server error"}),
+                binary: None,
+            },
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        entity::campaign_entity::CampaignStatus,
+        repository::campaign_repository::CampaignRepository,
+        usecase::campaign::get_campaigns_usecase::GetCampaignsUseCase,
+    },
+    interfaces::handler::generic_handler::{
+        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    },
+};
+
+#[derive(Clone)]
+pub struct GetCampaignsController {
+    pub campaign_repository: Arc<RwLock<dyn CampaignRepository + Send + Sync>>,
+}
+
+#[async_trait]
+impl GenericHandler for GetCampaignsController {
+    async fn handle(&self, request: AdapterRequest) -> AdapterResponse {
+        log::info!("Start request");
+        fn get_param<T: serde::de::DeserializeOwned>(query: &Value, key: &str, default: T) -> T {
+            query
+                .get(key)
+                .and_then(|v| serde_json::from_value(v.clone()).ok())
+                .unwrap_or(default)
+        }
+
+        let query = request.query.unwrap_or_else(|| json!({}));
+
+        let page: u32 = get_param(&query, "page", 1).max(1);
+        let limit: u32 = get_param(&query, "limit", 10).clamp(1, 50);
+        let status: Option<CampaignStatus> = match query.get("status") {
+            None | Some(Value::Null) => None,
+            Some(value) => match serde_json::from_value(value.clone()) {
+                Ok(status) => Some(status),
+                Err(_) => {
+                    return AdapterResponse {
+                        status: StatusCode::BadRequest,
+                        data: json!({"error": "Invalid campaign status"}),
+                        binary: None,
+                    };
+                }
+            },
+        };
+
+        let usecase = GetCampaignsUseCase::new(self.campaign_repository.clone());
+        let response = usecase.execute(page, limit, status).await;
+
+        log::info!("End request");
+
+        match response {
+            Ok(campaigns) => match serde_json::to_value(campaigns) {
+                Ok(json_value) => AdapterResponse {
+                    status: StatusCode::Ok,
+                    data: json_value,
+                    binary: None,
+                },
+                Err(_) => AdapterResponse {
+                    status: StatusCode::InternalServerError,
+                    data: json!({"error": "Failed to serialize campaigns"}),
+                    binary: None,
+                },
+            },
+            Err(e) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": format!("Failed to fetch campaigns: {}", e)}),
+                binary: None,
+            },
+        }
+    }
+}
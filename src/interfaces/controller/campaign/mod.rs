@@ -0,0 +1,3 @@
+pub mod create_campaign_controller;
+pub mod get_campaign_controller;
+pub mod get_campaigns_controller;
@@ -0,0 +1,114 @@
+use async_trait::async_trait;
+use serde_json::{from_value, json};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        entity::coupon_entity::{Coupon, CouponTemplate},
+        repository::coupon_repository::CouponRepository,
+        usecase::coupon::batch_generate_coupons_usecase::BatchGenerateCouponsUseCase,
+    },
+    interfaces::handler::generic_handler::{
+        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    },
+};
+
+#[derive(Clone)]
+pub struct BatchGenerateCouponsController {
+    pub coupon_repository: Arc<RwLock<dyn CouponRepository + Send + Sync>>,
+}
+
+#[derive(serde::Deserialize)]
+struct BatchGenerateDTO {
+    template: CouponTemplate,
+    count: u32,
+    #[serde(default)]
+    prefix: String,
+    #[serde(default = "default_code_length")]
+    code_length: usize,
+}
+
+fn default_code_length() -> usize {
+    8
+}
+
+fn to_csv(coupons: &[Coupon]) -> Vec<u8> {
+    let mut csv = String::from("code,type,value,valid_from,valid_until,max_uses\n");
+    for coupon in coupons {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            coupon.code,
+            coupon.coupon_type,
+            coupon.value,
+            coupon.valid_from.to_rfc3339(),
+            coupon.valid_until.to_rfc3339(),
+            coupon.max_uses.map(|m| m.to_string()).unwrap_or_default(),
+        ));
+    }
+    csv.into_bytes()
+}
+
+#[async_trait]
+impl GenericHandler for BatchGenerateCouponsController {
+    async fn handle(&self, request: AdapterRequest) -> AdapterResponse {
+        log::info!("Start request");
+
+        let body = match request.body {
+            Some(body) if body.is_object() => body,
+            _ => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing batch data"}),
+                    binary: None,
+                };
+            }
+        };
+
+        let batch: BatchGenerateDTO = match from_value(body) {
+            Ok(batch) => batch,
+            Err(e) => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Invalid field types", "details": e.to_string()}),
+                    binary: None,
+                };
+            }
+        };
+
+        let usecase = BatchGenerateCouponsUseCase::new(self.coupon_repository.clone());
+        let response = usecase
+            .execute(batch.template, batch.count, batch.prefix, batch.code_length)
+            .await;
+
+        log::info!("End request");
+
+        match response {
+            Ok(coupons) => AdapterResponse {
+                status: StatusCode::Created,
+                data: json!({"count": coupons.len()}),
+                binary: Some(to_csv(&coupons)),
+            },
+            Err(e) if e.starts_with("Invalid batch") => AdapterResponse {
+                status: StatusCode::BadRequest,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(e) if e == "Campaign not found" => AdapterResponse {
+                status: StatusCode::UnprocessableEntity,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(e) if e == "Coupon code already exists" => AdapterResponse {
+                status: StatusCode::Conflict,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(_) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": "Internal server error"}),
+                binary: None,
+            },
+        }
+    }
+}
@@ -1,15 +1,23 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use serde_json::{from_value, json};
+use std::str::FromStr;
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use uuid::Uuid;
 
 use crate::{
     domain::{
+        entity::coupon_entity::{
+            CouponParams, CouponRules, CouponStacking, CouponType, CreateCoupon,
+        },
         repository::coupon_repository::CouponRepository,
         usecase::coupon::create_coupon_usecase::CreateCouponUseCase,
+        utils::validation::{FieldError, VALIDATION_FAILED},
     },
-    interfaces::handler::generic_handler::{
-        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    interfaces::handler::{
+        generic_handler::{AdapterRequest, AdapterResponse, GenericHandler, StatusCode},
+        validation::{fields_response, is_validation_error, validation_response},
     },
 };
 
@@ -24,10 +32,70 @@ struct CouponDTO {
     #[serde(rename = "type")]
     coupon_type: String,
     value: u64,
+    #[serde(default)]
+    params: Option<CouponParams>,
+    #[serde(default)]
+    max_discount_amount: Option<u64>,
     one_shot: bool,
     valid_from: String,
     valid_until: String,
     max_uses: Option<u32>,
+    #[serde(default)]
+    max_uses_per_customer: Option<u32>,
+    #[serde(flatten)]
+    rules: CouponRules,
+    #[serde(default)]
+    campaign_id: Option<Uuid>,
+    #[serde(flatten)]
+    stacking: CouponStacking,
+}
+
+impl CouponDTO {
+    /// The coupon to create, or what is wrong with its type and dates.
+    fn into_coupon(self) -> Result<CreateCoupon, Vec<FieldError>> {
+        let parse_date = |s: &str| -> Option<DateTime<Utc>> {
+            DateTime::parse_from_rfc3339(s)
+                .or_else(|_| DateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S"))
+                .or_else(|_| DateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S"))
+                .map(|dt| dt.with_timezone(&Utc))
+                .ok()
+        };
+
+        match (
+            CouponType::from_str(&self.coupon_type),
+            parse_date(&self.valid_from),
+            parse_date(&self.valid_until),
+        ) {
+            (Ok(coupon_type), Some(valid_from), Some(valid_until)) => Ok(CreateCoupon {
+                code: self.code,
+                coupon_type,
+                value: self.value,
+                params: self.params,
+                max_discount_amount: self.max_discount_amount,
+                one_shot: self.one_shot,
+                valid_from,
+                valid_until,
+                max_uses: self.max_uses,
+                max_uses_per_customer: self.max_uses_per_customer,
+                rules: self.rules,
+                campaign_id: self.campaign_id,
+                stacking: self.stacking,
+            }),
+            (coupon_type, valid_from, valid_until) => {
+                let mut errors = Vec::new();
+                if coupon_type.is_err() {
+                    errors.push(FieldError::coupon_type("type"));
+                }
+                if valid_from.is_none() {
+                    errors.push(FieldError::date("valid_from"));
+                }
+                if valid_until.is_none() {
+                    errors.push(FieldError::date("valid_until"));
+                }
+                Err(errors)
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -77,14 +145,16 @@ impl GenericHandler for CreateCouponController {
         }
 
         if !missing_fields.is_empty() {
-            return AdapterResponse {
-                status: StatusCode::BadRequest,
-                data: json!({"error": "Missing required fields", "fields": missing_fields}),
-                binary: None,
-            };
+            return fields_response(
+                "Missing required fields",
+                missing_fields
+                    .into_iter()
+                    .map(FieldError::required)
+                    .collect(),
+            );
         }
 
-        let coupon: CouponDTO = match from_value(body) {
+        let coupon = match from_value::<CouponDTO>(body) {
             Ok(coupon) => coupon,
             Err(e) => {
                 return AdapterResponse {
@@ -94,20 +164,13 @@ impl GenericHandler for CreateCouponController {
                 };
             }
         };
+        let coupon = match coupon.into_coupon() {
+            Ok(coupon) => coupon,
+            Err(errors) => return fields_response(VALIDATION_FAILED, errors),
+        };
 
         let usecase = CreateCouponUseCase::new(self.coupon_repository.clone());
-
-        let response = usecase
-            .execute(
-                coupon.code,
-                coupon.coupon_type,
-                coupon.value,
-                coupon.one_shot,
-                coupon.valid_from,
-                coupon.valid_until,
-                coupon.max_uses,
-            )
-            .await;
+        let response = usecase.execute(coupon).await;
 
         match response {
             Ok(coupon) => {
@@ -135,12 +198,14 @@ impl GenericHandler for CreateCouponController {
                         data: json!({"error": "Coupon already exists"}),
                         binary: None,
                     }
-                } else if error_str.contains("inválida") {
+                } else if error_str == "Campaign not found" {
                     AdapterResponse {
-                        status: StatusCode::BadRequest,
+                        status: StatusCode::UnprocessableEntity,
                         data: json!({"error": error_str}),
                         binary: None,
                     }
+                } else if is_validation_error(&error_str) {
+                    validation_response(&error_str)
                 } else {
                     AdapterResponse {
                         status: StatusCode::InternalServerError,
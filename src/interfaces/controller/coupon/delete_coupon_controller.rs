@@ -8,8 +8,9 @@ use crate::{
         repository::coupon_repository::CouponRepository,
         usecase::coupon::delete_coupon_usecase::DeleteCouponUseCase,
     },
-    interfaces::handler::generic_handler::{
-        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    interfaces::handler::{
+        etag::parse_if_match,
+        generic_handler::{AdapterRequest, AdapterResponse, GenericHandler, StatusCode},
     },
 };
 
@@ -34,10 +35,21 @@ impl GenericHandler for DeleteCouponController {
             }
         };
 
+        let expected_versions = match parse_if_match(&request.headers) {
+            Ok(versions) => versions,
+            Err(e) => {
+                return AdapterResponse {
+                    status: StatusCode::PreconditionFailed,
+                    data: json!({"error": e}),
+                    binary: None,
+                };
+            }
+        };
+
         let repository = self.coupon_repository.clone();
         let usecase = DeleteCouponUseCase::new(repository);
 
-        let response = usecase.execute(code).await;
+        let response = usecase.execute(code, expected_versions).await;
         log::info!("End request");
 
         match response {
@@ -46,6 +58,11 @@ impl GenericHandler for DeleteCouponController {
                 data: Value::Null,
                 binary: None,
             },
+            Err(e) if e == "Version mismatch" => AdapterResponse {
+                status: StatusCode::PreconditionFailed,
+                data: json!({"error": e}),
+                binary: None,
+            },
             Err(e) => AdapterResponse {
                 status: StatusCode::NotFound,
                 data: json!({"error": e}),
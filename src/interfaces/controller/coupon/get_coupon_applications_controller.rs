@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        repository::discount_repository::DiscountRepository,
+        usecase::coupon::get_coupon_applications_usecase::GetCouponApplicationsUseCase,
+    },
+    interfaces::handler::{
+        generic_handler::{AdapterRequest, AdapterResponse, GenericHandler, StatusCode},
+        period::parse_period,
+        validation::{is_validation_error, validation_response},
+    },
+};
+
+#[derive(Clone)]
+pub struct GetCouponApplicationsController {
+    pub discount_repository: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+}
+
+#[async_trait]
+impl GenericHandler for GetCouponApplicationsController {
+    async fn handle(&self, request: AdapterRequest) -> AdapterResponse {
+        log::info!("Start request");
+        fn get_param<T: serde::de::DeserializeOwned>(query: &Value, key: &str, default: T) -> T {
+            query
+                .get(key)
+                .and_then(|v| serde_json::from_value(v.clone()).ok())
+                .unwrap_or(default)
+        }
+
+        let code = match request.params.and_then(|p| p.get("code").cloned()) {
+            Some(Value::String(code)) => code,
+            _ => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing or invalid coupon code"}),
+                    binary: None,
+                };
+            }
+        };
+
+        let query = request.query.unwrap_or_else(|| json!({}));
+
+        let page: u32 = get_param(&query, "page", 1).max(1);
+        let limit: u32 = get_param(&query, "limit", 10).clamp(1, 50);
+        let period = match parse_period(&query) {
+            Ok(period) => period,
+            Err(e) => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": e}),
+                    binary: None,
+                };
+            }
+        };
+
+        let usecase = GetCouponApplicationsUseCase::new(self.discount_repository.clone());
+        let response = usecase.execute(code, period, page, limit).await;
+
+        log::info!("End request");
+
+        match response {
+            Ok(applications) => match serde_json::to_value(applications) {
+                Ok(json_value) => AdapterResponse {
+                    status: StatusCode::Ok,
+                    data: json_value,
+                    binary: None,
+                },
+                Err(_) => AdapterResponse {
+                    status: StatusCode::InternalServerError,
+                    data: Value::String("Failed to serialize applications".to_string()),
+                    binary: None,
+                },
+            },
+            Err(e) if e == "Coupon not found" => AdapterResponse {
+                status: StatusCode::NotFound,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(e) if is_validation_error(&e) => validation_response(&e),
+            Err(e) if e.starts_with("Invalid period") => AdapterResponse {
+                status: StatusCode::BadRequest,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(e) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": format!("Failed to fetch applications: {}", e)}),
+                binary: None,
+            },
+        }
+    }
+}
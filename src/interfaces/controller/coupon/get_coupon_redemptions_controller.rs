@@ -0,0 +1,78 @@
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        repository::coupon_repository::CouponRepository,
+        usecase::coupon::get_coupon_redemptions_usecase::GetCouponRedemptionsUseCase,
+    },
+    interfaces::handler::generic_handler::{
+        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    },
+};
+
+#[derive(Clone)]
+pub struct GetCouponRedemptionsController {
+    pub coupon_repository: Arc<RwLock<dyn CouponRepository + Send + Sync>>,
+}
+
+#[async_trait]
+impl GenericHandler for GetCouponRedemptionsController {
+    async fn handle(&self, request: AdapterRequest) -> AdapterResponse {
+        log::info!("Start request");
+        fn get_param<T: serde::de::DeserializeOwned>(query: &Value, key: &str, default: T) -> T {
+            query
+                .get(key)
+                .and_then(|v| serde_json::from_value(v.clone()).ok())
+                .unwrap_or(default)
+        }
+
+        let code = match request.params.and_then(|p| p.get("code").cloned()) {
+            Some(Value::String(code)) => code,
+            _ => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing or invalid coupon code"}),
+                    binary: None,
+                };
+            }
+        };
+
+        let query = request.query.unwrap_or_else(|| json!({}));
+
+        let page: u32 = get_param(&query, "page", 1).max(1);
+        let limit: u32 = get_param(&query, "limit", 10).clamp(1, 50);
+
+        let usecase = GetCouponRedemptionsUseCase::new(self.coupon_repository.clone());
+        let response = usecase.execute(code, page, limit).await;
+
+        log::info!("End request");
+
+        match response {
+            Ok(redemptions) => match serde_json::to_value(redemptions) {
+                Ok(json_value) => AdapterResponse {
+                    status: StatusCode::Ok,
+                    data: json_value,
+                    binary: None,
+                },
+                Err(_) => AdapterResponse {
+                    status: StatusCode::InternalServerError,
+                    data: Value::String("Failed to serialize redemptions".to_string()),
+                    binary: None,
+                },
+            },
+            Err(e) if e == "Coupon not found" => AdapterResponse {
+                status: StatusCode::NotFound,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(e) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": format!("Failed to fetch redemptions: {}", e)}),
+                binary: None,
+            },
+        }
+    }
+}
@@ -7,9 +7,11 @@ use crate::{
     domain::{
         repository::coupon_repository::CouponRepository,
         usecase::coupon::get_coupons_usecase::GetAllCouponsUseCase,
+        utils::validation::VALIDATION_FAILED,
     },
-    interfaces::handler::generic_handler::{
-        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    interfaces::handler::{
+        generic_handler::{AdapterRequest, AdapterResponse, GenericHandler, StatusCode},
+        validation::{fields_response, pagination_errors},
     },
 };
 
@@ -38,6 +40,11 @@ impl GenericHandler for GetAllCouponsController {
         let valid_until: String = get_param(&query, "valid_until", "".to_string());
         let is_active: bool = get_param(&query, "is_active", false);
 
+        let errors = pagination_errors(page, limit);
+        if !errors.is_empty() {
+            return fields_response(VALIDATION_FAILED, errors);
+        }
+
         let repo = self.coupon_repository.clone();
         let usecase = GetAllCouponsUseCase::new(repo);
 
@@ -1,5 +1,9 @@
+pub mod batch_generate_coupons_controller;
 pub mod create_coupon_controller;
 pub mod delete_coupon_controller;
+pub mod get_coupon_applications_controller;
 pub mod get_coupon_controller;
+pub mod get_coupon_redemptions_controller;
 pub mod get_coupons_controller;
 pub mod update_coupon_controller;
+pub mod validate_coupon_controller;
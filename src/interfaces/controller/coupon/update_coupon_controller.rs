@@ -1,16 +1,26 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use serde_json::{Value, from_value, json};
+use std::str::FromStr;
 use std::sync::Arc;
 use tokio::sync::RwLock;
+use uuid::Uuid;
 
 use crate::{
     domain::{
+        entity::coupon_entity::{
+            CouponParams, CouponType, UpdateCoupon, UpdateCouponRules, UpdateCouponStacking,
+            explicit_null,
+        },
         repository::coupon_repository::CouponRepository,
         usecase::coupon::update_coupon_usecase::UpdateCouponUseCase,
+        utils::validation::{FieldError, VALIDATION_FAILED},
     },
-    interfaces::handler::generic_handler::{
-        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    interfaces::handler::{
+        etag::parse_if_match,
+        generic_handler::{AdapterRequest, AdapterResponse, GenericHandler, StatusCode},
+        validation::{fields_response, is_validation_error, validation_response},
     },
 };
 
@@ -20,6 +30,10 @@ struct CouponDTO {
     coupon_type: Option<String>,
     #[serde(default)]
     value: Option<u64>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    params: Option<Option<CouponParams>>,
+    #[serde(default)]
+    max_discount_amount: Option<u64>,
     #[serde(default)]
     one_shot: Option<bool>,
     #[serde(default)]
@@ -28,6 +42,59 @@ struct CouponDTO {
     valid_until: Option<String>,
     #[serde(default)]
     max_uses: Option<u32>,
+    #[serde(default)]
+    max_uses_per_customer: Option<u32>,
+    #[serde(flatten)]
+    rules: UpdateCouponRules,
+    #[serde(default)]
+    campaign_id: Option<Uuid>,
+    #[serde(flatten)]
+    stacking: UpdateCouponStacking,
+}
+
+impl CouponDTO {
+    /// The update to make, or what is wrong with its type and dates.
+    fn into_update(self) -> Result<UpdateCoupon, Vec<FieldError>> {
+        let mut errors = Vec::new();
+        let coupon_type = self
+            .coupon_type
+            .and_then(|ct| match CouponType::from_str(&ct) {
+                Ok(val) => Some(val),
+                Err(_) => {
+                    errors.push(FieldError::coupon_type("coupon_type"));
+                    None
+                }
+            });
+        let mut parse_date = |field: &str, value: Option<String>| {
+            value.and_then(|v| match v.parse::<DateTime<Utc>>() {
+                Ok(dt) => Some(dt),
+                Err(_) => {
+                    errors.push(FieldError::date(field));
+                    None
+                }
+            })
+        };
+        let valid_from = parse_date("valid_from", self.valid_from);
+        let valid_until = parse_date("valid_until", self.valid_until);
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(UpdateCoupon {
+            coupon_type,
+            value: self.value,
+            params: self.params,
+            max_discount_amount: self.max_discount_amount,
+            one_shot: self.one_shot,
+            valid_from,
+            valid_until,
+            max_uses: self.max_uses,
+            max_uses_per_customer: self.max_uses_per_customer,
+            rules: self.rules,
+            campaign_id: self.campaign_id,
+            stacking: self.stacking,
+        })
+    }
 }
 
 #[derive(Clone)]
@@ -50,6 +117,17 @@ impl GenericHandler for UpdateCouponController {
                 };
             }
         };
+        let expected_versions = match parse_if_match(&request.headers) {
+            Ok(versions) => versions,
+            Err(e) => {
+                return AdapterResponse {
+                    status: StatusCode::PreconditionFailed,
+                    data: json!({"error": e}),
+                    binary: None,
+                };
+            }
+        };
+
         let body = match request.body {
             Some(b) => b,
             None => {
@@ -74,18 +152,13 @@ impl GenericHandler for UpdateCouponController {
             }
         };
 
+        let update = match coupon.into_update() {
+            Ok(update) => update,
+            Err(errors) => return fields_response(VALIDATION_FAILED, errors),
+        };
+
         let usecase = UpdateCouponUseCase::new(self.coupon_repository.clone());
-        let response = usecase
-            .execute(
-                code,
-                coupon.coupon_type,
-                coupon.value,
-                coupon.one_shot,
-                coupon.valid_from,
-                coupon.valid_until,
-                coupon.max_uses,
-            )
-            .await;
+        let response = usecase.execute(code, update, expected_versions).await;
         match response {
             Ok(coupon) => {
                 let coupon_json = serde_json::to_value(coupon);
@@ -103,6 +176,17 @@ impl GenericHandler for UpdateCouponController {
                     },
                 }
             }
+            Err(e) if is_validation_error(&e) => validation_response(&e),
+            Err(e) if e == "Version mismatch" => AdapterResponse {
+                status: StatusCode::PreconditionFailed,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(e) if e == "Campaign not found" => AdapterResponse {
+                status: StatusCode::UnprocessableEntity,
+                data: json!({"error": e}),
+                binary: None,
+            },
             Err(e) => AdapterResponse {
                 status: StatusCode::NotFound,
                 data: json!({"error": e}),
@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use serde_json::{Value, from_value, json};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        pricing::pricing_engine::PricingEngine,
+        repository::{
+            campaign_repository::CampaignRepository, coupon_repository::CouponRepository,
+            discount_repository::DiscountRepository, product_repository::ProductRepository,
+            sale_event_repository::SaleEventRepository, tax_class_repository::TaxClassRepository,
+        },
+        usecase::coupon::validate_coupon_usecase::ValidateCouponUseCase,
+    },
+    interfaces::handler::generic_handler::{
+        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    },
+};
+
+#[derive(serde::Deserialize)]
+struct ValidateCouponDTO {
+    product_id: Uuid,
+    #[serde(default)]
+    customer_id: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct ValidateCouponController {
+    pub coupon_repository: Arc<RwLock<dyn CouponRepository + Send + Sync>>,
+    pub product_repository: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+    pub discount_repository: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+    pub campaign_repository: Arc<RwLock<dyn CampaignRepository + Send + Sync>>,
+    pub sale_event_repository: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+    pub tax_class_repository: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+    pub pricing_engine: PricingEngine,
+}
+
+#[async_trait]
+impl GenericHandler for ValidateCouponController {
+    async fn handle(&self, request: AdapterRequest) -> AdapterResponse {
+        log::info!("Start request");
+        let code = match request.params.and_then(|p| p.get("code").cloned()) {
+            Some(Value::String(code)) => code,
+            _ => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing or invalid coupon code"}),
+                    binary: None,
+                };
+            }
+        };
+
+        let body: ValidateCouponDTO = match request.body.map(from_value) {
+            Some(Ok(body)) => body,
+            _ => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing or invalid product_id"}),
+                    binary: None,
+                };
+            }
+        };
+        let customer_ref = request
+            .headers
+            .as_ref()
+            .and_then(|h| h.get("x-customer-id"))
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .or(body.customer_id);
+
+        let usecase = ValidateCouponUseCase::new(
+            self.coupon_repository.clone(),
+            self.product_repository.clone(),
+            self.discount_repository.clone(),
+            self.campaign_repository.clone(),
+            self.sale_event_repository.clone(),
+            self.tax_class_repository.clone(),
+            self.pricing_engine,
+        );
+        let response = usecase.execute(code, body.product_id, customer_ref).await;
+
+        log::info!("End request");
+
+        match response {
+            Ok(validation) => match serde_json::to_value(validation) {
+                Ok(json_value) => AdapterResponse {
+                    status: StatusCode::Ok,
+                    data: json_value,
+                    binary: None,
+                },
+                Err(_) => AdapterResponse {
+                    status: StatusCode::InternalServerError,
+                    data: json!({"error": "Failed to serialize validation"}),
+                    binary: None,
+                },
+            },
+            Err(e) if e == "Coupon not found" || e == "Product not found" => AdapterResponse {
+                status: StatusCode::NotFound,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(_) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": "Internal server error"}),
+                binary: None,
+            },
+        }
+    }
+}
@@ -6,6 +6,7 @@ use tokio::sync::RwLock;
 
 use crate::{
     domain::{
+        pricing::pricing_engine::PricingEngine,
         repository::discount_repository::DiscountRepository,
         usecase::discount::apply_coupon_discount_usecase::ApplyCouponDiscountUseCase,
     },
@@ -17,10 +18,13 @@ use crate::{
 #[derive(Deserialize, Default, Debug)]
 struct DiscountDTO {
     code: String,
+    #[serde(default)]
+    customer_id: Option<String>,
 }
 #[derive(Clone)]
 pub struct ApplyCouponDiscountController {
     pub discount_repository: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+    pub pricing_engine: PricingEngine,
 }
 
 #[async_trait]
@@ -38,12 +42,37 @@ impl GenericHandler for ApplyCouponDiscountController {
                 };
             }
         };
-        let discount: DiscountDTO = from_value(request.body.unwrap()).expect("Invalid User data");
+        let discount: DiscountDTO = match request.body.map(from_value) {
+            Some(Ok(discount)) => discount,
+            Some(Err(e)) => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Invalid field types", "details": e.to_string()}),
+                    binary: None,
+                };
+            }
+            None => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing discount data"}),
+                    binary: None,
+                };
+            }
+        };
+        let customer_ref = request
+            .headers
+            .as_ref()
+            .and_then(|h| h.get("x-customer-id"))
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .or(discount.customer_id);
 
         let repository = self.discount_repository.clone();
-        let usecase = ApplyCouponDiscountUseCase::new(repository);
+        let usecase = ApplyCouponDiscountUseCase::new(repository, self.pricing_engine);
 
-        let response = usecase.execute(product_id, discount.code).await;
+        let response = usecase
+            .execute(product_id, discount.code, customer_ref)
+            .await;
         log::info!("End request");
 
         match response {
@@ -52,6 +81,41 @@ impl GenericHandler for ApplyCouponDiscountController {
                 data: Value::Null,
                 binary: None,
             },
+            Err(e)
+                if e == "Coupon usage limit reached"
+                    || e == "Customer usage limit reached"
+                    || e == "Campaign is not active"
+                    || e == "Campaign budget exhausted" =>
+            {
+                AdapterResponse {
+                    status: StatusCode::UnprocessableEntity,
+                    data: json!({"error": e}),
+                    binary: None,
+                }
+            }
+            Err(e) if e.starts_with("Coupon not eligible: ") => AdapterResponse {
+                status: StatusCode::UnprocessableEntity,
+                data: json!({
+                    "error": "Coupon not eligible",
+                    "reason": e.trim_start_matches("Coupon not eligible: "),
+                }),
+                binary: None,
+            },
+            Err(e)
+                if e == "Product already has an active coupon"
+                    || e == "Coupon already applied to product" =>
+            {
+                AdapterResponse {
+                    status: StatusCode::Conflict,
+                    data: json!({"error": e}),
+                    binary: None,
+                }
+            }
+            Err(e) if e == "Customer reference is required for this coupon" => AdapterResponse {
+                status: StatusCode::BadRequest,
+                data: json!({"error": e}),
+                binary: None,
+            },
             Err(e) => AdapterResponse {
                 status: StatusCode::NotFound,
                 data: json!({"error": e}),
@@ -6,6 +6,7 @@ use tokio::sync::RwLock;
 
 use crate::{
     domain::{
+        pricing::pricing_engine::PricingEngine,
         repository::discount_repository::DiscountRepository,
         usecase::discount::apply_percent_discount_usecase::ApplyPercentDiscountUseCase,
     },
@@ -21,6 +22,7 @@ struct DiscountDTO {
 #[derive(Clone)]
 pub struct ApplyPercentDiscountController {
     pub discount_repository: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+    pub pricing_engine: PricingEngine,
 }
 
 #[async_trait]
@@ -38,10 +40,26 @@ impl GenericHandler for ApplyPercentDiscountController {
                 };
             }
         };
-        let discount: DiscountDTO = from_value(request.body.unwrap()).expect("Invalid User data");
+        let discount: DiscountDTO = match request.body.map(from_value) {
+            Some(Ok(discount)) => discount,
+            Some(Err(e)) => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Invalid field types", "details": e.to_string()}),
+                    binary: None,
+                };
+            }
+            None => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing discount data"}),
+                    binary: None,
+                };
+            }
+        };
 
         let repository = self.discount_repository.clone();
-        let usecase = ApplyPercentDiscountUseCase::new(repository);
+        let usecase = ApplyPercentDiscountUseCase::new(repository, self.pricing_engine);
 
         let response = usecase.execute(product_id, discount.code).await;
         log::info!("End request");
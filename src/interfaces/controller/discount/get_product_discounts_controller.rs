@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        repository::discount_repository::DiscountRepository,
+        usecase::discount::get_product_discounts_usecase::GetProductDiscountsUseCase,
+    },
+    interfaces::handler::{
+        generic_handler::{AdapterRequest, AdapterResponse, GenericHandler, StatusCode},
+        period::parse_period,
+        validation::{is_validation_error, validation_response},
+    },
+};
+
+#[derive(Clone)]
+pub struct GetProductDiscountsController {
+    pub discount_repository: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+}
+
+#[async_trait]
+impl GenericHandler for GetProductDiscountsController {
+    async fn handle(&self, request: AdapterRequest) -> AdapterResponse {
+        log::info!("Start request");
+        fn get_param<T: serde::de::DeserializeOwned>(query: &Value, key: &str, default: T) -> T {
+            query
+                .get(key)
+                .and_then(|v| serde_json::from_value(v.clone()).ok())
+                .unwrap_or(default)
+        }
+
+        let id = match request.params.and_then(|p| p.get("id").cloned()) {
+            Some(Value::String(id)) => id,
+            _ => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing or invalid product ID"}),
+                    binary: None,
+                };
+            }
+        };
+
+        let query = request.query.unwrap_or_else(|| json!({}));
+
+        let page: u32 = get_param(&query, "page", 1).max(1);
+        let limit: u32 = get_param(&query, "limit", 10).clamp(1, 50);
+        let period = match parse_period(&query) {
+            Ok(period) => period,
+            Err(e) => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": e}),
+                    binary: None,
+                };
+            }
+        };
+
+        let usecase = GetProductDiscountsUseCase::new(self.discount_repository.clone());
+        let response = usecase.execute(id, period, page, limit).await;
+
+        log::info!("End request");
+
+        match response {
+            Ok(history) => match serde_json::to_value(history) {
+                Ok(json_value) => AdapterResponse {
+                    status: StatusCode::Ok,
+                    data: json_value,
+                    binary: None,
+                },
+                Err(_) => AdapterResponse {
+                    status: StatusCode::InternalServerError,
+                    data: Value::String("Failed to serialize discount history".to_string()),
+                    binary: None,
+                },
+            },
+            Err(e) if e == "Product not found" => AdapterResponse {
+                status: StatusCode::NotFound,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(e) if is_validation_error(&e) => validation_response(&e),
+            Err(e) if e.starts_with("Invalid period") => AdapterResponse {
+                status: StatusCode::BadRequest,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(e) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": format!("Failed to fetch discount history: {}", e)}),
+                binary: None,
+            },
+        }
+    }
+}
@@ -1,3 +1,4 @@
 pub mod apply_coupon_discount_controller;
 pub mod apply_percent_discount_controller;
+pub mod get_product_discounts_controller;
 pub mod remove_discount_controller;
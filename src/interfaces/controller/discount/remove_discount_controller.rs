@@ -38,7 +38,23 @@ impl GenericHandler for RemoveDiscountController {
                 };
             }
         };
-        let discount: DiscountDTO = from_value(request.body.unwrap()).expect("Invalid User data");
+        let discount: DiscountDTO = match request.body.map(from_value) {
+            Some(Ok(discount)) => discount,
+            Some(Err(e)) => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Invalid field types", "details": e.to_string()}),
+                    binary: None,
+                };
+            }
+            None => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing discount data"}),
+                    binary: None,
+                };
+            }
+        };
 
         let repository = self.discount_repository.clone();
         let usecase = RemoveDiscountUseCase::new(repository);
@@ -1,3 +1,8 @@
+pub mod campaign;
 pub mod coupon;
 pub mod discount;
+pub mod order;
 pub mod product;
+pub mod quote;
+pub mod sale_event;
+pub mod tax;
@@ -0,0 +1,144 @@
+use async_trait::async_trait;
+use serde_json::{from_value, json};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        entity::order_entity::CreateOrder,
+        pricing::pricing_engine::PricingEngine,
+        repository::{
+            campaign_repository::CampaignRepository, coupon_repository::CouponRepository,
+            discount_repository::DiscountRepository, order_repository::OrderRepository,
+            product_repository::ProductRepository, sale_event_repository::SaleEventRepository,
+            tax_class_repository::TaxClassRepository,
+        },
+        usecase::{
+            order::create_order_usecase::CreateOrderUseCase,
+            quote::create_quote_usecase::CreateQuoteUseCase,
+        },
+    },
+    interfaces::handler::{
+        generic_handler::{AdapterRequest, AdapterResponse, GenericHandler, StatusCode},
+        validation::{is_validation_error, validation_response},
+    },
+};
+
+#[derive(Clone)]
+pub struct CreateOrderController {
+    pub order_repository: Arc<RwLock<dyn OrderRepository + Send + Sync>>,
+    pub product_repository: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+    pub coupon_repository: Arc<RwLock<dyn CouponRepository + Send + Sync>>,
+    pub discount_repository: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+    pub campaign_repository: Arc<RwLock<dyn CampaignRepository + Send + Sync>>,
+    pub sale_event_repository: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+    pub tax_class_repository: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+    pub pricing_engine: PricingEngine,
+}
+
+#[async_trait]
+impl GenericHandler for CreateOrderController {
+    async fn handle(&self, request: AdapterRequest) -> AdapterResponse {
+        log::info!("Start request");
+
+        let body = match request.body {
+            Some(body) if body.is_object() => body,
+            _ => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing order data"}),
+                    binary: None,
+                };
+            }
+        };
+
+        let order: CreateOrder = match from_value(body) {
+            Ok(order) => order,
+            Err(e) => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Invalid field types", "details": e.to_string()}),
+                    binary: None,
+                };
+            }
+        };
+        let customer_ref = request
+            .headers
+            .as_ref()
+            .and_then(|h| h.get("x-customer-id"))
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .or(order.customer_id.clone());
+
+        let quote = CreateQuoteUseCase::new(
+            self.product_repository.clone(),
+            self.coupon_repository.clone(),
+            self.discount_repository.clone(),
+            self.campaign_repository.clone(),
+            self.sale_event_repository.clone(),
+            self.tax_class_repository.clone(),
+            self.pricing_engine,
+        );
+        let usecase = CreateOrderUseCase::new(self.order_repository.clone(), quote);
+        let response = usecase.execute(order, customer_ref).await;
+
+        log::info!("End request");
+
+        match response {
+            Ok(order) => match serde_json::to_value(order) {
+                Ok(json_value) => AdapterResponse {
+                    status: StatusCode::Created,
+                    data: json_value,
+                    binary: None,
+                },
+                Err(_) => AdapterResponse {
+                    status: StatusCode::InternalServerError,
+                    data: json!({"error": "Failed to serialize order"}),
+                    binary: None,
+                },
+            },
+            Err(e) if is_validation_error(&e) => validation_response(&e),
+            Err(e) if e == "Customer reference is required for this coupon" => AdapterResponse {
+                status: StatusCode::BadRequest,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(e) if e == "Product not found" || e == "Coupon not found" => AdapterResponse {
+                status: StatusCode::NotFound,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(e) if e == "Insufficient stock" => AdapterResponse {
+                status: StatusCode::Conflict,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(e)
+                if e == "Coupon is not valid"
+                    || e == "Coupon usage limit reached"
+                    || e == "Customer usage limit reached"
+                    || e == "Campaign is not active"
+                    || e == "Campaign budget exhausted" =>
+            {
+                AdapterResponse {
+                    status: StatusCode::UnprocessableEntity,
+                    data: json!({"error": e}),
+                    binary: None,
+                }
+            }
+            Err(e) if e.starts_with("Coupon not eligible") => AdapterResponse {
+                status: StatusCode::UnprocessableEntity,
+                data: json!({
+                    "error": "Coupon not eligible",
+                    "reason": e.trim_start_matches("Coupon not eligible: "),
+                }),
+                binary: None,
+            },
+            Err(_) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": "Internal server error"}),
+                binary: None,
+            },
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        repository::order_repository::OrderRepository,
+        usecase::order::get_order_usecase::GetOrderUseCase,
+    },
+    interfaces::handler::generic_handler::{
+        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    },
+};
+
+#[derive(Clone)]
+pub struct GetOrderController {
+    pub order_repository: Arc<RwLock<dyn OrderRepository + Send + Sync>>,
+}
+
+#[async_trait]
+impl GenericHandler for GetOrderController {
+    async fn handle(&self, request: AdapterRequest) -> AdapterResponse {
+        log::info!("Start request");
+        let id = match request.params.and_then(|p| p.get("id").cloned()) {
+            Some(Value::String(id)) => id,
+            _ => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing or invalid order id"}),
+                    binary: None,
+                };
+            }
+        };
+
+        let usecase = GetOrderUseCase::new(self.order_repository.clone());
+        let response = usecase.execute(id).await;
+
+        log::info!("End request");
+
+        match response {
+            Ok(order) => match serde_json::to_value(order) {
+                Ok(json_value) => AdapterResponse {
+                    status: StatusCode::Ok,
+                    data: json_value,
+                    binary: None,
+                },
+                Err(_) => AdapterResponse {
+                    status: StatusCode::InternalServerError,
+                    data: json!({"error": "Failed to serialize order"}),
+                    binary: None,
+                },
+            },
+            Err(e) if e == "Order not found" => AdapterResponse {
+                status: StatusCode::NotFound,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(_) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": "Internal server error"}),
+                binary: None,
+            },
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        repository::order_repository::OrderRepository,
+        usecase::order::get_orders_usecase::GetOrdersUseCase,
+    },
+    interfaces::handler::generic_handler::{
+        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    },
+};
+
+#[derive(Clone)]
+pub struct GetOrdersController {
+    pub order_repository: Arc<RwLock<dyn OrderRepository + Send + Sync>>,
+}
+
+#[async_trait]
+impl GenericHandler for GetOrdersController {
+    async fn handle(&self, request: AdapterRequest) -> AdapterResponse {
+        log::info!("Start request");
+        fn get_param<T: serde::de::DeserializeOwned>(query: &Value, key: &str, default: T) -> T {
+            query
+                .get(key)
+                .and_then(|v| serde_json::from_value(v.clone()).ok())
+                .unwrap_or(default)
+        }
+
+        let query = request.query.unwrap_or_else(|| json!({}));
+
+        let page: u32 = get_param(&query, "page", 1).max(1);
+        let limit: u32 = get_param(&query, "limit", 10).clamp(1, 50);
+        let customer_ref: Option<String> = get_param(&query, "customer_id", None);
+
+        let usecase = GetOrdersUseCase::new(self.order_repository.clone());
+        let response = usecase.execute(page, limit, customer_ref).await;
+
+        log::info!("End request");
+
+        match response {
+            Ok(orders) => match serde_json::to_value(orders) {
+                Ok(json_value) => AdapterResponse {
+                    status: StatusCode::Ok,
+                    data: json_value,
+                    binary: None,
+                },
+                Err(_) => AdapterResponse {
+                    status: StatusCode::InternalServerError,
+                    data: json!({"error": "Failed to serialize orders"}),
+                    binary: None,
+                },
+            },
+            Err(e) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": format!("Failed to fetch orders: {}", e)}),
+                binary: None,
+            },
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod create_order_controller;
+pub mod get_order_controller;
+pub mod get_orders_controller;
+pub mod transition_order_controller;
@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+use serde_json::{Value, from_value, json};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        entity::order_entity::{OrderAction, TransitionOrder},
+        repository::order_repository::OrderRepository,
+        usecase::order::transition_order_usecase::TransitionOrderUseCase,
+    },
+    interfaces::handler::{
+        generic_handler::{AdapterRequest, AdapterResponse, GenericHandler, StatusCode},
+        validation::{is_validation_error, validation_response},
+    },
+};
+
+/// Applies one order action; each action has its own route.
+#[derive(Clone)]
+pub struct TransitionOrderController {
+    pub order_repository: Arc<RwLock<dyn OrderRepository + Send + Sync>>,
+    pub action: OrderAction,
+}
+
+#[async_trait]
+impl GenericHandler for TransitionOrderController {
+    async fn handle(&self, request: AdapterRequest) -> AdapterResponse {
+        log::info!("Start request");
+        let id = match request.params.and_then(|p| p.get("id").cloned()) {
+            Some(Value::String(id)) => id,
+            _ => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing or invalid order id"}),
+                    binary: None,
+                };
+            }
+        };
+
+        let transition: TransitionOrder = match request.body {
+            None | Some(Value::Null) => TransitionOrder::default(),
+            Some(body) => match from_value(body) {
+                Ok(transition) => transition,
+                Err(e) => {
+                    return AdapterResponse {
+                        status: StatusCode::BadRequest,
+                        data: json!({"error": "Invalid field types", "details": e.to_string()}),
+                        binary: None,
+                    };
+                }
+            },
+        };
+
+        let usecase = TransitionOrderUseCase::new(self.order_repository.clone());
+        let response = usecase.execute(id, self.action, transition).await;
+
+        log::info!("End request");
+
+        match response {
+            Ok(order) => match serde_json::to_value(order) {
+                Ok(json_value) => AdapterResponse {
+                    status: StatusCode::Ok,
+                    data: json_value,
+                    binary: None,
+                },
+                Err(_) => AdapterResponse {
+                    status: StatusCode::InternalServerError,
+                    data: json!({"error": "Failed to serialize order"}),
+                    binary: None,
+                },
+            },
+            Err(e) if e == "Order not found" => AdapterResponse {
+                status: StatusCode::NotFound,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(e) if is_validation_error(&e) => validation_response(&e),
+            Err(e) if e.starts_with("Order is ") || e == "Restock exceeds the stock limit" => {
+                AdapterResponse {
+                    status: StatusCode::Conflict,
+                    data: json!({"error": e}),
+                    binary: None,
+                }
+            }
+            Err(_) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": "Internal server error"}),
+                binary: None,
+            },
+        }
+    }
+}
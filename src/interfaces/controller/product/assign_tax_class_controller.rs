@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use serde_json::{Value, from_value, json};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        entity::product_entity::AssignTaxClass,
+        repository::{
+            product_repository::ProductRepository, tax_class_repository::TaxClassRepository,
+        },
+        usecase::product::assign_tax_class_usecase::AssignTaxClassUseCase,
+    },
+    interfaces::handler::generic_handler::{
+        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    },
+};
+
+#[derive(Clone)]
+pub struct AssignTaxClassController {
+    pub product_repository: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+    pub tax_class_repository: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+}
+
+#[async_trait]
+impl GenericHandler for AssignTaxClassController {
+    async fn handle(&self, request: AdapterRequest) -> AdapterResponse {
+        log::info!("Start request");
+        let id = match request.params.and_then(|p| p.get("id").cloned()) {
+            Some(Value::String(id)) => id,
+            _ => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing or invalid product ID"}),
+                    binary: None,
+                };
+            }
+        };
+
+        let assign: AssignTaxClass = match request.body.map(from_value) {
+            Some(Ok(assign)) => assign,
+            _ => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing or invalid tax_class_id"}),
+                    binary: None,
+                };
+            }
+        };
+
+        let usecase = AssignTaxClassUseCase::new(
+            self.product_repository.clone(),
+            self.tax_class_repository.clone(),
+        );
+        let response = usecase.execute(id, assign).await;
+
+        log::info!("End request");
+
+        match response {
+            Ok(product) => match serde_json::to_value(product) {
+                Ok(json_value) => AdapterResponse {
+                    status: StatusCode::Ok,
+                    data: json_value,
+                    binary: None,
+                },
+                Err(_) => AdapterResponse {
+                    status: StatusCode::InternalServerError,
+                    data: json!({"error": "Failed to serialize product"}),
+                    binary: None,
+                },
+            },
+            Err(e) if e == "Tax class not found" => AdapterResponse {
+                status: StatusCode::UnprocessableEntity,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(e) if e == "Product not found" || e == "Invalid UUID" => AdapterResponse {
+                status: StatusCode::NotFound,
+                data: json!({"error": "Product not found"}),
+                binary: None,
+            },
+            Err(_) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": "Internal server error"}),
+                binary: None,
+            },
+        }
+    }
+}
@@ -8,9 +8,11 @@ use crate::{
     domain::{
         repository::product_repository::ProductRepository,
         usecase::product::create_product_usecase::CreateProductUseCase,
+        utils::validation::FieldError,
     },
-    interfaces::handler::generic_handler::{
-        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    interfaces::handler::{
+        generic_handler::{AdapterRequest, AdapterResponse, GenericHandler, StatusCode},
+        validation::{fields_response, is_validation_error, validation_response},
     },
 };
 
@@ -65,11 +67,13 @@ impl GenericHandler for CreateProductController {
         }
 
         if !missing_fields.is_empty() {
-            return AdapterResponse {
-                status: StatusCode::BadRequest,
-                data: json!({"error": "Missing required fields", "fields": missing_fields}),
-                binary: None,
-            };
+            return fields_response(
+                "Missing required fields",
+                missing_fields
+                    .into_iter()
+                    .map(FieldError::required)
+                    .collect(),
+            );
         }
 
         let product: ProductDTO = match from_value(body) {
@@ -108,7 +112,8 @@ impl GenericHandler for CreateProductController {
                 let error_str = e.to_string();
                 log::error!("Error creating product: {}", error_str);
 
-                if error_str.contains("products_name_unique_idx")
+                if error_str == "Product already exists"
+                    || error_str.contains("products_name_unique_idx")
                     || error_str.contains("duplicate key value")
                 {
                     AdapterResponse {
@@ -116,6 +121,8 @@ impl GenericHandler for CreateProductController {
                         data: json!({"error": "Product already exists"}),
                         binary: None,
                     }
+                } else if is_validation_error(&error_str) {
+                    validation_response(&error_str)
                 } else if error_str.contains("inválida") {
                     AdapterResponse {
                         status: StatusCode::BadRequest,
@@ -8,8 +8,9 @@ use crate::{
         repository::product_repository::ProductRepository,
         usecase::product::delete_product_usecase::DeleteProductUseCase,
     },
-    interfaces::handler::generic_handler::{
-        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    interfaces::handler::{
+        etag::parse_if_match,
+        generic_handler::{AdapterRequest, AdapterResponse, GenericHandler, StatusCode},
     },
 };
 
@@ -34,10 +35,21 @@ impl GenericHandler for DeleteProductController {
             }
         };
 
+        let expected_versions = match parse_if_match(&request.headers) {
+            Ok(versions) => versions,
+            Err(e) => {
+                return AdapterResponse {
+                    status: StatusCode::PreconditionFailed,
+                    data: json!({"error": e}),
+                    binary: None,
+                };
+            }
+        };
+
         let repository = self.product_repository.clone();
         let usecase = DeleteProductUseCase::new(repository);
 
-        let response = usecase.execute(id).await;
+        let response = usecase.execute(id, expected_versions).await;
         log::warn!("Response: {:#?}", response);
 
         match response {
@@ -46,6 +58,11 @@ impl GenericHandler for DeleteProductController {
                 data: Value::Null,
                 binary: None,
             },
+            Err(e) if e == "Version mismatch" => AdapterResponse {
+                status: StatusCode::PreconditionFailed,
+                data: json!({"error": e}),
+                binary: None,
+            },
             Err(e) => AdapterResponse {
                 status: StatusCode::NotFound,
                 data: json!({"error": e}),
@@ -5,8 +5,10 @@ use tokio::sync::RwLock;
 
 use crate::{
     domain::{
+        pricing::pricing_engine::PricingEngine,
         repository::{
             discount_repository::DiscountRepository, product_repository::ProductRepository,
+            sale_event_repository::SaleEventRepository, tax_class_repository::TaxClassRepository,
         },
         usecase::product::get_product_usecase::GetProductUseCase,
     },
@@ -19,6 +21,9 @@ use crate::{
 pub struct GetProductController {
     pub product_repository: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
     pub discount_repository: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+    pub sale_event_repository: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+    pub tax_class_repository: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+    pub pricing_engine: PricingEngine,
 }
 
 #[async_trait]
@@ -39,8 +44,15 @@ impl GenericHandler for GetProductController {
 
         let product_repository = self.product_repository.clone();
         let discount_repository = self.discount_repository.clone();
+        let sale_event_repository = self.sale_event_repository.clone();
 
-        let usecase = GetProductUseCase::new(product_repository, discount_repository);
+        let usecase = GetProductUseCase::new(
+            product_repository,
+            discount_repository,
+            sale_event_repository,
+            self.tax_class_repository.clone(),
+            self.pricing_engine,
+        );
 
         let response = usecase.execute(id).await;
         log::info!("End request");
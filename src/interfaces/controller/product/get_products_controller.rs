@@ -5,17 +5,27 @@ use tokio::sync::RwLock;
 
 use crate::{
     domain::{
-        repository::product_repository::ProductRepository,
+        pricing::pricing_engine::PricingEngine,
+        repository::{
+            discount_repository::DiscountRepository, product_repository::ProductRepository,
+            sale_event_repository::SaleEventRepository, tax_class_repository::TaxClassRepository,
+        },
         usecase::product::get_all_product_usecase::GetAllProductsUseCase,
+        utils::validation::VALIDATION_FAILED,
     },
-    interfaces::handler::generic_handler::{
-        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    interfaces::handler::{
+        generic_handler::{AdapterRequest, AdapterResponse, GenericHandler, StatusCode},
+        validation::{fields_response, pagination_errors},
     },
 };
 
 #[derive(Clone)]
 pub struct GetAllProductsController {
     pub product_repository: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+    pub discount_repository: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+    pub sale_event_repository: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+    pub tax_class_repository: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+    pub pricing_engine: PricingEngine,
 }
 
 #[async_trait]
@@ -38,8 +48,18 @@ impl GenericHandler for GetAllProductsController {
         let max_price: u32 = get_param(&query, "max_price", u32::MAX);
         let has_discount: bool = get_param(&query, "has_discount", false);
 
-        let repo = self.product_repository.clone();
-        let usecase = GetAllProductsUseCase::new(repo);
+        let errors = pagination_errors(page, limit);
+        if !errors.is_empty() {
+            return fields_response(VALIDATION_FAILED, errors);
+        }
+
+        let usecase = GetAllProductsUseCase::new(
+            self.product_repository.clone(),
+            self.discount_repository.clone(),
+            self.sale_event_repository.clone(),
+            self.tax_class_repository.clone(),
+            self.pricing_engine,
+        );
 
         let response = usecase
             .execute(page, limit, search, min_price, max_price, has_discount)
@@ -1,6 +1,9 @@
+pub mod assign_tax_class_controller;
 pub mod create_product_controller;
 pub mod delete_product_controller;
 pub mod get_product_controller;
 pub mod get_products_controller;
+pub mod get_trash_products_controller;
+pub mod purge_product_controller;
 pub mod restore_product_controller;
 pub mod update_product_controller;
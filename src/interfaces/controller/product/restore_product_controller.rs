@@ -56,9 +56,14 @@ impl GenericHandler for RestoreProductController {
                     },
                 }
             }
+            Err(e) if e == "Product already exists" => AdapterResponse {
+                status: StatusCode::Conflict,
+                data: json!({"error": e}),
+                binary: None,
+            },
             Err(e) => AdapterResponse {
                 status: StatusCode::NotFound,
-                data: json!({"error": format!("Product not found: {}", e)}),
+                data: json!({"error": e}),
                 binary: None,
             },
         }
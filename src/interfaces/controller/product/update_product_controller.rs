@@ -9,8 +9,10 @@ use crate::{
         repository::product_repository::ProductRepository,
         usecase::product::update_product_usecase::UpdateProductUseCase,
     },
-    interfaces::handler::generic_handler::{
-        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    interfaces::handler::{
+        etag::parse_if_match,
+        generic_handler::{AdapterRequest, AdapterResponse, GenericHandler, StatusCode},
+        validation::{is_validation_error, validation_response},
     },
 };
 
@@ -43,6 +45,17 @@ impl GenericHandler for UpdateProductController {
             }
         };
 
+        let expected_versions = match parse_if_match(&request.headers) {
+            Ok(versions) => versions,
+            Err(e) => {
+                return AdapterResponse {
+                    status: StatusCode::PreconditionFailed,
+                    data: json!({"error": e}),
+                    binary: None,
+                };
+            }
+        };
+
         let product: ProductDTO = match request.body {
             Some(b) => match from_value(b) {
                 Ok(prod) => prod,
@@ -71,6 +84,7 @@ impl GenericHandler for UpdateProductController {
                 product.description,
                 product.stock,
                 product.price,
+                expected_versions,
             )
             .await;
 
@@ -91,11 +105,27 @@ impl GenericHandler for UpdateProductController {
                     },
                 }
             }
-            Err(e) => AdapterResponse {
+            Err(e) if is_validation_error(&e) => validation_response(&e),
+            Err(e) if e == "Version mismatch" => AdapterResponse {
+                status: StatusCode::PreconditionFailed,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(e) if e == "Product already exists" => AdapterResponse {
+                status: StatusCode::Conflict,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(e) if e == "Product not found" || e == "Invalid UUID" => AdapterResponse {
                 status: StatusCode::NotFound,
                 data: json!({ "error": e }),
                 binary: None,
             },
+            Err(_) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": "Internal server error"}),
+                binary: None,
+            },
         }
     }
 }
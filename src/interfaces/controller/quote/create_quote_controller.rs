@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use serde_json::{from_value, json};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        entity::quote_entity::CreateQuote,
+        pricing::pricing_engine::PricingEngine,
+        repository::{
+            campaign_repository::CampaignRepository, coupon_repository::CouponRepository,
+            discount_repository::DiscountRepository, product_repository::ProductRepository,
+            sale_event_repository::SaleEventRepository, tax_class_repository::TaxClassRepository,
+        },
+        usecase::quote::create_quote_usecase::CreateQuoteUseCase,
+    },
+    interfaces::handler::{
+        generic_handler::{AdapterRequest, AdapterResponse, GenericHandler, StatusCode},
+        validation::{is_validation_error, validation_response},
+    },
+};
+
+#[derive(Clone)]
+pub struct CreateQuoteController {
+    pub product_repository: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+    pub coupon_repository: Arc<RwLock<dyn CouponRepository + Send + Sync>>,
+    pub discount_repository: Arc<RwLock<dyn DiscountRepository + Send + Sync>>,
+    pub campaign_repository: Arc<RwLock<dyn CampaignRepository + Send + Sync>>,
+    pub sale_event_repository: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+    pub tax_class_repository: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+    pub pricing_engine: PricingEngine,
+}
+
+#[async_trait]
+impl GenericHandler for CreateQuoteController {
+    async fn handle(&self, request: AdapterRequest) -> AdapterResponse {
+        log::info!("Start request");
+
+        let body = match request.body {
+            Some(body) if body.is_object() => body,
+            _ => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing quote data"}),
+                    binary: None,
+                };
+            }
+        };
+
+        let quote: CreateQuote = match from_value(body) {
+            Ok(quote) => quote,
+            Err(e) => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Invalid field types", "details": e.to_string()}),
+                    binary: None,
+                };
+            }
+        };
+        let customer_ref = request
+            .headers
+            .as_ref()
+            .and_then(|h| h.get("x-customer-id"))
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .or(quote.customer_id.clone());
+
+        let usecase = CreateQuoteUseCase::new(
+            self.product_repository.clone(),
+            self.coupon_repository.clone(),
+            self.discount_repository.clone(),
+            self.campaign_repository.clone(),
+            self.sale_event_repository.clone(),
+            self.tax_class_repository.clone(),
+            self.pricing_engine,
+        );
+        let response = usecase.execute(quote, customer_ref).await;
+
+        log::info!("End request");
+
+        match response {
+            Ok(quote) => match serde_json::to_value(quote) {
+                Ok(json_value) => AdapterResponse {
+                    status: StatusCode::Ok,
+                    data: json_value,
+                    binary: None,
+                },
+                Err(_) => AdapterResponse {
+                    status: StatusCode::InternalServerError,
+                    data: json!({"error": "Failed to serialize quote"}),
+                    binary: None,
+                },
+            },
+            Err(e) if is_validation_error(&e) => validation_response(&e),
+            Err(_) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": "Internal server error"}),
+                binary: None,
+            },
+        }
+    }
+}
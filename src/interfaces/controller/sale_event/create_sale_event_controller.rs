@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use serde_json::{from_value, json};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        entity::sale_event_entity::CreateSaleEvent,
+        repository::sale_event_repository::SaleEventRepository,
+        usecase::sale_event::create_sale_event_usecase::CreateSaleEventUseCase,
+    },
+    interfaces::handler::{
+        generic_handler::{AdapterRequest, AdapterResponse, GenericHandler, StatusCode},
+        validation::{is_validation_error, validation_response},
+    },
+};
+
+#[derive(Clone)]
+pub struct CreateSaleEventController {
+    pub sale_event_repository: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+}
+
+#[async_trait]
+impl GenericHandler for CreateSaleEventController {
+    async fn handle(&self, request: AdapterRequest) -> AdapterResponse {
+        log::info!("Start request");
+
+        let body = match request.body {
+            Some(body) if body.is_object() => body,
+            _ => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing sale event data"}),
+                    binary: None,
+                };
+            }
+        };
+
+        let event: CreateSaleEvent = match from_value(body) {
+            Ok(event) => event,
+            Err(e) => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Invalid field types", "details": e.to_string()}),
+                    binary: None,
+                };
+            }
+        };
+
+        let usecase = CreateSaleEventUseCase::new(self.sale_event_repository.clone());
+        let response = usecase.execute(event).await;
+
+        log::info!("End request");
+
+        match response {
+            Ok(event) => match serde_json::to_value(event) {
+                Ok(json_value) => AdapterResponse {
+                    status: StatusCode::Created,
+                    data: json_value,
+                    binary: None,
+                },
+                Err(_) => AdapterResponse {
+                    status: StatusCode::InternalServerError,
+                    data: json!({"error": "Failed to serialize sale event"}),
+                    binary: None,
+                },
+            },
+            Err(e) if is_validation_error(&e) => validation_response(&e),
+            Err(_) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": "Internal server error"}),
+                binary: None,
+            },
+        }
+    }
+}
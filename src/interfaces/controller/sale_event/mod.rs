@@ -0,0 +1,4 @@
+pub mod cancel_sale_event_controller;
+pub mod create_sale_event_controller;
+pub mod get_sale_event_controller;
+pub mod preview_sale_event_controller;
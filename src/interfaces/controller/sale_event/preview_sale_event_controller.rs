@@ -0,0 +1,87 @@
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        pricing::pricing_engine::PricingEngine,
+        repository::{
+            product_repository::ProductRepository, sale_event_repository::SaleEventRepository,
+        },
+        usecase::sale_event::preview_sale_event_usecase::PreviewSaleEventUseCase,
+    },
+    interfaces::handler::generic_handler::{
+        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    },
+};
+
+#[derive(Clone)]
+pub struct PreviewSaleEventController {
+    pub sale_event_repository: Arc<RwLock<dyn SaleEventRepository + Send + Sync>>,
+    pub product_repository: Arc<RwLock<dyn ProductRepository + Send + Sync>>,
+    pub pricing_engine: PricingEngine,
+}
+
+#[async_trait]
+impl GenericHandler for PreviewSaleEventController {
+    async fn handle(&self, request: AdapterRequest) -> AdapterResponse {
+        log::info!("Start request");
+        fn get_param<T: serde::de::DeserializeOwned>(query: &Value, key: &str, default: T) -> T {
+            query
+                .get(key)
+                .and_then(|v| serde_json::from_value(v.clone()).ok())
+                .unwrap_or(default)
+        }
+
+        let id = match request.params.and_then(|p| p.get("id").cloned()) {
+            Some(Value::String(id)) => id,
+            _ => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing or invalid sale event id"}),
+                    binary: None,
+                };
+            }
+        };
+
+        let query = request.query.unwrap_or_else(|| json!({}));
+
+        let page: u32 = get_param(&query, "page", 1).max(1);
+        let limit: u32 = get_param(&query, "limit", 10).clamp(1, 50);
+
+        let usecase = PreviewSaleEventUseCase::new(
+            self.sale_event_repository.clone(),
+            self.product_repository.clone(),
+            self.pricing_engine,
+        );
+        let response = usecase.execute(id, page, limit).await;
+
+        log::info!("End request");
+
+        match response {
+            Ok(preview) => match serde_json::to_value(preview) {
+                Ok(json_value) => AdapterResponse {
+                    status: StatusCode::Ok,
+                    data: json_value,
+                    binary: None,
+                },
+                Err(_) => AdapterResponse {
+                    status: StatusCode::InternalServerError,
+                    data: json!({"error": "Failed to serialize sale event preview"}),
+                    binary: None,
+                },
+            },
+            Err(e) if e == "Sale event not found" => AdapterResponse {
+                status: StatusCode::NotFound,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(e) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": format!("Failed to preview sale event: {}", e)}),
+                binary: None,
+            },
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use serde_json::{from_value, json};
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        entity::tax_entity::CreateTaxClass, repository::tax_class_repository::TaxClassRepository,
+        usecase::tax::create_tax_class_usecase::CreateTaxClassUseCase,
+    },
+    interfaces::handler::{
+        generic_handler::{AdapterRequest, AdapterResponse, GenericHandler, StatusCode},
+        validation::{is_validation_error, validation_response},
+    },
+};
+
+#[derive(Clone)]
+pub struct CreateTaxClassController {
+    pub tax_class_repository: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+}
+
+#[async_trait]
+impl GenericHandler for CreateTaxClassController {
+    async fn handle(&self, request: AdapterRequest) -> AdapterResponse {
+        log::info!("Start request");
+
+        let body = match request.body {
+            Some(body) if body.is_object() => body,
+            _ => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Missing tax class data"}),
+                    binary: None,
+                };
+            }
+        };
+
+        let tax_class: CreateTaxClass = match from_value(body) {
+            Ok(tax_class) => tax_class,
+            Err(e) => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": "Invalid field types", "details": e.to_string()}),
+                    binary: None,
+                };
+            }
+        };
+
+        let usecase = CreateTaxClassUseCase::new(self.tax_class_repository.clone());
+        let response = usecase.execute(tax_class).await;
+
+        log::info!("End request");
+
+        match response {
+            Ok(tax_class) => match serde_json::to_value(tax_class) {
+                Ok(json_value) => AdapterResponse {
+                    status: StatusCode::Created,
+                    data: json_value,
+                    binary: None,
+                },
+                Err(_) => AdapterResponse {
+                    status: StatusCode::InternalServerError,
+                    data: json!({"error": "Failed to serialize tax class"}),
+                    binary: None,
+                },
+            },
+            Err(e) if is_validation_error(&e) => validation_response(&e),
+            Err(e) if e == "Tax class already exists" => AdapterResponse {
+                status: StatusCode::Conflict,
+                data: json!({"error": e}),
+                binary: None,
+            },
+            Err(_) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": "Internal server error"}),
+                binary: None,
+            },
+        }
+    }
+}
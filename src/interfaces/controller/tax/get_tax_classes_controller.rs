@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        repository::tax_class_repository::TaxClassRepository,
+        usecase::tax::get_tax_classes_usecase::GetTaxClassesUseCase,
+    },
+    interfaces::handler::generic_handler::{
+        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    },
+};
+
+#[derive(Clone)]
+pub struct GetTaxClassesController {
+    pub tax_class_repository: Arc<RwLock<dyn TaxClassRepository + Send + Sync>>,
+}
+
+#[async_trait]
+impl GenericHandler for GetTaxClassesController {
+    async fn handle(&self, _request: AdapterRequest) -> AdapterResponse {
+        log::info!("Start request");
+
+        let usecase = GetTaxClassesUseCase::new(self.tax_class_repository.clone());
+        let response = usecase.execute().await;
+
+        log::info!("End request");
+
+        match response {
+            Ok(tax_classes) => match serde_json::to_value(tax_classes) {
+                Ok(json_value) => AdapterResponse {
+                    status: StatusCode::Ok,
+                    data: json_value,
+                    binary: None,
+                },
+                Err(_) => AdapterResponse {
+                    status: StatusCode::InternalServerError,
+                    data: json!({"error": "Failed to serialize tax classes"}),
+                    binary: None,
+                },
+            },
+            Err(e) => AdapterResponse {
+                status: StatusCode::InternalServerError,
+                data: json!({"error": format!("Failed to fetch tax classes: {}", e)}),
+                binary: None,
+            },
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use serde_json::Value;
+
+pub fn format_etag(version: u32) -> String {
+    format!("\"{}\"", version)
+}
+
+/// Reads the `If-Match` request header into the versions it accepts.
+/// `Ok(None)` means no precondition (header absent or `*`). `If-Match`
+/// compares strongly, so weak tags like `W/"3"` match nothing; a header where
+/// no tag is a strong version can never match and is an error.
+pub fn parse_if_match(headers: &Option<Value>) -> Result<Option<Vec<u32>>, String> {
+    let raw = match headers
+        .as_ref()
+        .and_then(|h| h.get("if-match"))
+        .and_then(|v| v.as_str())
+    {
+        Some(raw) => raw.trim(),
+        None => return Ok(None),
+    };
+
+    let tags: Vec<&str> = raw.split(',').map(str::trim).collect();
+    if tags.contains(&"*") {
+        return Ok(None);
+    }
+
+    let versions: Vec<u32> = tags
+        .iter()
+        .filter_map(|tag| tag.strip_prefix('"')?.strip_suffix('"')?.parse().ok())
+        .collect();
+    match versions.is_empty() {
+        true => Err("Version mismatch".to_string()),
+        false => Ok(Some(versions)),
+    }
+}
@@ -7,6 +7,8 @@ pub struct AdapterRequest {
     pub query: Option<Value>,
     pub params: Option<Value>,
     pub body: Option<Value>,
+    #[serde(default)]
+    pub headers: Option<Value>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -27,6 +29,8 @@ pub enum StatusCode {
     Forbidden = 403,
     NotFound = 404,
     Conflict = 409,
+    PreconditionFailed = 412,
+    UnprocessableEntity = 422,
     InternalServerError = 500,
     NotImplemented = 501,
     ServiceUnavailable = 503,
@@ -44,6 +48,8 @@ impl From<u16> for StatusCode {
             403 => StatusCode::Forbidden,
             404 => StatusCode::NotFound,
             409 => StatusCode::Conflict,
+            412 => StatusCode::PreconditionFailed,
+            422 => StatusCode::UnprocessableEntity,
             500 => StatusCode::InternalServerError,
             501 => StatusCode::NotImplemented,
             503 => StatusCode::ServiceUnavailable,
@@ -0,0 +1,121 @@
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::{
+        entity::idempotency_entity::IdempotencyLookup,
+        repository::idempotency_repository::IdempotencyRepository,
+        usecase::idempotency::{
+            acquire_idempotency_key_usecase::AcquireIdempotencyKeyUseCase,
+            complete_idempotency_key_usecase::CompleteIdempotencyKeyUseCase,
+        },
+    },
+    interfaces::handler::generic_handler::{
+        AdapterRequest, AdapterResponse, GenericHandler, StatusCode,
+    },
+};
+
+/// Wraps a handler so requests carrying an `Idempotency-Key` header are
+/// executed at most once per `scope`; retries get the stored response.
+pub struct IdempotentHandler {
+    pub inner: Arc<dyn GenericHandler + Send + Sync>,
+    pub idempotency_repository: Arc<RwLock<dyn IdempotencyRepository + Send + Sync>>,
+    pub scope: String,
+    pub ttl_secs: i64,
+}
+
+#[async_trait]
+impl GenericHandler for IdempotentHandler {
+    async fn handle(&self, request: AdapterRequest) -> AdapterResponse {
+        let key = match request
+            .headers
+            .as_ref()
+            .and_then(|h| h.get("idempotency-key"))
+            .and_then(|v| v.as_str())
+        {
+            Some(key) => key.to_string(),
+            None => return self.inner.handle(request).await,
+        };
+
+        // The customer is part of the request: another customer reusing the
+        // key must not be replayed someone else's redemption or order.
+        let customer = request
+            .headers
+            .as_ref()
+            .and_then(|h| h.get("x-customer-id"));
+        let fingerprint = json!({
+            "params": request.params,
+            "body": request.body,
+            "customer": customer,
+        });
+
+        let usecase = AcquireIdempotencyKeyUseCase::new(self.idempotency_repository.clone());
+        let lookup = usecase
+            .execute(self.scope.clone(), key.clone(), fingerprint, self.ttl_secs)
+            .await;
+
+        match lookup {
+            Ok(IdempotencyLookup::Acquired) => {}
+            Ok(IdempotencyLookup::Replay { status, body }) => {
+                log::info!("Replaying response for Idempotency-Key {}", key);
+                return AdapterResponse {
+                    status: StatusCode::from(status),
+                    data: body,
+                    binary: None,
+                };
+            }
+            Ok(IdempotencyLookup::InProgress) => {
+                return AdapterResponse {
+                    status: StatusCode::Conflict,
+                    data: json!({"error": "A request with this Idempotency-Key is already in progress"}),
+                    binary: None,
+                };
+            }
+            Ok(IdempotencyLookup::Mismatch) => {
+                return AdapterResponse {
+                    status: StatusCode::UnprocessableEntity,
+                    data: json!({"error": "Idempotency-Key already used with a different request"}),
+                    binary: None,
+                };
+            }
+            Err(e) if e.starts_with("Idempotency-Key") => {
+                return AdapterResponse {
+                    status: StatusCode::BadRequest,
+                    data: json!({"error": e}),
+                    binary: None,
+                };
+            }
+            Err(e) => {
+                log::error!("Failed to acquire Idempotency-Key {}: {}", key, e);
+                return AdapterResponse {
+                    status: StatusCode::InternalServerError,
+                    data: json!({"error": e}),
+                    binary: None,
+                };
+            }
+        }
+
+        let response = self.inner.handle(request).await;
+
+        let usecase = CompleteIdempotencyKeyUseCase::new(self.idempotency_repository.clone());
+        if let Err(e) = usecase
+            .execute(
+                self.scope.clone(),
+                key.clone(),
+                response.status as u16,
+                response.data.clone(),
+            )
+            .await
+        {
+            log::error!(
+                "Failed to store response for Idempotency-Key {}: {}",
+                key,
+                e
+            );
+        }
+
+        response
+    }
+}
@@ -1 +1,5 @@
+pub mod etag;
 pub mod generic_handler;
+pub mod idempotent_handler;
+pub mod period;
+pub mod validation;
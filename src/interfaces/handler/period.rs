@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+use crate::domain::entity::discount_entity::DiscountPeriod;
+
+/// Reads the optional `from` and `to` query parameters as RFC 3339 instants.
+pub fn parse_period(query: &Value) -> Result<DiscountPeriod, String> {
+    let parse = |key: &str| match query.get(key).and_then(|v| v.as_str()) {
+        Some(raw) => DateTime::parse_from_rfc3339(raw)
+            .map(|dt| Some(dt.with_timezone(&Utc)))
+            .map_err(|_| format!("Invalid '{}' date, expected RFC 3339", key)),
+        None => Ok(None),
+    };
+
+    Ok(DiscountPeriod {
+        from: parse("from")?,
+        to: parse("to")?,
+    })
+}
@@ -0,0 +1,41 @@
+use serde_json::json;
+
+use crate::{
+    domain::utils::validation::{FieldError, VALIDATION_FAILED, parse_validation_error},
+    interfaces::handler::generic_handler::{AdapterResponse, StatusCode},
+};
+
+/// Whether `error` carries field errors, from `validation_error`.
+pub fn is_validation_error(error: &str) -> bool {
+    parse_validation_error(error).is_some()
+}
+
+/// The 400 for an error carrying field errors.
+pub fn validation_response(error: &str) -> AdapterResponse {
+    fields_response(
+        VALIDATION_FAILED,
+        parse_validation_error(error).unwrap_or_default(),
+    )
+}
+
+/// What is wrong with a listing's `page` and `limit`, both counted from 1.
+pub fn pagination_errors(page: u32, limit: u32) -> Vec<FieldError> {
+    [("page", page), ("limit", limit)]
+        .into_iter()
+        .filter(|(_, value)| *value == 0)
+        .map(|(field, _)| {
+            let mut error = FieldError::new(field, "range", "Must be at least 1");
+            error.params.insert("min".to_string(), json!(1));
+            error
+        })
+        .collect()
+}
+
+/// A 400 listing what is wrong with each field.
+pub fn fields_response(message: &str, fields: Vec<FieldError>) -> AdapterResponse {
+    AdapterResponse {
+        status: StatusCode::BadRequest,
+        data: json!({"error": message, "fields": fields}),
+        binary: None,
+    }
+}
@@ -0,0 +1,149 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::{
+    fixtures::{apply, create_coupon, create_product},
+    start_server::init_tracing,
+};
+
+#[tokio::test]
+#[serial]
+async fn test_01_campaign_budget_is_charged_and_closes_when_exhausted() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+    let campaigns_url = format!("http://localhost:{}/api/v1/campaigns", port);
+
+    let campaign: Value = client
+        .post(&campaigns_url)
+        .json(&json!({
+            "name": "Black Friday",
+            "starts_at": "2025-01-01T00:00:00Z",
+            "ends_at": "2029-12-31T23:59:59Z",
+            "budget": 300
+        }))
+        .send()
+        .await
+        .expect("Failed to create campaign")
+        .json()
+        .await
+        .unwrap();
+    let campaign_id = campaign["id"].as_str().expect("Campaign ID missing");
+    assert_eq!(campaign["status"], "active");
+    assert_eq!(campaign["remaining_budget"], 300);
+
+    let big_coupon = create_coupon(
+        &client,
+        port,
+        json!({ "value": 200, "campaign_id": campaign_id }),
+    )
+    .await;
+    let first_product = create_product(&client, port, json!({})).await;
+    let second_product = create_product(&client, port, json!({})).await;
+
+    assert_eq!(
+        apply(&client, port, &first_product, &big_coupon).await,
+        StatusCode::NO_CONTENT
+    );
+
+    let campaign: Value = client
+        .get(format!("{}/{}", campaigns_url, campaign_id))
+        .send()
+        .await
+        .expect("Failed to fetch campaign")
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(campaign["spent"], 200);
+    assert_eq!(campaign["remaining_budget"], 100);
+
+    // 200 more would exceed the 100 left in the budget.
+    assert_eq!(
+        apply(&client, port, &second_product, &big_coupon).await,
+        StatusCode::UNPROCESSABLE_ENTITY
+    );
+
+    let small_coupon = create_coupon(&client, port, json!({ "campaign_id": campaign_id })).await;
+    assert_eq!(
+        apply(&client, port, &second_product, &small_coupon).await,
+        StatusCode::NO_CONTENT
+    );
+
+    let campaign: Value = client
+        .get(format!("{}/{}", campaigns_url, campaign_id))
+        .send()
+        .await
+        .expect("Failed to fetch campaign")
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(campaign["spent"], 300);
+    assert_eq!(campaign["remaining_budget"], 0);
+    assert_eq!(campaign["status"], "closed");
+    assert!(campaign["closed_at"].is_string());
+
+    let closed: Value = client
+        .get(format!("{}?status=closed&limit=50", campaigns_url))
+        .send()
+        .await
+        .expect("Failed to list campaigns")
+        .json()
+        .await
+        .unwrap();
+    assert!(
+        closed["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|c| c["id"] == campaign_id)
+    );
+
+    let orphan = client
+        .post(format!("http://localhost:{}/api/v1/coupons", port))
+        .json(&json!({
+            "code": format!("ORPH{}", &Uuid::new_v4().simple().to_string()[..12]),
+            "type": "fixed",
+            "value": 100,
+            "one_shot": false,
+            "valid_from": "2025-01-01T00:00:00Z",
+            "valid_until": "2029-12-31T23:59:59Z",
+            "campaign_id": Uuid::new_v4()
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(orphan.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = client
+        .post(&campaigns_url)
+        .json(&json!({
+            "name": " ",
+            "starts_at": "2029-01-01T00:00:00Z",
+            "ends_at": "2025-01-01T00:00:00Z",
+            "budget": 0
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = response.json().await.unwrap();
+    let fields: Vec<&str> = body["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["field"].as_str().unwrap())
+        .collect();
+    assert_eq!(fields, ["name", "budget", "ends_at"]);
+    assert_eq!(
+        body["errors"][0]["message"],
+        "Length must be between 1 and 100"
+    );
+}
@@ -0,0 +1 @@
+pub mod budget_campaign_test;
@@ -0,0 +1,101 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::json;
+use serial_test::serial;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::utils::start_server::init_tracing;
+
+#[tokio::test]
+#[serial]
+async fn test_17_batch_generate_coupons_returns_csv() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+    let url = format!("http://localhost:{}/api/v1/coupons/batch-generate", port);
+
+    let prefix = format!("B{}", &Uuid::new_v4().simple().to_string()[..5]).to_uppercase();
+    let response = client
+        .post(&url)
+        .json(&json!({
+            "template": {
+                "type": "percent",
+                "value": 1500,
+                "valid_from": "2025-01-01T00:00:00Z",
+                "valid_until": "2029-12-31T23:59:59Z",
+                "max_uses": 3
+            },
+            "count": 50,
+            "prefix": prefix,
+            "code_length": 8
+        }))
+        .send()
+        .await
+        .expect("Failed to generate coupons");
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(
+        response.headers()["content-type"].to_str().unwrap(),
+        "text/csv; charset=utf-8"
+    );
+
+    let csv = response.text().await.unwrap();
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next(),
+        Some("code,type,value,valid_from,valid_until,max_uses")
+    );
+
+    let codes: Vec<String> = lines
+        .map(|line| line.split(',').next().unwrap().to_string())
+        .collect();
+    assert_eq!(codes.len(), 50);
+    assert_eq!(codes.iter().collect::<HashSet<_>>().len(), 50);
+    for code in &codes {
+        assert!(code.starts_with(&prefix));
+        assert_eq!(code.len(), prefix.len() + 8);
+        assert!(
+            !code[prefix.len()..].contains(['0', 'O', '1', 'I']),
+            "ambiguous character in {}",
+            code
+        );
+    }
+
+    let coupon: serde_json::Value = client
+        .get(format!(
+            "http://localhost:{}/api/v1/coupons/{}",
+            port, codes[0]
+        ))
+        .send()
+        .await
+        .expect("Failed to fetch generated coupon")
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(coupon["value"], 1500);
+    assert_eq!(coupon["max_uses"], 3);
+
+    let too_short = client
+        .post(&url)
+        .json(&json!({
+            "template": {
+                "type": "fixed",
+                "value": 500,
+                "valid_from": "2025-01-01T00:00:00Z",
+                "valid_until": "2029-12-31T23:59:59Z"
+            },
+            "count": 200,
+            "prefix": prefix,
+            "code_length": 1
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(too_short.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,92 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode, header};
+use serde_json::json;
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::start_server::init_tracing;
+
+#[tokio::test]
+#[serial]
+async fn test_12_coupon_etag_and_if_match() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+
+    let code = format!("ETAG{}", &Uuid::new_v4().simple().to_string()[..12]);
+    let create_url = format!("http://localhost:{}/api/v1/coupons", port);
+    let create_response = client
+        .post(&create_url)
+        .json(&json!({
+            "code": code,
+            "type": "fixed",
+            "value": 500,
+            "one_shot": false,
+            "valid_from": "2025-01-01T00:00:00Z",
+            "valid_until": "2026-12-31T23:59:59Z",
+            "max_uses": 10
+        }))
+        .send()
+        .await
+        .expect("Failed to create coupon");
+    assert_eq!(create_response.status(), StatusCode::CREATED);
+
+    let coupon_url = format!("http://localhost:{}/api/v1/coupons/{}", port, code);
+    let get_response = client
+        .get(&coupon_url)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(get_response.status(), StatusCode::OK);
+    let etag = get_response
+        .headers()
+        .get(header::ETAG)
+        .expect("ETag missing")
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let update = client
+        .patch(&coupon_url)
+        .header(header::IF_MATCH, &etag)
+        .json(&json!({ "value": 700 }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(update.status(), StatusCode::OK);
+
+    let stale_update = client
+        .patch(&coupon_url)
+        .header(header::IF_MATCH, &etag)
+        .json(&json!({ "value": 900 }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(stale_update.status(), StatusCode::PRECONDITION_FAILED);
+
+    let body: serde_json::Value = stale_update.json().await.unwrap();
+    assert_eq!(body["detail"], "Version mismatch");
+
+    let stale_delete = client
+        .delete(&coupon_url)
+        .header(header::IF_MATCH, &etag)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(stale_delete.status(), StatusCode::PRECONDITION_FAILED);
+
+    let coupon: serde_json::Value = client
+        .get(&coupon_url)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(coupon["value"], 700);
+}
@@ -15,7 +15,7 @@ async fn test_01_create_coupon_success() {
     let client = Client::new();
     let url = format!("http://localhost:{}/api/v1/coupons", port);
 
-    let code = format!("PROMO-{}", uuid::Uuid::new_v4());
+    let code = format!("PROMO{}", &uuid::Uuid::new_v4().simple().to_string()[..12]);
 
     let coupon_data = json!({
         "code": code,
@@ -56,9 +56,15 @@ async fn test_02_create_coupon_missing_fields() {
 
     let body: serde_json::Value = response.json().await.unwrap();
     println!("{}", body);
-    assert_eq!(body["error"], "Missing required fields");
-
-    let fields = body["fields"].as_array().unwrap();
+    assert_eq!(body["detail"], "Missing required fields");
+
+    assert_eq!(body["title"], "Validation Error");
+    let fields: Vec<serde_json::Value> = body["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["field"].clone())
+        .collect();
     assert!(fields.contains(&json!("code")));
     assert!(fields.contains(&json!("type")));
     assert!(fields.contains(&json!("value")));
@@ -87,8 +93,9 @@ async fn test_03_create_coupon_invalid_types() {
     assert_eq!(response.status(), ReqwestStatusCode::BAD_REQUEST);
 
     let body: serde_json::Value = response.json().await.unwrap();
-    assert_eq!(body["error"], "Invalid field types");
-    assert!(body["details"].as_str().unwrap().contains("invalid type"));
+    let detail = body["detail"].as_str().unwrap();
+    assert!(detail.starts_with("Invalid field types: "));
+    assert!(detail.contains("invalid type"));
 }
 
 #[tokio::test]
@@ -100,7 +107,10 @@ async fn test_04_create_coupon_conflict() {
     let client = Client::new();
     let url = format!("http://localhost:{}/api/v1/coupons", port);
 
-    let code = format!("PROMO20-{}", uuid::Uuid::new_v4());
+    let code = format!(
+        "PROMO20{}",
+        &uuid::Uuid::new_v4().simple().to_string()[..12]
+    );
 
     let coupon_data = json!({
         "code": code,
@@ -0,0 +1,117 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::{fixtures::create_product, start_server::init_tracing};
+
+#[tokio::test]
+#[serial]
+async fn test_15_coupon_usage_limits() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+    let coupons_url = format!("http://localhost:{}/api/v1/coupons", port);
+    let apply_url = |product_id: &str| {
+        format!(
+            "http://localhost:{}/api/v1/products/{}/discount/coupon",
+            port, product_id
+        )
+    };
+
+    let per_customer_code = format!("CUST{}", &Uuid::new_v4().simple().to_string()[..12]);
+    let create_response = client
+        .post(&coupons_url)
+        .json(&json!({
+            "code": per_customer_code,
+            "type": "fixed",
+            "value": 100,
+            "one_shot": false,
+            "valid_from": "2025-01-01T00:00:00Z",
+            "valid_until": "2029-12-31T23:59:59Z",
+            "max_uses": 10,
+            "max_uses_per_customer": 1
+        }))
+        .send()
+        .await
+        .expect("Failed to create coupon");
+    assert_eq!(create_response.status(), StatusCode::CREATED);
+    let coupon: Value = create_response.json().await.unwrap();
+    assert_eq!(coupon["max_uses_per_customer"], 1);
+
+    let first = client
+        .post(apply_url(&create_product(&client, port, json!({})).await))
+        .header("X-Customer-Id", "customer-1")
+        .json(&json!({ "code": per_customer_code }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(first.status(), StatusCode::NO_CONTENT);
+
+    let second = client
+        .post(apply_url(&create_product(&client, port, json!({})).await))
+        .json(&json!({ "code": per_customer_code, "customer_id": "customer-1" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(second.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: Value = second.json().await.unwrap();
+    assert_eq!(body["detail"], "Customer usage limit reached");
+
+    let other_customer = client
+        .post(apply_url(&create_product(&client, port, json!({})).await))
+        .header("X-Customer-Id", "customer-2")
+        .json(&json!({ "code": per_customer_code }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(other_customer.status(), StatusCode::NO_CONTENT);
+
+    let anonymous = client
+        .post(apply_url(&create_product(&client, port, json!({})).await))
+        .json(&json!({ "code": per_customer_code }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(anonymous.status(), StatusCode::BAD_REQUEST);
+
+    let one_shot_code = format!("ONCE{}", &Uuid::new_v4().simple().to_string()[..12]);
+    let create_response = client
+        .post(&coupons_url)
+        .json(&json!({
+            "code": one_shot_code,
+            "type": "fixed",
+            "value": 100,
+            "one_shot": true,
+            "valid_from": "2025-01-01T00:00:00Z",
+            "valid_until": "2029-12-31T23:59:59Z"
+        }))
+        .send()
+        .await
+        .expect("Failed to create coupon");
+    assert_eq!(create_response.status(), StatusCode::CREATED);
+
+    let first = client
+        .post(apply_url(&create_product(&client, port, json!({})).await))
+        .json(&json!({ "code": one_shot_code }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(first.status(), StatusCode::NO_CONTENT);
+
+    let second = client
+        .post(apply_url(&create_product(&client, port, json!({})).await))
+        .json(&json!({ "code": one_shot_code }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(second.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: Value = second.json().await.unwrap();
+    assert_eq!(body["detail"], "Coupon usage limit reached");
+}
@@ -18,7 +18,7 @@ async fn test_10_delete_coupon_success() {
         .expect("PORT must be a number");
     let client = Client::new();
 
-    let code = format!("del-{}", Uuid::new_v4());
+    let code = format!("del{}", &Uuid::new_v4().simple().to_string()[..12]);
     let create_url = format!("http://localhost:{}/api/v1/coupons", port);
     let coupon_data = json!({
         "code": code,
@@ -79,5 +79,5 @@ async fn test_11_delete_coupon_not_found() {
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 
     let response_body: serde_json::Value = response.json().await.expect("Failed to parse response");
-    assert_eq!(response_body["error"], "Coupon not found");
+    assert_eq!(response_body["detail"], "Coupon not found");
 }
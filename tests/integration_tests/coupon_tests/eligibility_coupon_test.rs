@@ -0,0 +1,91 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::{fixtures::create_product, start_server::init_tracing};
+
+#[tokio::test]
+#[serial]
+async fn test_16_coupon_eligibility_rules() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+    let coupons_url = format!("http://localhost:{}/api/v1/coupons", port);
+
+    let allowed = create_product(&client, port, json!({ "price": 5000 })).await;
+    let cheap = create_product(&client, port, json!({ "price": 500 })).await;
+    let other = create_product(&client, port, json!({ "price": 5000 })).await;
+
+    let code = format!("ELIG{}", &Uuid::new_v4().simple().to_string()[..12]);
+    let create_response = client
+        .post(&coupons_url)
+        .json(&json!({
+            "code": code,
+            "type": "fixed",
+            "value": 100,
+            "one_shot": false,
+            "valid_from": "2025-01-01T00:00:00Z",
+            "valid_until": "2029-12-31T23:59:59Z",
+            "max_uses": 10,
+            "allowed_product_ids": [allowed, cheap],
+            "min_product_price": 1000
+        }))
+        .send()
+        .await
+        .expect("Failed to create coupon");
+    assert_eq!(create_response.status(), StatusCode::CREATED);
+    let coupon: Value = create_response.json().await.unwrap();
+    assert_eq!(coupon["min_product_price"], 1000);
+    assert_eq!(coupon["exclude_discounted"], false);
+
+    let apply = |product_id: &str| {
+        client
+            .post(format!(
+                "http://localhost:{}/api/v1/products/{}/discount/coupon",
+                port, product_id
+            ))
+            .json(&json!({ "code": code }))
+            .send()
+    };
+
+    let not_allowed = apply(&other).await.unwrap();
+    assert_eq!(not_allowed.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: Value = not_allowed.json().await.unwrap();
+    assert_eq!(body["reason"], "product_not_allowed");
+
+    let too_cheap = apply(&cheap).await.unwrap();
+    assert_eq!(too_cheap.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: Value = too_cheap.json().await.unwrap();
+    assert_eq!(body["reason"], "below_min_price");
+
+    let update = client
+        .patch(format!("{}/{}", coupons_url, code))
+        .json(&json!({ "denied_product_ids": [allowed] }))
+        .send()
+        .await
+        .expect("Failed to update coupon");
+    assert_eq!(update.status(), StatusCode::OK);
+
+    let denied = apply(&allowed).await.unwrap();
+    assert_eq!(denied.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: Value = denied.json().await.unwrap();
+    assert_eq!(body["reason"], "product_excluded");
+
+    let update = client
+        .patch(format!("{}/{}", coupons_url, code))
+        .json(&json!({ "denied_product_ids": [], "min_product_price": 0 }))
+        .send()
+        .await
+        .expect("Failed to update coupon");
+    assert_eq!(update.status(), StatusCode::OK);
+
+    let applied = apply(&cheap).await.unwrap();
+    assert_eq!(applied.status(), StatusCode::NO_CONTENT);
+}
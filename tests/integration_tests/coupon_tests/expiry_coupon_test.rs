@@ -0,0 +1,166 @@
+use chrono::Utc;
+use isi_dev::{
+    application::repository::discount_postgres_repository::PostgresDiscountRepository,
+    domain::{
+        entity::discount_entity::RemovalReason, repository::discount_repository::DiscountRepository,
+    },
+};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use serial_test::serial;
+use sqlx::postgres::PgPoolOptions;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::utils::start_server::init_tracing;
+
+async fn create_applied_coupon(client: &Client, port: u16) -> (String, String) {
+    let product: Value = client
+        .post(format!("http://localhost:{}/api/v1/products", port))
+        .json(&json!({
+            "name": format!("Expiring {}", Uuid::new_v4()),
+            "stock": 10,
+            "price": 1000
+        }))
+        .send()
+        .await
+        .expect("Failed to create product")
+        .json()
+        .await
+        .unwrap();
+    let product_id = product["id"].as_str().unwrap().to_string();
+
+    let code = format!("DEAD{}", &Uuid::new_v4().simple().to_string()[..12]);
+    let response = client
+        .post(format!("http://localhost:{}/api/v1/coupons", port))
+        .json(&json!({
+            "code": code,
+            "type": "fixed",
+            "value": 100,
+            "one_shot": false,
+            "valid_from": "2025-01-01T00:00:00Z",
+            "valid_until": "2029-12-31T23:59:59Z"
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let apply = client
+        .post(format!(
+            "http://localhost:{}/api/v1/products/{}/discount/coupon",
+            port, product_id
+        ))
+        .json(&json!({ "code": code }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(apply.status(), StatusCode::NO_CONTENT);
+
+    (product_id, code)
+}
+
+#[tokio::test]
+#[serial]
+async fn test_22_expiry_job_closes_applications_of_dead_coupons() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+    let pool = PgPoolOptions::new()
+        .max_connections(2)
+        .connect(&dotenv::var("DATABASE_URL").unwrap())
+        .await
+        .expect("Failed to connect to the database");
+    let repository = PostgresDiscountRepository::new(Arc::new(pool));
+
+    let (deleted_product, deleted_code) = create_applied_coupon(&client, port).await;
+    let delete = client
+        .delete(format!(
+            "http://localhost:{}/api/v1/coupons/{}",
+            port, deleted_code
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert!(delete.status().is_success());
+
+    let (expired_product, expired_code) = create_applied_coupon(&client, port).await;
+    let expire = client
+        .patch(format!(
+            "http://localhost:{}/api/v1/coupons/{}",
+            port, expired_code
+        ))
+        .json(&json!({
+            "valid_from": "2020-01-01T00:00:00Z",
+            "valid_until": "2021-01-01T00:00:00Z"
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert!(expire.status().is_success());
+
+    // Two concurrent runs, as from two instances, close each application once.
+    let (first, second) = tokio::join!(
+        repository.close_dead_applications(Utc::now()),
+        repository.close_dead_applications(Utc::now())
+    );
+    let closed: Vec<_> = first.unwrap().into_iter().chain(second.unwrap()).collect();
+
+    let reason_for = |product_id: &str| {
+        let matches: Vec<_> = closed
+            .iter()
+            .filter(|a| a.product_id.to_string() == product_id)
+            .collect();
+        assert_eq!(matches.len(), 1);
+        matches[0].removal_reason
+    };
+    assert_eq!(
+        reason_for(&deleted_product),
+        Some(RemovalReason::CouponDeleted)
+    );
+    assert_eq!(reason_for(&expired_product), Some(RemovalReason::Expired));
+
+    for product_id in [&deleted_product, &expired_product] {
+        let product: Value = client
+            .get(format!(
+                "http://localhost:{}/api/v1/products/{}",
+                port, product_id
+            ))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        let listing: Value = client
+            .get(format!("http://localhost:{}/api/v1/products", port))
+            .query(&[
+                ("has_discount", "true"),
+                ("search", product["name"].as_str().unwrap()),
+            ])
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        assert_eq!(listing["data"], json!([]));
+    }
+
+    let rerun = repository
+        .close_dead_applications(Utc::now())
+        .await
+        .unwrap();
+    assert!(
+        rerun
+            .iter()
+            .all(|a| a.product_id.to_string() != deleted_product
+                && a.product_id.to_string() != expired_product)
+    );
+}
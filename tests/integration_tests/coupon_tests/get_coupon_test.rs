@@ -19,7 +19,7 @@ async fn test_05_get_coupon_by_code_success() {
 
     let client = Client::new();
 
-    let code = format!("PROMO-{}", Uuid::new_v4());
+    let code = format!("PROMO{}", &Uuid::new_v4().simple().to_string()[..12]);
 
     let create_url = format!("http://localhost:{}/api/v1/coupons", port);
     let coupon_data = json!({
@@ -90,5 +90,5 @@ async fn test_06_get_coupon_by_code_not_found() {
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 
     let response_body: serde_json::Value = response.json().await.expect("Failed to parse response");
-    assert_eq!(response_body["error"], "Coupon not found");
+    assert_eq!(response_body["detail"], "Coupon not found");
 }
@@ -0,0 +1,111 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::json;
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::{fixtures::create_product, start_server::init_tracing};
+
+#[tokio::test]
+#[serial]
+async fn test_13_apply_coupon_retry_consumes_one_use() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+
+    let code = format!("IDEM{}", &Uuid::new_v4().simple().to_string()[..12]);
+    let coupon_data = json!({
+        "code": code,
+        "type": "fixed",
+        "value": 100,
+        "one_shot": false,
+        "valid_from": "2025-01-01T00:00:00Z",
+        "valid_until": "2029-12-31T23:59:59Z",
+        "max_uses": 10
+    });
+    let create_key = Uuid::new_v4().to_string();
+    let coupons_url = format!("http://localhost:{}/api/v1/coupons", port);
+
+    for _ in 0..2 {
+        let response = client
+            .post(&coupons_url)
+            .header("Idempotency-Key", &create_key)
+            .json(&coupon_data)
+            .send()
+            .await
+            .expect("Failed to create coupon");
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    let product: serde_json::Value = client
+        .post(format!("http://localhost:{}/api/v1/products", port))
+        .json(&json!({
+            "name": format!("Café Cupom {}", Uuid::new_v4()),
+            "stock": 10,
+            "price": 1000
+        }))
+        .send()
+        .await
+        .expect("Failed to create product")
+        .json()
+        .await
+        .unwrap();
+    let product_id = product["id"].as_str().expect("Product ID missing");
+
+    let apply_url = format!(
+        "http://localhost:{}/api/v1/products/{}/discount/coupon",
+        port, product_id
+    );
+    let apply_key = Uuid::new_v4().to_string();
+
+    for _ in 0..2 {
+        let response = client
+            .post(&apply_url)
+            .header("Idempotency-Key", &apply_key)
+            .json(&json!({ "code": code }))
+            .send()
+            .await
+            .expect("Failed to apply coupon");
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    let coupon: serde_json::Value = client
+        .get(format!("{}/{}", coupons_url, code))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(coupon["uses_count"], 1);
+
+    // The same key and body from another customer is a different request.
+    let other_product = create_product(&client, port, json!({})).await;
+    let other_apply_url = format!(
+        "http://localhost:{}/api/v1/products/{}/discount/coupon",
+        port, other_product
+    );
+    let customer_key = Uuid::new_v4().to_string();
+    let apply_as = |customer: &'static str| {
+        client
+            .post(&other_apply_url)
+            .header("Idempotency-Key", &customer_key)
+            .header("X-Customer-Id", customer)
+            .json(&json!({ "code": code }))
+            .send()
+    };
+
+    let response = apply_as("customer-a")
+        .await
+        .expect("Failed to apply coupon");
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    let response = apply_as("customer-b")
+        .await
+        .expect("Failed to apply coupon");
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
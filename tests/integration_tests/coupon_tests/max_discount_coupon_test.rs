@@ -0,0 +1,120 @@
+use isi_dev::domain::{entity::coupon_entity::Coupon, pricing::pricing_engine::PricingEngine};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::{fixtures::create_product, start_server::init_tracing};
+
+#[tokio::test]
+#[serial]
+async fn test_21_percent_coupon_respects_max_discount_amount() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+    let code = format!("CAP{}", &Uuid::new_v4().simple().to_string()[..12]);
+
+    let coupon = json!({
+        "code": code,
+        "type": "percent",
+        "value": 4000,
+        "one_shot": false,
+        "valid_from": "2025-01-01T00:00:00Z",
+        "valid_until": "2029-12-31T23:59:59Z",
+        "max_discount_amount": 5000
+    });
+    let response = client
+        .post(format!("http://localhost:{}/api/v1/coupons", port))
+        .json(&coupon)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["max_discount_amount"], 5000);
+
+    // The cap applies to the line as a whole.
+    let pricing = PricingEngine::default();
+    let created: Coupon = serde_json::from_value(body).unwrap();
+    let line = created.line_total(&pricing, 20000, 1).unwrap();
+    assert_eq!((line.discount, line.capped), (5000, true));
+    let line = created.line_total(&pricing, 10000, 2).unwrap();
+    assert_eq!((line.discount, line.capped), (5000, true));
+    let line = created.line_total(&pricing, 1000, 3).unwrap();
+    assert_eq!((line.discount, line.capped), (1200, false));
+
+    let mut invalid = coupon.clone();
+    invalid["code"] = json!(format!("CAP{}", &Uuid::new_v4().simple().to_string()[..12]));
+    invalid["max_discount_amount"] = json!(0);
+    let response = client
+        .post(format!("http://localhost:{}/api/v1/coupons", port))
+        .json(&invalid)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // 40% of 1000 stays under the cap.
+    let cheap_id = create_product(&client, port, json!({})).await;
+    let preview: Value = client
+        .post(format!(
+            "http://localhost:{}/api/v1/coupons/{}/validate",
+            port, code
+        ))
+        .json(&json!({ "product_id": cheap_id }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(preview["discount_amount"], 400);
+    assert_eq!(preview["capped"], false);
+
+    // 40% of 100000 would be 40000.
+    let product_id = create_product(&client, port, json!({ "price": 100000 })).await;
+    let apply = client
+        .post(format!(
+            "http://localhost:{}/api/v1/products/{}/discount/coupon",
+            port, product_id
+        ))
+        .json(&json!({ "code": code }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(apply.status(), StatusCode::NO_CONTENT);
+
+    let product: Value = client
+        .get(format!(
+            "http://localhost:{}/api/v1/products/{}",
+            port, product_id
+        ))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(product["final_price"], 95000);
+    assert_eq!(product["discounts"][0]["amount"], 5000);
+    assert_eq!(product["discounts"][0]["capped"], true);
+
+    let redemptions: Value = client
+        .get(format!(
+            "http://localhost:{}/api/v1/coupons/{}/redemptions",
+            port, code
+        ))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(redemptions["data"][0]["discount_amount"], 5000);
+}
@@ -1,4 +1,18 @@
+pub mod batch_generate_coupon_test;
+pub mod concurrency_coupon_test;
 pub mod create_coupon_test;
+pub mod customer_limit_coupon_test;
 pub mod delete_coupon_test;
+pub mod eligibility_coupon_test;
+pub mod expiry_coupon_test;
 pub mod get_coupon_test;
+pub mod idempotency_coupon_test;
+pub mod max_discount_coupon_test;
+pub mod quantity_coupon_test;
+pub mod rate_limit_coupon_test;
+pub mod reconcile_coupon_test;
+pub mod redemption_coupon_test;
+pub mod stacking_coupon_test;
 pub mod update_coupon_test;
+pub mod validate_coupon_test;
+pub mod validation_coupon_test;
@@ -0,0 +1,135 @@
+use isi_dev::domain::{
+    entity::coupon_entity::Coupon,
+    pricing::{pricing_engine::PricingEngine, rounding_policy::RoundingPolicy},
+};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use serial_test::serial;
+
+use crate::utils::{fixtures::post_coupon, start_server::init_tracing};
+
+#[tokio::test]
+#[serial]
+async fn test_20_bogo_and_tiered_coupons_price_by_quantity() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+    let pricing = PricingEngine::default();
+
+    // Buy 2, get the 3rd free.
+    let (status, bogo) = post_coupon(
+        &client,
+        port,
+        json!({
+            "type": "bogo",
+            "value": 10000,
+            "params": { "buy_quantity": 2, "get_quantity": 1 }
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(bogo["type"], "bogo");
+    assert_eq!(bogo["params"]["buy_quantity"], 2);
+
+    let bogo: Coupon = serde_json::from_value(bogo).unwrap();
+    assert_eq!(bogo.line_total(&pricing, 1000, 2).unwrap().discount, 0);
+    assert_eq!(bogo.line_total(&pricing, 1000, 3).unwrap().discount, 1000);
+    assert_eq!(bogo.line_total(&pricing, 1000, 7).unwrap().total, 5000);
+    let price = u64::MAX / 3;
+    assert_eq!(bogo.line_total(&pricing, price, 3).unwrap().discount, price);
+    assert!(bogo.line_total(&pricing, u64::MAX / 2, 3).is_err());
+
+    let (status, tiered) = post_coupon(
+        &client,
+        port,
+        json!({
+            "type": "tiered",
+            "value": 1000,
+            "params": { "tiers": [
+                { "min_quantity": 2, "percent": 500 },
+                { "min_quantity": 5, "percent": 1000 }
+            ] }
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let tiered: Coupon = serde_json::from_value(tiered).unwrap();
+    assert_eq!(tiered.line_total(&pricing, 1000, 1).unwrap().discount, 0);
+    assert_eq!(tiered.line_total(&pricing, 1000, 2).unwrap().discount, 100);
+    assert_eq!(tiered.line_total(&pricing, 1000, 6).unwrap().total, 5400);
+    // Line discounts follow the engine's rounding policy.
+    let round_up = PricingEngine {
+        rounding: RoundingPolicy::Up,
+        ..pricing
+    };
+    assert_eq!(tiered.line_total(&pricing, 999, 2).unwrap().discount, 99);
+    assert_eq!(tiered.line_total(&round_up, 999, 2).unwrap().discount, 100);
+
+    // Switching to percent drops the tiers.
+    let coupon_url = format!("http://localhost:{}/api/v1/coupons/{}", port, tiered.code);
+    let response = client
+        .patch(&coupon_url)
+        .json(&json!({ "type": "percent", "value": 1000, "params": null }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let coupon: Value = client
+        .get(&coupon_url)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(coupon["type"], "percent");
+    assert_eq!(coupon["params"], Value::Null);
+    let percent: Coupon = serde_json::from_value(coupon).unwrap();
+    assert_eq!(percent.line_total(&pricing, 1000, 2).unwrap().discount, 200);
+
+    let (status, body) = post_coupon(
+        &client,
+        port,
+        json!({
+            "type": "tiered",
+            "value": 500,
+            "params": { "tiers": [
+                { "min_quantity": 5, "percent": 1000 },
+                { "min_quantity": 2, "percent": 500 }
+            ] }
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["errors"][0]["field"], "value");
+    assert_eq!(
+        body["errors"][0]["message"],
+        "tiers must increase in min_quantity and percent"
+    );
+
+    let (status, _) = post_coupon(&client, port, json!({ "type": "bogo", "value": 10000 })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, body) = post_coupon(
+        &client,
+        port,
+        json!({
+            "type": "bogo",
+            "value": 10000,
+            "params": { "buy_quantity": 4_000_000_000u32, "get_quantity": 1 }
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(
+        body["errors"][0]["message"],
+        "buy_quantity and get_quantity must be between 1 and 999999"
+    );
+}
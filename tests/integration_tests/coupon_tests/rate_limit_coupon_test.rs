@@ -0,0 +1,119 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, RequestBuilder, StatusCode, header};
+use serde_json::{Value, json};
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::start_server::init_tracing;
+
+/// Spends the bucket `request` draws from and returns the response that
+/// was turned away. Coupon validation is limited by `RATE_LIMITS` in the
+/// `.env`.
+async fn exhaust(request: impl Fn() -> RequestBuilder) -> reqwest::Response {
+    let first = request().send().await.unwrap();
+    assert_eq!(first.status(), StatusCode::NOT_FOUND);
+    let limit: u32 = first.headers()["ratelimit-limit"]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert_eq!(
+        first.headers()["ratelimit-remaining"],
+        (limit - 1).to_string()
+    );
+
+    for _ in 1..limit {
+        let response = request().send().await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+    let response = request().send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert_eq!(response.headers()["ratelimit-remaining"], "0");
+    response
+}
+
+#[tokio::test]
+#[serial]
+async fn test_24_coupon_validation_is_rate_limited_per_client() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+
+    let url = format!(
+        "http://localhost:{}/api/v1/coupons/NOPE{}/validate",
+        port,
+        &Uuid::new_v4().simple().to_string()[..12]
+    );
+    let body = json!({ "product_id": Uuid::new_v4() });
+
+    // A key the server doesn't know counts as no key: clients behind a
+    // trusted proxy are told apart by X-Forwarded-For.
+    let bytes = Uuid::new_v4().into_bytes();
+    let forwarded_for = |host: u8| format!("198.18.{}.{}, 127.0.0.1", bytes[0], host);
+    let response = exhaust(|| {
+        client
+            .post(&url)
+            .header("x-forwarded-for", forwarded_for(1))
+            .header("x-api-key", Uuid::new_v4().to_string())
+            .json(&body)
+    })
+    .await;
+    let retry_after: u64 = response.headers()[header::RETRY_AFTER]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(retry_after >= 1);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/problem+json"
+    );
+    let problem: Value = response.json().await.unwrap();
+    assert_eq!(problem["type"], "/problems/too-many-requests");
+    assert_eq!(problem["status"], 429);
+
+    let response = client
+        .post(&url)
+        .header("x-forwarded-for", forwarded_for(1))
+        .bearer_auth(Uuid::new_v4().to_string())
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+    // A configured key has a bucket of its own, as does another address.
+    let api_key = dotenv::var("RATE_LIMIT_API_KEYS").unwrap();
+    let api_key = api_key.split(',').next().unwrap().trim();
+    let response = client
+        .post(&url)
+        .header("x-forwarded-for", forwarded_for(1))
+        .header("x-api-key", api_key)
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let response = client
+        .post(&url)
+        .header("x-forwarded-for", forwarded_for(2))
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // Routes without a rule are not limited.
+    let response = client
+        .get(format!("http://localhost:{}/api/v1/health", port))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get("ratelimit-limit"), None);
+}
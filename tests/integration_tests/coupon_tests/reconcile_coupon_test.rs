@@ -0,0 +1,79 @@
+use isi_dev::{
+    application::repository::coupon_postgres_repository::PostgresCouponRepository,
+    domain::repository::coupon_repository::CouponRepository,
+};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use serial_test::serial;
+use sqlx::postgres::PgPoolOptions;
+use std::sync::Arc;
+
+use crate::utils::{
+    fixtures::{create_coupon, create_product},
+    start_server::init_tracing,
+};
+
+async fn get_json(client: &Client, url: String) -> Value {
+    client.get(url).send().await.unwrap().json().await.unwrap()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_25_uses_count_is_reconciled_with_the_ledger() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+    let base = format!("http://localhost:{}/api/v1", port);
+    let pool = PgPoolOptions::new()
+        .max_connections(2)
+        .connect(&dotenv::var("DATABASE_URL").unwrap())
+        .await
+        .expect("Failed to connect to the database");
+
+    let mut product_ids = Vec::new();
+    for _ in 0..2 {
+        product_ids.push(create_product(&client, port, json!({})).await);
+    }
+
+    let code = create_coupon(&client, port, json!({})).await;
+
+    // One order discounting two lines is one use with two ledger rows.
+    let response = client
+        .post(format!("{}/orders", base))
+        .json(&json!({
+            "items": [
+                { "product_id": product_ids[0], "quantity": 1 },
+                { "product_id": product_ids[1], "quantity": 1 }
+            ],
+            "coupon_code": code
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let redemptions = get_json(&client, format!("{}/coupons/{}/redemptions", base, code)).await;
+    assert_eq!(redemptions["meta"]["total_items"], 2);
+
+    let coupon = get_json(&client, format!("{}/coupons/{}", base, code)).await;
+    assert_eq!(coupon["uses_count"], 1);
+
+    sqlx::query("UPDATE coupons SET uses_count = 7 WHERE code = $1")
+        .bind(&code)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let repository = PostgresCouponRepository::new(Arc::new(pool));
+    let corrected = repository.reconcile_uses_count().await.unwrap();
+    assert!(corrected >= 1);
+    assert_eq!(repository.reconcile_uses_count().await.unwrap(), 0);
+
+    let coupon = get_json(&client, format!("{}/coupons/{}", base, code)).await;
+    assert_eq!(coupon["uses_count"], 1);
+}
@@ -0,0 +1,117 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::json;
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::start_server::init_tracing;
+
+#[tokio::test]
+#[serial]
+async fn test_14_apply_coupon_records_redemption() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+
+    let code = format!("LEDGER{}", &Uuid::new_v4().simple().to_string()[..12]);
+    let coupons_url = format!("http://localhost:{}/api/v1/coupons", port);
+    let create_response = client
+        .post(&coupons_url)
+        .json(&json!({
+            "code": code,
+            "type": "percent",
+            "value": 2000,
+            "one_shot": false,
+            "valid_from": "2025-01-01T00:00:00Z",
+            "valid_until": "2029-12-31T23:59:59Z",
+            "max_uses": 10
+        }))
+        .send()
+        .await
+        .expect("Failed to create coupon");
+    assert_eq!(create_response.status(), StatusCode::CREATED);
+
+    let product: serde_json::Value = client
+        .post(format!("http://localhost:{}/api/v1/products", port))
+        .json(&json!({
+            "name": format!("Café Ledger {}", Uuid::new_v4()),
+            "stock": 10,
+            "price": 1000
+        }))
+        .send()
+        .await
+        .expect("Failed to create product")
+        .json()
+        .await
+        .unwrap();
+    let product_id = product["id"].as_str().expect("Product ID missing");
+
+    let apply_response = client
+        .post(format!(
+            "http://localhost:{}/api/v1/products/{}/discount/coupon",
+            port, product_id
+        ))
+        .json(&json!({ "code": code, "customer_id": "customer-42" }))
+        .send()
+        .await
+        .expect("Failed to apply coupon");
+    assert_eq!(apply_response.status(), StatusCode::NO_CONTENT);
+
+    let discounted: serde_json::Value = client
+        .get(format!(
+            "http://localhost:{}/api/v1/products/{}",
+            port, product_id
+        ))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(discounted["final_price"], 800);
+
+    let redemptions_response = client
+        .get(format!(
+            "{}/{}/redemptions?page=1&limit=5",
+            coupons_url, code
+        ))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(redemptions_response.status(), StatusCode::OK);
+
+    let redemptions: serde_json::Value = redemptions_response.json().await.unwrap();
+    assert_eq!(redemptions["meta"]["total_items"], 1);
+    assert_eq!(redemptions["meta"]["limit"], 5);
+
+    let redemption = &redemptions["data"][0];
+    assert_eq!(redemption["product_id"], product_id);
+    assert_eq!(redemption["discount_amount"], 200);
+    assert_eq!(redemption["customer_ref"], "customer-42");
+
+    let coupon: serde_json::Value = client
+        .get(format!("{}/{}", coupons_url, code))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(coupon["uses_count"], redemptions["meta"]["total_items"]);
+
+    let missing = client
+        .get(format!(
+            "{}/MISSING{}/redemptions",
+            coupons_url,
+            Uuid::new_v4().simple()
+        ))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,73 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use serial_test::serial;
+
+use crate::utils::{
+    fixtures::{apply, create_coupon, create_product},
+    start_server::init_tracing,
+};
+
+#[tokio::test]
+#[serial]
+async fn test_19_stackable_coupons_combine_percent_first() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+
+    let product_id = create_product(&client, port, json!({})).await;
+    let product_id = product_id.as_str();
+
+    let fixed = create_coupon(&client, port, json!({ "stackable": true, "priority": 10 })).await;
+    let percent = create_coupon(
+        &client,
+        port,
+        json!({ "type": "percent", "value": 1000, "stackable": true }),
+    )
+    .await;
+    let exclusive = create_coupon(&client, port, json!({ "stackable": false })).await;
+
+    assert_eq!(
+        apply(&client, port, product_id, &fixed).await,
+        StatusCode::NO_CONTENT
+    );
+    assert_eq!(
+        apply(&client, port, product_id, &percent).await,
+        StatusCode::NO_CONTENT
+    );
+    assert_eq!(
+        apply(&client, port, product_id, &percent).await,
+        StatusCode::CONFLICT
+    );
+    assert_eq!(
+        apply(&client, port, product_id, &exclusive).await,
+        StatusCode::CONFLICT
+    );
+
+    let product: Value = client
+        .get(format!(
+            "http://localhost:{}/api/v1/products/{}",
+            port, product_id
+        ))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    // 10% of 1000 first, then 100 off the remaining 900.
+    let discounts = product["discounts"].as_array().unwrap();
+    assert_eq!(discounts.len(), 2);
+    assert_eq!(discounts[0]["code"], percent);
+    assert_eq!(discounts[0]["amount"], 100);
+    assert_eq!(discounts[1]["code"], fixed);
+    assert_eq!(discounts[1]["amount"], 100);
+    assert_eq!(product["final_price"], 800);
+    assert_eq!(product["has_coupon_applied"], true);
+}
@@ -15,7 +15,7 @@ async fn test_05_update_coupon_success() {
     let client = Client::new();
     let create_url = format!("http://localhost:{}/api/v1/coupons", port);
 
-    let code = format!("UPDATE-{}", uuid::Uuid::new_v4());
+    let code = format!("UPDATE{}", &uuid::Uuid::new_v4().simple().to_string()[..12]);
     let create_data = json!({
         "code": code,
         "type": "percent",
@@ -69,7 +69,7 @@ async fn test_06_update_coupon_not_found() {
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 
     let body: serde_json::Value = response.json().await.unwrap();
-    assert_eq!(body["error"], "Coupon not found");
+    assert_eq!(body["detail"], "Coupon not found");
 }
 
 #[tokio::test]
@@ -112,6 +112,6 @@ async fn test_07_update_coupon_invalid_data() {
     assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 
     let body: serde_json::Value = response.json().await.unwrap();
-    let error_msg = body["error"].as_str().unwrap_or_default();
+    let error_msg = body["detail"].as_str().unwrap_or_default();
     assert!(error_msg.starts_with("Invalid body:"));
 }
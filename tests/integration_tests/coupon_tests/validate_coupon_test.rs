@@ -0,0 +1,128 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::{fixtures::create_coupon, start_server::init_tracing};
+
+async fn validate(client: &Client, port: u16, code: &str, product_id: &str) -> (StatusCode, Value) {
+    let response = client
+        .post(format!(
+            "http://localhost:{}/api/v1/coupons/{}/validate",
+            port, code
+        ))
+        .json(&json!({ "product_id": product_id }))
+        .send()
+        .await
+        .expect("Failed to validate coupon");
+    let status = response.status();
+    (status, response.json().await.unwrap())
+}
+
+#[tokio::test]
+#[serial]
+async fn test_18_validate_coupon_reports_all_reasons_without_side_effects() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+
+    let product: Value = client
+        .post(format!("http://localhost:{}/api/v1/products", port))
+        .json(&json!({
+            "name": format!("Preview {}", Uuid::new_v4()),
+            "stock": 10,
+            "price": 1000
+        }))
+        .send()
+        .await
+        .expect("Failed to create product")
+        .json()
+        .await
+        .unwrap();
+    let product_id = product["id"].as_str().expect("Product ID missing");
+
+    let code = create_coupon(&client, port, json!({ "value": 300, "max_uses": 1 })).await;
+    let (status, preview) = validate(&client, port, &code, product_id).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(preview["applicable"], true);
+    assert_eq!(preview["reasons"], json!([]));
+    assert_eq!(preview["discount_amount"], 300);
+    assert_eq!(preview["final_price"], 700);
+
+    let coupon: Value = client
+        .get(format!("http://localhost:{}/api/v1/coupons/{}", port, code))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(coupon["uses_count"], 0);
+
+    let apply = client
+        .post(format!(
+            "http://localhost:{}/api/v1/products/{}/discount/coupon",
+            port, product_id
+        ))
+        .json(&json!({ "code": code }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(apply.status(), StatusCode::NO_CONTENT);
+
+    let (_, preview) = validate(&client, port, &code, product_id).await;
+    assert_eq!(preview["applicable"], false);
+    assert_eq!(
+        preview["reasons"],
+        json!(["usage_limit_reached", "already_discounted"])
+    );
+    assert_eq!(preview["final_price"], 700);
+
+    let expired = create_coupon(
+        &client,
+        port,
+        json!({
+            "valid_from": "2020-01-01T00:00:00Z",
+            "valid_until": "2021-01-01T00:00:00Z",
+            "min_product_price": 5000
+        }),
+    )
+    .await;
+    let (_, preview) = validate(&client, port, &expired, product_id).await;
+    assert_eq!(
+        preview["reasons"],
+        json!(["expired", "below_min_price", "already_discounted"])
+    );
+
+    let upcoming = create_coupon(
+        &client,
+        port,
+        json!({
+            "valid_from": "2030-01-01T00:00:00Z",
+            "valid_until": "2031-01-01T00:00:00Z"
+        }),
+    )
+    .await;
+    client
+        .delete(format!(
+            "http://localhost:{}/api/v1/coupons/{}",
+            port, upcoming
+        ))
+        .send()
+        .await
+        .unwrap();
+    let (_, preview) = validate(&client, port, &upcoming, product_id).await;
+    assert_eq!(
+        preview["reasons"],
+        json!(["deleted", "not_yet_valid", "already_discounted"])
+    );
+
+    let (status, _) = validate(&client, port, "NOPE0000", product_id).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,135 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::start_server::init_tracing;
+
+/// `(field, code)` of each field error in a problem response.
+fn field_codes(body: &Value) -> Vec<(String, String)> {
+    body["errors"]
+        .as_array()
+        .expect("Field errors missing")
+        .iter()
+        .map(|e| {
+            (
+                e["field"].as_str().unwrap().to_string(),
+                e["code"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_23_coupon_create_and_update_report_field_errors() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+    let url = format!("http://localhost:{}/api/v1/coupons", port);
+
+    let response = client
+        .post(&url)
+        .json(&json!({
+            "code": "ab",
+            "type": "percent",
+            "value": 9000,
+            "one_shot": true,
+            "max_uses": 3,
+            "max_uses_per_customer": 0,
+            "valid_from": "2025-01-01T00:00:00Z",
+            "valid_until": "2024-01-01T00:00:00Z"
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["title"], "Validation Error");
+    assert_eq!(
+        field_codes(&body),
+        vec![
+            ("code".to_string(), "regex".to_string()),
+            ("max_uses_per_customer".to_string(), "range".to_string()),
+            ("value".to_string(), "coupon_type".to_string()),
+            ("valid_until".to_string(), "validity_period".to_string()),
+            ("max_uses".to_string(), "max_uses".to_string()),
+        ]
+    );
+    assert_eq!(body["errors"][1]["params"], json!({ "min": 1, "value": 0 }));
+
+    let response = client
+        .post(&url)
+        .json(&json!({
+            "code": "BADTYPE1",
+            "type": "gift",
+            "value": 100,
+            "one_shot": false,
+            "valid_from": "2025-01-01T00:00:00Z",
+            "valid_until": "yesterday"
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(
+        field_codes(&body),
+        vec![
+            ("type".to_string(), "choice".to_string()),
+            ("valid_until".to_string(), "date".to_string()),
+        ]
+    );
+
+    let coupon_code = format!("VALID{}", &Uuid::new_v4().simple().to_string()[..12]);
+    let response = client
+        .post(&url)
+        .json(&json!({
+            "code": coupon_code,
+            "type": "percent",
+            "value": 1000,
+            "one_shot": false,
+            "max_uses": 5,
+            "valid_from": "2025-01-01T00:00:00Z",
+            "valid_until": "2029-12-31T23:59:59Z"
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    // The first update breaks a derived rule; the others only conflict
+    // with the stored coupon, so the CHECK constraints reject them and come
+    // back as the same field errors.
+    for (update, field, code) in [
+        (json!({ "max_uses": 0 }), "max_uses", "range"),
+        (json!({ "value": 9000 }), "value", "coupon_type"),
+        (
+            json!({ "valid_until": "2024-01-01T00:00:00Z" }),
+            "valid_until",
+            "validity_period",
+        ),
+        (json!({ "one_shot": true }), "max_uses", "max_uses"),
+        (json!({ "coupon_type": "gift" }), "coupon_type", "choice"),
+        (json!({ "valid_from": "soon" }), "valid_from", "date"),
+    ] {
+        let response = client
+            .patch(format!("{}/{}", url, coupon_code))
+            .json(&update)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body: Value = response.json().await.unwrap();
+        assert_eq!(
+            field_codes(&body),
+            vec![(field.to_string(), code.to_string())]
+        );
+    }
+}
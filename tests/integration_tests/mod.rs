@@ -1,2 +1,6 @@
+pub mod campaign_tests;
 pub mod coupon_tests;
+pub mod order_tests;
 pub mod product_tests;
+pub mod quote_tests;
+pub mod sale_event_tests;
@@ -0,0 +1,2 @@
+pub mod place_order_test;
+pub mod order_transition_test;
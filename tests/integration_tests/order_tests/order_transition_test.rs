@@ -0,0 +1,148 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::start_server::init_tracing;
+
+async fn post(client: &Client, url: String, body: Value) -> (StatusCode, Value) {
+    let response = client.post(url).json(&body).send().await.unwrap();
+    let status = response.status();
+    (status, response.json().await.unwrap())
+}
+
+async fn get_json(client: &Client, url: String) -> Value {
+    client.get(url).send().await.unwrap().json().await.unwrap()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_02_transitions_give_back_stock_and_coupon_uses() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+    let base = format!("http://localhost:{}/api/v1", port);
+
+    let (_, product) = post(
+        &client,
+        format!("{}/products", base),
+        json!({ "name": format!("Order {}", Uuid::new_v4()), "stock": 5, "price": 1000 }),
+    )
+    .await;
+    let product_id = product["id"].as_str().unwrap().to_string();
+    let product_url = format!("{}/products/{}", base, product_id);
+
+    let code = format!("BACK{}", &Uuid::new_v4().simple().to_string()[..12]);
+    let (status, _) = post(
+        &client,
+        format!("{}/coupons", base),
+        json!({
+            "code": code,
+            "type": "percent",
+            "value": 1000,
+            "one_shot": false,
+            "max_uses": 1,
+            "valid_from": "2025-01-01T00:00:00Z",
+            "valid_until": "2029-12-31T23:59:59Z"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    // Cancelling a pending order puts the stock and the coupon use back.
+    let (status, order) = post(
+        &client,
+        format!("{}/orders", base),
+        json!({
+            "items": [{ "product_id": product_id, "quantity": 2 }],
+            "coupon_code": code
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let order_url = format!("{}/orders/{}", base, order["id"].as_str().unwrap());
+    assert_eq!(get_json(&client, product_url.clone()).await["stock"], 3);
+
+    let (status, body) = post(&client, format!("{}/fulfill", order_url), json!({})).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["detail"], "Order is pending, cannot fulfill");
+
+    let (status, order) = post(
+        &client,
+        format!("{}/cancel", order_url),
+        json!({ "restore_coupon": true }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(order["status"], "cancelled");
+    assert_eq!(order["history"][0]["action"], "cancel");
+    assert_eq!(order["history"][0]["from_status"], "pending");
+    assert_eq!(order["history"][0]["coupon_restored"], true);
+    assert_eq!(order["history"][0]["lines"][0]["quantity"], 2);
+    assert_eq!(get_json(&client, product_url.clone()).await["stock"], 5);
+    let coupon = get_json(&client, format!("{}/coupons/{}", base, code)).await;
+    assert_eq!(coupon["uses_count"], 0);
+    let redemptions = get_json(&client, format!("{}/coupons/{}/redemptions", base, code)).await;
+    assert!(redemptions["data"][0]["reversed_at"].is_string());
+
+    let (status, _) = post(&client, format!("{}/cancel", order_url), json!({})).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    // A paid order is refunded line by line.
+    let (_, order) = post(
+        &client,
+        format!("{}/orders", base),
+        json!({ "items": [{ "product_id": product_id, "quantity": 3 }] }),
+    )
+    .await;
+    let order_url = format!("{}/orders/{}", base, order["id"].as_str().unwrap());
+    let line_id = order["lines"][0]["id"].clone();
+
+    let (status, order) = post(&client, format!("{}/pay", order_url), json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(order["status"], "paid");
+
+    // Paid orders are refunded, never cancelled.
+    let (status, body) = post(&client, format!("{}/cancel", order_url), json!({})).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert_eq!(body["detail"], "Order is paid, cannot cancel");
+
+    let (status, body) = post(
+        &client,
+        format!("{}/refund", order_url),
+        json!({ "lines": [{ "line_id": line_id, "quantity": 4 }] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["errors"][0]["field"], "lines[0].quantity");
+    assert_eq!(body["errors"][0]["message"], "Must be between 1 and 3");
+
+    let (status, order) = post(
+        &client,
+        format!("{}/refund", order_url),
+        json!({ "lines": [{ "line_id": line_id, "quantity": 1 }] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(order["status"], "partially_refunded");
+    assert_eq!(order["lines"][0]["refunded_quantity"], 1);
+    assert_eq!(order["refunded_total"], 1000);
+    assert_eq!(get_json(&client, product_url.clone()).await["stock"], 3);
+
+    let (status, order) = post(&client, format!("{}/refund", order_url), json!({})).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(order["status"], "refunded");
+    assert_eq!(order["refunded_total"], 3000);
+    assert_eq!(order["history"].as_array().unwrap().len(), 3);
+    assert_eq!(order["history"][2]["refund_amount"], 2000);
+    assert_eq!(get_json(&client, product_url).await["stock"], 5);
+
+    let fetched = get_json(&client, order_url).await;
+    assert_eq!(fetched, order);
+}
@@ -0,0 +1,143 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::start_server::init_tracing;
+
+async fn get_json(client: &Client, url: String) -> (StatusCode, Value) {
+    let response = client.get(url).send().await.unwrap();
+    let status = response.status();
+    (status, response.json().await.unwrap())
+}
+
+#[tokio::test]
+#[serial]
+async fn test_01_order_takes_stock_and_redeems_coupon() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+    let base = format!("http://localhost:{}/api/v1", port);
+
+    let product: Value = client
+        .post(format!("{}/products", base))
+        .json(&json!({
+            "name": format!("Order {}", Uuid::new_v4()),
+            "stock": 3,
+            "price": 1500
+        }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let product_id = product["id"].as_str().unwrap().to_string();
+
+    let code = format!("ORDER{}", &Uuid::new_v4().simple().to_string()[..12]);
+    let response = client
+        .post(format!("{}/coupons", base))
+        .json(&json!({
+            "code": code,
+            "type": "percent",
+            "value": 1000,
+            "one_shot": false,
+            "max_uses": 1,
+            "valid_from": "2025-01-01T00:00:00Z",
+            "valid_until": "2029-12-31T23:59:59Z",
+            "allowed_product_ids": [product_id]
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let customer = format!("customer-{}", Uuid::new_v4());
+    let response = client
+        .post(format!("{}/orders", base))
+        .header("X-Customer-Id", &customer)
+        .json(&json!({
+            "items": [{ "product_id": product_id, "quantity": 2 }],
+            "coupon_code": code
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let order: Value = response.json().await.unwrap();
+    let order_id = order["id"].as_str().unwrap().to_string();
+
+    assert_eq!(order["status"], "pending");
+    assert_eq!(order["customer_ref"], customer);
+    assert_eq!(order["coupon_code"], code);
+    assert_eq!(order["lines"][0]["product_id"], product_id);
+    assert_eq!(order["lines"][0]["quantity"], 2);
+    assert_eq!(order["lines"][0]["price"], 1500);
+    assert_eq!(order["lines"][0]["subtotal"], 3000);
+    assert_eq!(order["lines"][0]["discount_total"], 300);
+    assert_eq!(order["lines"][0]["total"], 2700);
+    assert_eq!(order["total"], 2700);
+
+    let (_, product) = get_json(&client, format!("{}/products/{}", base, product_id)).await;
+    assert_eq!(product["stock"], 1);
+    let (_, coupon) = get_json(&client, format!("{}/coupons/{}", base, code)).await;
+    assert_eq!(coupon["uses_count"], 1);
+    let (_, redemptions) =
+        get_json(&client, format!("{}/coupons/{}/redemptions", base, code)).await;
+    assert_eq!(redemptions["data"][0]["discount_amount"], 300);
+    assert_eq!(redemptions["data"][0]["customer_ref"], customer);
+
+    let (status, fetched) = get_json(&client, format!("{}/orders/{}", base, order_id)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(fetched, order);
+
+    let (status, listed) =
+        get_json(&client, format!("{}/orders?customer_id={}", base, customer)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(listed["meta"]["total_items"], 1);
+    assert_eq!(listed["data"][0]["id"], order_id);
+
+    // The coupon is used up, and nothing is taken when an order fails.
+    let response = client
+        .post(format!("{}/orders", base))
+        .json(&json!({
+            "items": [{ "product_id": product_id, "quantity": 1 }],
+            "coupon_code": code
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["detail"], "Coupon usage limit reached");
+
+    let response = client
+        .post(format!("{}/orders", base))
+        .json(&json!({ "items": [{ "product_id": product_id, "quantity": 2 }] }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let response = client
+        .post(format!("{}/orders", base))
+        .json(&json!({ "items": [] }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["errors"][0]["field"], "items");
+
+    let (_, product) = get_json(&client, format!("{}/products/{}", base, product_id)).await;
+    assert_eq!(product["stock"], 1);
+
+    let (status, _) = get_json(&client, format!("{}/orders/{}", base, Uuid::new_v4())).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,134 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode, header};
+use serde_json::json;
+use serial_test::serial;
+
+use crate::utils::{fixtures::create_product, start_server::init_tracing};
+
+#[tokio::test]
+#[serial]
+async fn test_13_product_etag_and_if_match() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+
+    let create_url = format!("http://localhost:{}/api/v1/products", port);
+    let product_data = json!({
+        "name": format!("Café Concorrência {}", uuid::Uuid::new_v4()),
+        "description": "Edição simultânea",
+        "stock": 10,
+        "price": 1000
+    });
+
+    let create_response = client
+        .post(&create_url)
+        .json(&product_data)
+        .send()
+        .await
+        .expect("Failed to create product");
+    assert_eq!(create_response.status(), StatusCode::CREATED);
+
+    let created_product: serde_json::Value = create_response
+        .json()
+        .await
+        .expect("Failed to parse created product");
+    let product_id = created_product["id"].as_str().expect("Product ID missing");
+
+    let product_url = format!("http://localhost:{}/api/v1/products/{}", port, product_id);
+    let get_response = client
+        .get(&product_url)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(get_response.status(), StatusCode::OK);
+
+    let etag = get_response
+        .headers()
+        .get(header::ETAG)
+        .expect("ETag missing")
+        .to_str()
+        .unwrap()
+        .to_string();
+    assert_eq!(etag, "\"1\"");
+
+    let first_update = client
+        .patch(&product_url)
+        .header(header::IF_MATCH, &etag)
+        .json(&json!({ "stock": 5 }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(first_update.status(), StatusCode::OK);
+
+    let stale_update = client
+        .patch(&product_url)
+        .header(header::IF_MATCH, &etag)
+        .json(&json!({ "stock": 7 }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(stale_update.status(), StatusCode::PRECONDITION_FAILED);
+
+    let stale_delete = client
+        .delete(&product_url)
+        .header(header::IF_MATCH, &etag)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(stale_delete.status(), StatusCode::PRECONDITION_FAILED);
+
+    let get_response = client
+        .get(&product_url)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(get_response.headers().get(header::ETAG).unwrap(), "\"2\"");
+    let body: serde_json::Value = get_response.json().await.unwrap();
+    assert_eq!(body["stock"], 5);
+
+    // If-Match compares strongly: a weak tag never authorizes a write, and
+    // a list matches any of its strong tags.
+    let weak_update = client
+        .patch(&product_url)
+        .header(header::IF_MATCH, "W/\"2\"")
+        .json(&json!({ "stock": 6 }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(weak_update.status(), StatusCode::PRECONDITION_FAILED);
+
+    let listed_update = client
+        .patch(&product_url)
+        .header(header::IF_MATCH, "W/\"2\", \"1\", \"2\"")
+        .json(&json!({ "stock": 7 }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(listed_update.status(), StatusCode::OK);
+
+    let taken_name = product_data["name"].as_str().unwrap().to_string();
+    let other_id = create_product(&client, port, json!({})).await;
+    let conflict = client
+        .patch(format!(
+            "http://localhost:{}/api/v1/products/{}",
+            port, other_id
+        ))
+        .json(&json!({ "name": taken_name }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(conflict.status(), StatusCode::CONFLICT);
+
+    let delete_response = client
+        .delete(&product_url)
+        .header(header::IF_MATCH, "\"3\"")
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(delete_response.status(), StatusCode::NO_CONTENT);
+}
@@ -0,0 +1,121 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode, header};
+use serde_json::{Value, json};
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::start_server::init_tracing;
+
+#[tokio::test]
+#[serial]
+async fn test_21_list_products_honors_accept_header() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+
+    let prefix = format!("Negotiation {}", Uuid::new_v4());
+    for suffix in ["a", "b"] {
+        let response = client
+            .post(format!("http://localhost:{}/api/v1/products", port))
+            .json(&json!({
+                "name": format!("{}, {}", prefix, suffix),
+                "stock": 3,
+                "price": 1500
+            }))
+            .send()
+            .await
+            .expect("Failed to create product");
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+    let url = format!("http://localhost:{}/api/v1/products", port);
+    let query = [("search", prefix.as_str()), ("limit", "50")];
+
+    let json_listing: Value = client
+        .get(&url)
+        .query(&query)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = client
+        .get(&url)
+        .query(&query)
+        .header(header::ACCEPT, "application/msgpack")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/msgpack"
+    );
+    let msgpack_listing: Value =
+        rmp_serde::from_slice(&response.bytes().await.unwrap()).expect("Body is not MessagePack");
+    assert_eq!(msgpack_listing, json_listing);
+
+    let response = client
+        .get(&url)
+        .query(&query)
+        .header(header::ACCEPT, "text/csv")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/csv; charset=utf-8"
+    );
+    assert_eq!(response.headers()["x-total-items"], "2");
+    let body = response.text().await.unwrap();
+    let mut reader = csv::Reader::from_reader(body.as_bytes());
+    let columns = reader.headers().unwrap().clone();
+    let name = columns
+        .iter()
+        .position(|c| c == "name")
+        .expect("No name column");
+    let price = columns
+        .iter()
+        .position(|c| c == "price")
+        .expect("No price column");
+    let rows: Vec<csv::StringRecord> = reader.records().map(Result::unwrap).collect();
+    assert_eq!(rows.len(), 2);
+    assert!(
+        rows.iter()
+            .all(|r| r[name].starts_with(&prefix) && &r[price] == "1500")
+    );
+
+    // CSV is only for collections; a single product can still fall back.
+    let id = json_listing["data"][0]["id"].as_str().unwrap();
+    let response = client
+        .get(format!("{}/{}", url, id))
+        .header(header::ACCEPT, "text/csv")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+
+    let response = client
+        .get(format!("{}/{}", url, id))
+        .header(header::ACCEPT, "text/csv, application/json;q=0.5")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+
+    let response = client
+        .get(&url)
+        .header(header::ACCEPT, "application/xml")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+}
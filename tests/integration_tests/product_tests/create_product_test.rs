@@ -70,8 +70,14 @@ async fn test_02_create_product_missing_fields() {
     assert_eq!(response.status(), ReqwestStatusCode::BAD_REQUEST);
 
     let response_body: serde_json::Value = response.json().await.expect("Failed to parse response");
-    assert_eq!(response_body["error"], "Missing required fields");
-    let fields = response_body["fields"].as_array().unwrap();
+    assert_eq!(response_body["detail"], "Missing required fields");
+    assert_eq!(response_body["title"], "Validation Error");
+    let fields: Vec<serde_json::Value> = response_body["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["field"].clone())
+        .collect();
     assert_eq!(fields.len(), 3);
     assert!(fields.contains(&json!("name")));
     assert!(fields.contains(&json!("stock")));
@@ -91,9 +97,15 @@ async fn test_02_create_product_missing_fields() {
     assert_eq!(response.status(), ReqwestStatusCode::BAD_REQUEST);
 
     let response_body: serde_json::Value = response.json().await.expect("Failed to parse response");
-    assert_eq!(response_body["error"], "Missing required fields");
-    let fields = response_body["fields"].as_array().unwrap();
-    assert_eq!(fields, &[json!("name")]);
+    assert_eq!(response_body["detail"], "Missing required fields");
+    assert_eq!(response_body["title"], "Validation Error");
+    let fields: Vec<serde_json::Value> = response_body["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["field"].clone())
+        .collect();
+    assert_eq!(fields, vec![json!("name")]);
 }
 
 #[tokio::test]
@@ -125,9 +137,14 @@ async fn test_03_create_product_invalid_types() {
     assert_eq!(response.status(), ReqwestStatusCode::BAD_REQUEST);
 
     let response_body: serde_json::Value = response.json().await.expect("Failed to parse response");
-    assert_eq!(response_body["error"], "Invalid field types");
     assert!(
-        response_body["details"]
+        response_body["detail"]
+            .as_str()
+            .unwrap()
+            .starts_with("Invalid field types: ")
+    );
+    assert!(
+        response_body["detail"]
             .as_str()
             .unwrap()
             .contains("invalid type: string \"cem\", expected u32")
@@ -148,9 +165,14 @@ async fn test_03_create_product_invalid_types() {
     assert_eq!(response.status(), ReqwestStatusCode::BAD_REQUEST);
 
     let response_body: serde_json::Value = response.json().await.expect("Failed to parse response");
-    assert_eq!(response_body["error"], "Invalid field types");
     assert!(
-        response_body["details"]
+        response_body["detail"]
+            .as_str()
+            .unwrap()
+            .starts_with("Invalid field types: ")
+    );
+    assert!(
+        response_body["detail"]
             .as_str()
             .unwrap()
             .contains("invalid type: string \"trinta\", expected u64")
@@ -200,5 +222,5 @@ async fn test_04_create_product_conflict() {
 
     let response_body: serde_json::Value =
         response2.json().await.expect("Failed to parse response");
-    assert_eq!(response_body["error"], "Product already exists");
+    assert_eq!(response_body["detail"], "Product already exists");
 }
@@ -87,5 +87,5 @@ async fn test_09_delete_product_not_found() {
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 
     let response_body: serde_json::Value = response.json().await.expect("Failed to parse response");
-    assert_eq!(response_body["error"], "Product not found");
+    assert_eq!(response_body["detail"], "Product not found");
 }
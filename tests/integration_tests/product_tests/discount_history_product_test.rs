@@ -0,0 +1,147 @@
+use chrono::{SecondsFormat, Utc};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use serial_test::serial;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::utils::{
+    fixtures::{create_coupon, create_product},
+    start_server::init_tracing,
+};
+
+#[tokio::test]
+#[serial]
+async fn test_18_discount_history_per_product_and_coupon() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+
+    let product_id = create_product(&client, port, json!({})).await;
+    let product_id = product_id.as_str();
+    let first = create_coupon(&client, port, json!({ "type": "percent", "value": 1000 })).await;
+    let second = create_coupon(&client, port, json!({ "type": "percent", "value": 1000 })).await;
+
+    let apply = |code: String| {
+        client
+            .post(format!(
+                "http://localhost:{}/api/v1/products/{}/discount/coupon",
+                port, product_id
+            ))
+            .json(&json!({ "code": code }))
+            .send()
+    };
+
+    assert_eq!(
+        apply(first.clone()).await.unwrap().status(),
+        StatusCode::NO_CONTENT
+    );
+    let remove = client
+        .delete(format!(
+            "http://localhost:{}/api/v1/products/{}/discount",
+            port, product_id
+        ))
+        .json(&json!({ "code": first }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(remove.status(), StatusCode::NO_CONTENT);
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    let between = Utc::now().to_rfc3339_opts(SecondsFormat::Micros, true);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    assert_eq!(
+        apply(second.clone()).await.unwrap().status(),
+        StatusCode::NO_CONTENT
+    );
+
+    let history = |query: Vec<(&'static str, String)>| {
+        client
+            .get(format!(
+                "http://localhost:{}/api/v1/products/{}/discounts",
+                port, product_id
+            ))
+            .query(&query)
+            .send()
+    };
+
+    let timeline: Value = history(vec![]).await.unwrap().json().await.unwrap();
+    assert_eq!(timeline["meta"]["total_items"], 2);
+    let entries = timeline["data"].as_array().expect("Entries missing");
+    assert_eq!(entries[0]["coupon_code"], second.as_str());
+    assert_eq!(entries[0]["removed_at"], Value::Null);
+    assert_eq!(entries[1]["coupon_code"], first.as_str());
+    assert_eq!(entries[1]["removal_reason"], "removed");
+
+    let after: Value = history(vec![("from", between.clone())])
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(after["meta"]["total_items"], 1);
+    assert_eq!(after["data"][0]["coupon_code"], second.as_str());
+
+    let before: Value = history(vec![("to", between.clone())])
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(before["meta"]["total_items"], 1);
+    assert_eq!(before["data"][0]["coupon_code"], first.as_str());
+
+    let paged: Value = history(vec![("limit", "1".to_string()), ("page", "2".to_string())])
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(paged["meta"]["total_pages"], 2);
+    assert_eq!(paged["data"][0]["coupon_code"], first.as_str());
+
+    let invalid = history(vec![("from", "yesterday".to_string())])
+        .await
+        .unwrap();
+    assert_eq!(invalid.status(), StatusCode::BAD_REQUEST);
+    let reversed = history(vec![
+        ("from", between.clone()),
+        ("to", "2020-01-01T00:00:00Z".to_string()),
+    ])
+    .await
+    .unwrap();
+    assert_eq!(reversed.status(), StatusCode::BAD_REQUEST);
+
+    let applications: Value = client
+        .get(format!(
+            "http://localhost:{}/api/v1/coupons/{}/applications",
+            port, first
+        ))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(applications["meta"]["total_items"], 1);
+    assert_eq!(applications["data"][0]["product_id"], product_id);
+    assert_eq!(applications["data"][0]["removal_reason"], "removed");
+
+    let missing = client
+        .get(format!(
+            "http://localhost:{}/api/v1/coupons/MISSING{}/applications",
+            port,
+            Uuid::new_v4().simple()
+        ))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+}
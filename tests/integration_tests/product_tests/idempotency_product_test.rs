@@ -0,0 +1,71 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::json;
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::start_server::init_tracing;
+
+#[tokio::test]
+#[serial]
+async fn test_14_create_product_with_idempotency_key() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+
+    let create_url = format!("http://localhost:{}/api/v1/products", port);
+    let idempotency_key = Uuid::new_v4().to_string();
+    let product_data = json!({
+        "name": format!("Café Idempotente {}", Uuid::new_v4()),
+        "description": "Criado uma única vez",
+        "stock": 10,
+        "price": 1000
+    });
+
+    let first = client
+        .post(&create_url)
+        .header("Idempotency-Key", &idempotency_key)
+        .json(&product_data)
+        .send()
+        .await
+        .expect("Failed to create product");
+    assert_eq!(first.status(), StatusCode::CREATED);
+    let first_body: serde_json::Value = first.json().await.unwrap();
+
+    let retry = client
+        .post(&create_url)
+        .header("Idempotency-Key", &idempotency_key)
+        .json(&product_data)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(retry.status(), StatusCode::CREATED);
+    let retry_body: serde_json::Value = retry.json().await.unwrap();
+    assert_eq!(retry_body, first_body);
+
+    let mismatch = client
+        .post(&create_url)
+        .header("Idempotency-Key", &idempotency_key)
+        .json(&json!({
+            "name": format!("Outro Café {}", Uuid::new_v4()),
+            "stock": 1,
+            "price": 500
+        }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(mismatch.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let without_key = client
+        .post(&create_url)
+        .json(&product_data)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(without_key.status(), StatusCode::CONFLICT);
+}
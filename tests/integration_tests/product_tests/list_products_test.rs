@@ -0,0 +1,103 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::start_server::init_tracing;
+
+#[tokio::test]
+#[serial]
+async fn test_17_list_products_returns_priced_items() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+
+    let prefix = format!("Listing {}", Uuid::new_v4());
+    let mut ids = Vec::new();
+    for (suffix, stock) in [("a", 10), ("b", 0)] {
+        let product: Value = client
+            .post(format!("http://localhost:{}/api/v1/products", port))
+            .json(&json!({
+                "name": format!("{} {}", prefix, suffix),
+                "stock": stock,
+                "price": 1000
+            }))
+            .send()
+            .await
+            .expect("Failed to create product")
+            .json()
+            .await
+            .unwrap();
+        ids.push(
+            product["id"]
+                .as_str()
+                .expect("Product ID missing")
+                .to_string(),
+        );
+    }
+
+    let code = format!("LIST{}", &Uuid::new_v4().simple().to_string()[..12]);
+    let response = client
+        .post(format!("http://localhost:{}/api/v1/coupons", port))
+        .json(&json!({
+            "code": code,
+            "type": "percent",
+            "value": 1000,
+            "one_shot": false,
+            "valid_from": "2025-01-01T00:00:00Z",
+            "valid_until": "2029-12-31T23:59:59Z"
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let apply = client
+        .post(format!(
+            "http://localhost:{}/api/v1/products/{}/discount/coupon",
+            port, ids[0]
+        ))
+        .json(&json!({ "code": code }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(apply.status(), StatusCode::NO_CONTENT);
+
+    let listing: Value = client
+        .get(format!("http://localhost:{}/api/v1/products", port))
+        .query(&[("search", prefix.as_str()), ("limit", "50")])
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let items = listing["data"].as_array().expect("Items missing");
+    assert_eq!(items.len(), 2);
+
+    let item = |id: &str| {
+        items
+            .iter()
+            .find(|p| p["id"] == id)
+            .expect("Product missing from listing")
+    };
+
+    let discounted = item(&ids[0]);
+    assert_eq!(discounted["final_price"], 900);
+    assert_eq!(discounted["total_discount"], 100);
+    assert_eq!(discounted["has_coupon_applied"], true);
+    assert_eq!(discounted["discounts"][0]["code"], code.as_str());
+    assert_eq!(discounted["is_out_of_stock"], false);
+
+    let plain = item(&ids[1]);
+    assert_eq!(plain["final_price"], 1000);
+    assert_eq!(plain["has_coupon_applied"], false);
+    assert_eq!(plain["discounts"], json!([]));
+    assert_eq!(plain["is_out_of_stock"], true);
+}
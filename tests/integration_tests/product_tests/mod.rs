@@ -1,5 +1,14 @@
+pub mod concurrency_product_test;
+pub mod content_negotiation_test;
 pub mod create_product_test;
 pub mod delete_product_test;
+pub mod discount_history_product_test;
 pub mod get_product_test;
 pub mod health_check_test;
+pub mod idempotency_product_test;
+pub mod list_products_test;
+pub mod pricing_product_test;
+pub mod problem_details_test;
+pub mod tax_product_test;
+pub mod trash_product_test;
 pub mod update_product_test;
@@ -0,0 +1,141 @@
+use chrono::Utc;
+use isi_dev::domain::{
+    entity::{
+        coupon_entity::{Coupon, CouponStacking, CouponType, CreateCoupon},
+        discount_entity::ProductDiscountInfo,
+    },
+    pricing::{pricing_engine::PricingEngine, rounding_policy::RoundingPolicy},
+};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::start_server::init_tracing;
+
+fn discount(coupon_type: CouponType, value: u64) -> ProductDiscountInfo {
+    let coupon = Coupon::new(CreateCoupon {
+        code: "PRICING".to_string(),
+        coupon_type,
+        value,
+        params: None,
+        max_discount_amount: None,
+        one_shot: false,
+        valid_from: Utc::now(),
+        valid_until: Utc::now(),
+        max_uses: None,
+        max_uses_per_customer: None,
+        rules: Default::default(),
+        campaign_id: None,
+        stacking: CouponStacking::default(),
+    });
+    ProductDiscountInfo::new(&coupon, Utc::now())
+}
+
+#[test]
+fn test_15_pricing_engine_rounding_and_minimum_price() {
+    // 15% of 999 is 149.85 cents.
+    let percent = || vec![discount(CouponType::Percent, 1500)];
+    let engine = |rounding, min_price| PricingEngine {
+        rounding,
+        min_price,
+        ..PricingEngine::default()
+    };
+
+    assert_eq!(
+        engine(RoundingPolicy::Down, 1)
+            .price(999, percent())
+            .final_price,
+        850
+    );
+    assert_eq!(
+        engine(RoundingPolicy::HalfUp, 1)
+            .price(999, percent())
+            .final_price,
+        849
+    );
+    assert_eq!(
+        engine(RoundingPolicy::Up, 1)
+            .price(999, percent())
+            .final_price,
+        849
+    );
+
+    let breakdown = engine(RoundingPolicy::Down, 900).price(999, percent());
+    assert_eq!(breakdown.final_price, 900);
+    assert_eq!(breakdown.total_discount, 99);
+    assert_eq!(breakdown.discounts[0].amount, 99);
+    assert!(breakdown.floor_applied);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_16_get_product_reports_price_breakdown() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+
+    let product: Value = client
+        .post(format!("http://localhost:{}/api/v1/products", port))
+        .json(&json!({
+            "name": format!("Floor {}", Uuid::new_v4()),
+            "stock": 10,
+            "price": 500
+        }))
+        .send()
+        .await
+        .expect("Failed to create product")
+        .json()
+        .await
+        .unwrap();
+    let product_id = product["id"].as_str().expect("Product ID missing");
+
+    let code = format!("FLOOR{}", &Uuid::new_v4().simple().to_string()[..12]);
+    let response = client
+        .post(format!("http://localhost:{}/api/v1/coupons", port))
+        .json(&json!({
+            "code": code,
+            "type": "fixed",
+            "value": 1000,
+            "one_shot": false,
+            "valid_from": "2025-01-01T00:00:00Z",
+            "valid_until": "2029-12-31T23:59:59Z"
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let apply = client
+        .post(format!(
+            "http://localhost:{}/api/v1/products/{}/discount/coupon",
+            port, product_id
+        ))
+        .json(&json!({ "code": code }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(apply.status(), StatusCode::NO_CONTENT);
+
+    let product: Value = client
+        .get(format!(
+            "http://localhost:{}/api/v1/products/{}",
+            port, product_id
+        ))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(product["price"], 500);
+    assert_eq!(product["total_discount"], 499);
+    assert_eq!(product["final_price"], 1);
+    assert_eq!(product["floor_applied"], true);
+}
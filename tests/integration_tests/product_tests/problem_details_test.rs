@@ -0,0 +1,111 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode, header};
+use serde_json::{Value, json};
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::start_server::init_tracing;
+
+#[tokio::test]
+#[serial]
+async fn test_22_errors_are_problem_details() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+
+    let missing = Uuid::new_v4();
+    let path = format!("/api/v1/products/{}", missing);
+    let response = client
+        .get(format!("http://localhost:{}{}", port, path))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/problem+json"
+    );
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["type"], "/problems/not-found");
+    assert_eq!(body["title"], "Not Found");
+    assert_eq!(body["status"], 404);
+    assert_eq!(body["instance"], path.as_str());
+    assert!(
+        body["detail"]
+            .as_str()
+            .unwrap()
+            .starts_with("Product not found")
+    );
+
+    let response = client
+        .post(format!("http://localhost:{}/api/v1/products", port))
+        .json(&json!({ "name": "Sem estoque" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["type"], "/problems/validation-error");
+    assert_eq!(body["status"], 400);
+    assert_eq!(
+        body["errors"],
+        json!([
+            { "field": "stock", "code": "required", "message": "Field is required", "params": {} },
+            { "field": "price", "code": "required", "message": "Field is required", "params": {} }
+        ])
+    );
+
+    // A body without a code used to panic the handler and drop the
+    // connection.
+    let response = client
+        .post(format!(
+            "http://localhost:{}/api/v1/products/{}/discount/percent",
+            port, missing
+        ))
+        .body("not json")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["title"], "Bad Request");
+    assert_eq!(body["detail"], "Invalid field types: missing field `code`");
+
+    for listing in ["products", "coupons"] {
+        let response = client
+            .get(format!("http://localhost:{}/api/v1/{}", port, listing))
+            .query(&[("page", "0"), ("limit", "0")])
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "application/problem+json"
+        );
+        let body: Value = response.json().await.unwrap();
+        assert_eq!(body["type"], "/problems/validation-error");
+        assert_eq!(
+            body["errors"],
+            json!([
+                { "field": "page", "code": "range", "message": "Must be at least 1", "params": { "min": 1 } },
+                { "field": "limit", "code": "range", "message": "Must be at least 1", "params": { "min": 1 } }
+            ])
+        );
+    }
+
+    let response = client
+        .get(format!("http://localhost:{}/api/v1/unknown", port))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["instance"], "/api/v1/unknown");
+    assert_eq!(body.get("detail"), None);
+}
@@ -0,0 +1,221 @@
+use chrono::Utc;
+use isi_dev::domain::{
+    entity::{
+        coupon_entity::{Coupon, CouponStacking, CouponType, CreateCoupon},
+        discount_entity::ProductDiscountInfo,
+    },
+    pricing::{
+        pricing_engine::{PriceBreakdown, PricingEngine},
+        tax_policy::{DiscountTaxOrder, TaxPolicy},
+    },
+};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::start_server::init_tracing;
+
+fn fixed_discount(value: u64) -> Vec<ProductDiscountInfo> {
+    let coupon = Coupon::new(CreateCoupon {
+        code: "TAX".to_string(),
+        coupon_type: CouponType::Fixed,
+        value,
+        params: None,
+        max_discount_amount: None,
+        one_shot: false,
+        valid_from: Utc::now(),
+        valid_until: Utc::now(),
+        max_uses: None,
+        max_uses_per_customer: None,
+        rules: Default::default(),
+        campaign_id: None,
+        stacking: CouponStacking::default(),
+    });
+    vec![ProductDiscountInfo::new(&coupon, Utc::now())]
+}
+
+/// `(net, tax, gross, final)` of `breakdown`.
+fn summary(breakdown: PriceBreakdown) -> (u64, u64, u64, u64) {
+    (
+        breakdown.tax.net_price,
+        breakdown.tax.tax_amount,
+        breakdown.tax.gross_price,
+        breakdown.final_price,
+    )
+}
+
+#[test]
+fn test_19_pricing_engine_tax_and_discount_order() {
+    // 10% tax and 100 off.
+    let price = |prices_include_tax, discount_order, base_price| {
+        PricingEngine {
+            tax: TaxPolicy {
+                prices_include_tax,
+                discount_order,
+            },
+            ..PricingEngine::default()
+        }
+        .price_with_tax(base_price, 1000, fixed_discount(100))
+    };
+
+    // Net 1000: 100 off the net, or 100 off the 1100 gross.
+    assert_eq!(
+        summary(price(false, DiscountTaxOrder::BeforeTax, 1000)),
+        (900, 90, 990, 900)
+    );
+    assert_eq!(
+        summary(price(false, DiscountTaxOrder::AfterTax, 1000)),
+        (909, 91, 1000, 909)
+    );
+
+    // The same product stored as gross 1100.
+    let breakdown = price(true, DiscountTaxOrder::BeforeTax, 1100);
+    assert_eq!(breakdown.base_price, 1100);
+    assert_eq!(summary(breakdown), (900, 90, 990, 990));
+    assert_eq!(
+        summary(price(true, DiscountTaxOrder::AfterTax, 1100)),
+        (909, 91, 1000, 1000)
+    );
+
+    // Untaxed products price as before.
+    let breakdown = PricingEngine::default().price_with_tax(1000, 0, fixed_discount(100));
+    assert_eq!(summary(breakdown), (900, 0, 900, 900));
+}
+
+#[tokio::test]
+#[serial]
+async fn test_20_product_reports_tax_breakdown_of_its_tax_class() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+
+    let response = client
+        .post(format!("http://localhost:{}/api/v1/tax-classes", port))
+        .json(&json!({
+            "name": format!("IVA {}", Uuid::new_v4()),
+            "rate": 1000,
+            "region": "pt"
+        }))
+        .send()
+        .await
+        .expect("Failed to create tax class");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let tax_class: Value = response.json().await.unwrap();
+    assert_eq!(tax_class["region"], "PT");
+    let tax_class_id = tax_class["id"].as_str().expect("Tax class ID missing");
+
+    let response = client
+        .post(format!("http://localhost:{}/api/v1/tax-classes", port))
+        .json(&json!({ "name": "Too much", "rate": 10_001, "region": "pt" }))
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["errors"][0]["field"], "rate");
+    assert_eq!(body["errors"][0]["message"], "Must be between 0 and 10000");
+
+    let tax_classes: Value = client
+        .get(format!("http://localhost:{}/api/v1/tax-classes", port))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(
+        tax_classes
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|t| t["id"] == tax_class_id)
+    );
+
+    let product: Value = client
+        .post(format!("http://localhost:{}/api/v1/products", port))
+        .json(&json!({
+            "name": format!("Taxed {}", Uuid::new_v4()),
+            "stock": 10,
+            "price": 1000
+        }))
+        .send()
+        .await
+        .expect("Failed to create product")
+        .json()
+        .await
+        .unwrap();
+    let product_id = product["id"].as_str().expect("Product ID missing");
+    let tax_class_url = format!(
+        "http://localhost:{}/api/v1/products/{}/tax-class",
+        port, product_id
+    );
+
+    let response = client
+        .put(&tax_class_url)
+        .json(&json!({ "tax_class_id": Uuid::new_v4() }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let response = client
+        .put(&tax_class_url)
+        .json(&json!({ "tax_class_id": tax_class_id }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // The test server runs with the defaults: net prices, discounts before tax.
+    let product: Value = client
+        .get(format!(
+            "http://localhost:{}/api/v1/products/{}",
+            port, product_id
+        ))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(product["tax_class_id"], tax_class_id);
+    assert_eq!(product["final_price"], 1000);
+    assert_eq!(
+        product["tax"],
+        json!({
+            "rate": 1000,
+            "prices_include_tax": false,
+            "net_price": 1000,
+            "tax_amount": 100,
+            "gross_price": 1100
+        })
+    );
+
+    let response = client
+        .put(&tax_class_url)
+        .json(&json!({ "tax_class_id": null }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let product: Value = client
+        .get(format!(
+            "http://localhost:{}/api/v1/products/{}",
+            port, product_id
+        ))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(product["tax"]["rate"], 0);
+    assert_eq!(product["tax"]["gross_price"], 1000);
+}
@@ -0,0 +1,195 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::json;
+use serial_test::serial;
+
+use crate::utils::start_server::init_tracing;
+
+async fn create_and_delete_product(client: &Client, port: u16, name: &str) -> String {
+    let create_url = format!("http://localhost:{}/api/v1/products", port);
+    let product_data = json!({
+        "name": name,
+        "description": "Produto para a lixeira",
+        "stock": 10,
+        "price": 1000
+    });
+
+    let create_response = client
+        .post(&create_url)
+        .json(&product_data)
+        .send()
+        .await
+        .expect("Failed to create product");
+    assert_eq!(create_response.status(), StatusCode::CREATED);
+
+    let created_product: serde_json::Value = create_response
+        .json()
+        .await
+        .expect("Failed to parse created product");
+    let product_id = created_product["id"]
+        .as_str()
+        .expect("Product ID missing")
+        .to_string();
+
+    let delete_url = format!("http://localhost:{}/api/v1/products/{}", port, product_id);
+    let delete_response = client
+        .delete(&delete_url)
+        .send()
+        .await
+        .expect("Failed to delete product");
+    assert_eq!(delete_response.status(), StatusCode::NO_CONTENT);
+
+    product_id
+}
+
+#[tokio::test]
+#[serial]
+async fn test_10_trash_lists_and_restores_product() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+
+    let name = format!("Café Lixeira {}", uuid::Uuid::new_v4());
+    let product_id = create_and_delete_product(&client, port, &name).await;
+
+    let trash_url = format!("http://localhost:{}/api/v1/products/trash?limit=50", port);
+    let trash_response = client
+        .get(&trash_url)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(trash_response.status(), StatusCode::OK);
+
+    let trash_body: serde_json::Value = trash_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    let in_trash = trash_body["data"]
+        .as_array()
+        .expect("data must be an array")
+        .iter()
+        .any(|p| p["id"] == product_id.as_str());
+    assert!(in_trash);
+
+    let restore_url = format!(
+        "http://localhost:{}/api/v1/products/{}/restore",
+        port, product_id
+    );
+    let restore_response = client
+        .post(&restore_url)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(restore_response.status(), StatusCode::OK);
+
+    let restored: serde_json::Value = restore_response
+        .json()
+        .await
+        .expect("Failed to parse response");
+    assert_eq!(restored["id"], product_id.as_str());
+    assert_eq!(restored["deleted_at"], serde_json::Value::Null);
+
+    let get_url = format!("http://localhost:{}/api/v1/products/{}", port, product_id);
+    let get_response = client
+        .get(&get_url)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(get_response.status(), StatusCode::OK);
+
+    let second_restore = client
+        .post(&restore_url)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(second_restore.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_11_restore_product_name_conflict() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+
+    let name = format!("Café Conflito {}", uuid::Uuid::new_v4());
+    let product_id = create_and_delete_product(&client, port, &name).await;
+
+    let create_url = format!("http://localhost:{}/api/v1/products", port);
+    let create_response = client
+        .post(&create_url)
+        .json(&json!({ "name": name, "stock": 1, "price": 500 }))
+        .send()
+        .await
+        .expect("Failed to create product");
+    assert_eq!(create_response.status(), StatusCode::CREATED);
+
+    let restore_url = format!(
+        "http://localhost:{}/api/v1/products/{}/restore",
+        port, product_id
+    );
+    let restore_response = client
+        .post(&restore_url)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(restore_response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_12_purge_product() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+
+    let name = format!("Café Purge {}", uuid::Uuid::new_v4());
+    let product_id = create_and_delete_product(&client, port, &name).await;
+
+    let purge_url = format!(
+        "http://localhost:{}/api/v1/products/{}/purge",
+        port, product_id
+    );
+    let purge_response = client
+        .delete(&purge_url)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(purge_response.status(), StatusCode::NO_CONTENT);
+
+    let restore_url = format!(
+        "http://localhost:{}/api/v1/products/{}/restore",
+        port, product_id
+    );
+    let restore_response = client
+        .post(&restore_url)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(restore_response.status(), StatusCode::NOT_FOUND);
+
+    let second_purge = client
+        .delete(&purge_url)
+        .send()
+        .await
+        .expect("Failed to send request");
+    assert_eq!(second_purge.status(), StatusCode::NOT_FOUND);
+
+    let body: serde_json::Value = second_purge.json().await.expect("Failed to parse response");
+    assert_eq!(body["detail"], "Product not found in trash");
+}
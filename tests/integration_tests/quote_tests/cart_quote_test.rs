@@ -0,0 +1,130 @@
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::{fixtures::create_product, start_server::init_tracing};
+
+#[tokio::test]
+#[serial]
+async fn test_01_quote_prices_lines_and_reports_problems_without_side_effects() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+
+    let mug = create_product(&client, port, json!({ "stock": 5 })).await;
+    let kettle = create_product(&client, port, json!({ "stock": 1, "price": 2000 })).await;
+    let missing = Uuid::new_v4();
+
+    let code = format!("QUOTE{}", &Uuid::new_v4().simple().to_string()[..12]);
+    let response = client
+        .post(format!("http://localhost:{}/api/v1/coupons", port))
+        .json(&json!({
+            "code": code,
+            "type": "percent",
+            "value": 1000,
+            "one_shot": false,
+            "valid_from": "2025-01-01T00:00:00Z",
+            "valid_until": "2029-12-31T23:59:59Z",
+            "allowed_product_ids": [mug]
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let unknown_code = format!("NOPE{}", &Uuid::new_v4().simple().to_string()[..12]);
+
+    let response = client
+        .post(format!("http://localhost:{}/api/v1/quotes", port))
+        .json(&json!({
+            "items": [
+                { "product_id": mug, "quantity": 1 },
+                { "product_id": kettle, "quantity": 3 },
+                { "product_id": missing, "quantity": 1 },
+                { "product_id": mug, "quantity": 1 }
+            ],
+            "coupon_codes": [code, unknown_code]
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let quote: Value = response.json().await.unwrap();
+
+    let lines = quote["lines"].as_array().unwrap();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["product_id"], mug);
+    assert_eq!(lines[0]["quantity"], 2);
+    assert_eq!(lines[0]["unit_price"], 1000);
+    assert_eq!(lines[0]["subtotal"], 2000);
+    assert_eq!(lines[0]["coupons"][0]["code"], code);
+    assert_eq!(lines[0]["coupons"][0]["amount"], 200);
+    assert_eq!(lines[0]["total"], 1800);
+    assert_eq!(lines[1]["product_id"], kettle);
+    assert_eq!(lines[1]["coupons"], json!([]));
+    assert_eq!(lines[1]["total"], 6000);
+
+    assert_eq!(quote["subtotal"], 8000);
+    assert_eq!(quote["discount_total"], 200);
+    assert_eq!(quote["total"], 7800);
+    assert_eq!(quote["tax_amount"], 0);
+    assert_eq!(
+        quote["warnings"],
+        json!([
+            { "type": "insufficient_stock", "product_id": kettle, "requested": 3, "available": 1 },
+            { "type": "product_not_found", "product_id": missing }
+        ])
+    );
+    assert_eq!(
+        quote["coupon_errors"],
+        json!([{ "code": unknown_code, "reasons": ["not_found"] }])
+    );
+
+    // Nothing was redeemed or reserved.
+    let coupon: Value = client
+        .get(format!("http://localhost:{}/api/v1/coupons/{}", port, code))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(coupon["uses_count"], 0);
+    let product: Value = client
+        .get(format!("http://localhost:{}/api/v1/products/{}", port, mug))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(product["stock"], 5);
+    assert_eq!(product["has_coupon_applied"], false);
+
+    let response = client
+        .post(format!("http://localhost:{}/api/v1/quotes", port))
+        .json(&json!({ "items": [] }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["errors"][0]["field"], "items");
+
+    let response = client
+        .post(format!("http://localhost:{}/api/v1/quotes", port))
+        .json(&json!({ "items": [{ "product_id": mug, "quantity": 0 }] }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: Value = response.json().await.unwrap();
+    assert_eq!(body["errors"][0]["field"], "items[0].quantity");
+    assert_eq!(body["errors"][0]["message"], "Must be between 1 and 999999");
+}
@@ -0,0 +1 @@
+pub mod cart_quote_test;
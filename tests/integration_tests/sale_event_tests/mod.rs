@@ -0,0 +1 @@
+pub mod scheduled_sale_event_test;
@@ -0,0 +1,202 @@
+use chrono::{Duration, Utc};
+use pretty_assertions::assert_eq;
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use serial_test::serial;
+use uuid::Uuid;
+
+use crate::utils::{fixtures::create_product, start_server::init_tracing};
+
+async fn get_product(client: &Client, port: u16, id: &str) -> Value {
+    client
+        .get(format!("http://localhost:{}/api/v1/products/{}", port, id))
+        .send()
+        .await
+        .expect("Failed to get product")
+        .json()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_01_sale_event_prices_targeted_products_while_running() {
+    init_tracing();
+
+    dotenv::dotenv().ok();
+    let port: u16 = dotenv::var("PORT")
+        .unwrap()
+        .parse()
+        .expect("PORT must be a number");
+    let client = Client::new();
+    let events_url = format!("http://localhost:{}/api/v1/sale-events", port);
+
+    let token = Uuid::new_v4().simple().to_string();
+    let cheap = create_product(
+        &client,
+        port,
+        json!({ "name": format!("Sale {} cheap", token) }),
+    )
+    .await;
+    let pricey = create_product(
+        &client,
+        port,
+        json!({ "name": format!("Sale {} pricey", token), "price": 5000 }),
+    )
+    .await;
+    let now = Utc::now();
+
+    let create = |body: Value| client.post(&events_url).json(&body).send();
+
+    let storewide = create(json!({
+        "name": "Black Friday",
+        "starts_at": now - Duration::minutes(1),
+        "ends_at": now + Duration::hours(1),
+        "type": "percent",
+        "value": 2000,
+        "target": { "type": "search", "term": token.to_uppercase() }
+    }))
+    .await
+    .unwrap();
+    assert_eq!(storewide.status(), StatusCode::CREATED);
+    let storewide: Value = storewide.json().await.unwrap();
+    let storewide_id = storewide["id"].as_str().unwrap().to_string();
+    assert_eq!(storewide["status"], "active");
+    assert_eq!(storewide["target"]["term"], token.as_str());
+
+    let product = get_product(&client, port, &pricey).await;
+    assert_eq!(product["final_price"], 4000);
+    assert_eq!(product["has_coupon_applied"], false);
+    assert_eq!(product["discounts"][0]["source"], "sale_event");
+    assert_eq!(product["discounts"][0]["code"], "Black Friday");
+
+    // Overlapping events never add up; the better one wins.
+    let flash: Value = create(json!({
+        "name": "Flash",
+        "starts_at": now - Duration::minutes(1),
+        "ends_at": now + Duration::hours(1),
+        "type": "fixed",
+        "value": 300,
+        "target": { "type": "products", "product_ids": [cheap] }
+    }))
+    .await
+    .unwrap()
+    .json()
+    .await
+    .unwrap();
+    let flash_id = flash["id"].as_str().unwrap().to_string();
+
+    let product = get_product(&client, port, &cheap).await;
+    assert_eq!(product["final_price"], 700);
+    assert_eq!(product["discounts"].as_array().unwrap().len(), 1);
+    assert_eq!(product["discounts"][0]["code"], "Flash");
+
+    let scheduled: Value = create(json!({
+        "name": "Tomorrow",
+        "starts_at": now + Duration::days(1),
+        "ends_at": now + Duration::days(2),
+        "type": "percent",
+        "value": 5000,
+        "target": { "type": "price_range", "min_price": 4000, "max_price": 6000 }
+    }))
+    .await
+    .unwrap()
+    .json()
+    .await
+    .unwrap();
+    let scheduled_id = scheduled["id"].as_str().unwrap().to_string();
+    let product = get_product(&client, port, &pricey).await;
+    assert_eq!(product["final_price"], 4000);
+
+    let listing: Value = client
+        .get(format!("http://localhost:{}/api/v1/products", port))
+        .query(&[("search", token.as_str())])
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let final_prices: Vec<&Value> = listing["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| &p["final_price"])
+        .collect();
+    assert_eq!(final_prices, vec![&json!(4000), &json!(700)]);
+
+    let preview: Value = client
+        .get(format!("{}/{}/products", events_url, storewide_id))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(preview["meta"]["total_items"], 2);
+    assert_eq!(preview["data"][0]["product_id"], pricey.as_str());
+    assert_eq!(preview["data"][0]["discount_amount"], 1000);
+    assert_eq!(preview["data"][1]["sale_price"], 800);
+
+    for (field, invalid) in [
+        (
+            "type",
+            json!({
+                "name": "Bogo",
+                "starts_at": now,
+                "ends_at": now + Duration::hours(1),
+                "type": "bogo",
+                "value": 5000,
+                "target": { "type": "all" }
+            }),
+        ),
+        (
+            "ends_at",
+            json!({
+                "name": "Backwards",
+                "starts_at": now + Duration::hours(1),
+                "ends_at": now,
+                "type": "percent",
+                "value": 1000,
+                "target": { "type": "all" }
+            }),
+        ),
+        (
+            "target",
+            json!({
+                "name": "Nobody",
+                "starts_at": now,
+                "ends_at": now + Duration::hours(1),
+                "type": "fixed",
+                "value": 100,
+                "target": { "type": "products", "product_ids": [] }
+            }),
+        ),
+    ] {
+        let response = create(invalid).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body: Value = response.json().await.unwrap();
+        assert_eq!(body["errors"][0]["field"], field);
+    }
+
+    let cancel = |id: String| client.post(format!("{}/{}/cancel", events_url, id)).send();
+
+    let cancelled = cancel(storewide_id.clone()).await.unwrap();
+    assert_eq!(cancelled.status(), StatusCode::OK);
+    let cancelled: Value = cancelled.json().await.unwrap();
+    assert_eq!(cancelled["status"], "cancelled");
+    let product = get_product(&client, port, &pricey).await;
+    assert_eq!(product["final_price"], 5000);
+    assert_eq!(product["discounts"], json!([]));
+
+    assert_eq!(
+        cancel(storewide_id).await.unwrap().status(),
+        StatusCode::CONFLICT
+    );
+    assert_eq!(cancel(flash_id).await.unwrap().status(), StatusCode::OK);
+    assert_eq!(cancel(scheduled_id).await.unwrap().status(), StatusCode::OK);
+    assert_eq!(
+        cancel(Uuid::new_v4().to_string()).await.unwrap().status(),
+        StatusCode::NOT_FOUND
+    );
+}
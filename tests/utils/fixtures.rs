@@ -0,0 +1,86 @@
+use reqwest::{Client, StatusCode};
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+/// Returns `defaults` with the fields of `overrides` written over it.
+fn merge(mut defaults: Value, overrides: Value) -> Value {
+    if let (Some(defaults), Some(overrides)) = (defaults.as_object_mut(), overrides.as_object()) {
+        defaults.extend(overrides.clone());
+    }
+    defaults
+}
+
+/// Creates a product with 10 in stock at 1000 cents under a unique name,
+/// with `overrides` written over those, and returns its id.
+pub async fn create_product(client: &Client, port: u16, overrides: Value) -> String {
+    let body = merge(
+        json!({
+            "name": format!("Product {}", Uuid::new_v4()),
+            "stock": 10,
+            "price": 1000
+        }),
+        overrides,
+    );
+    let product: Value = client
+        .post(format!("http://localhost:{}/api/v1/products", port))
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to create product")
+        .json()
+        .await
+        .unwrap();
+    product["id"]
+        .as_str()
+        .expect("Product ID missing")
+        .to_string()
+}
+
+/// Posts a coupon taking 100 cents off, valid from 2025 through 2029 under
+/// a unique code, with `overrides` written over those, and returns the
+/// status and body of the response.
+pub async fn post_coupon(client: &Client, port: u16, overrides: Value) -> (StatusCode, Value) {
+    let body = merge(
+        json!({
+            "code": format!("TEST{}", &Uuid::new_v4().simple().to_string()[..12]),
+            "type": "fixed",
+            "value": 100,
+            "one_shot": false,
+            "valid_from": "2025-01-01T00:00:00Z",
+            "valid_until": "2029-12-31T23:59:59Z"
+        }),
+        overrides,
+    );
+    let response = client
+        .post(format!("http://localhost:{}/api/v1/coupons", port))
+        .json(&body)
+        .send()
+        .await
+        .expect("Failed to create coupon");
+    let status = response.status();
+    (status, response.json().await.unwrap())
+}
+
+/// Creates a coupon as `post_coupon` does and returns its code.
+pub async fn create_coupon(client: &Client, port: u16, overrides: Value) -> String {
+    let (status, coupon) = post_coupon(client, port, overrides).await;
+    assert_eq!(status, StatusCode::CREATED, "{}", coupon);
+    coupon["code"]
+        .as_str()
+        .expect("Coupon code missing")
+        .to_string()
+}
+
+/// Applies the coupon `code` to a product and returns the status.
+pub async fn apply(client: &Client, port: u16, product_id: &str, code: &str) -> StatusCode {
+    client
+        .post(format!(
+            "http://localhost:{}/api/v1/products/{}/discount/coupon",
+            port, product_id
+        ))
+        .json(&json!({ "code": code }))
+        .send()
+        .await
+        .expect("Failed to apply coupon")
+        .status()
+}
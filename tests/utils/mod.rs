@@ -1 +1,2 @@
+pub mod fixtures;
 pub mod start_server;